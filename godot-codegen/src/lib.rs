@@ -50,6 +50,16 @@ pub const IS_CODEGEN_FULL: bool = false;
 #[cfg(feature = "codegen-full")]
 pub const IS_CODEGEN_FULL: bool = true;
 
+/// Optional tools of godot-core that are enabled, used by itest to compile the corresponding tests; see itest/build.rs.
+pub const ENABLED_TOOLS: &[&str] = &[
+    #[cfg(feature = "embedded-resources")]
+    "embedded-resources",
+    #[cfg(feature = "loopback-peer")]
+    "loopback-peer",
+    #[cfg(feature = "script-toolkit")]
+    "script-toolkit",
+];
+
 fn write_file(path: &Path, contents: String) {
     let dir = path.parent().unwrap();
     let _ = std::fs::create_dir_all(dir);
@@ -154,6 +154,10 @@ const SELECTED_CLASSES: &[&str] = &[
     "ResourceLoader",
     "ResourceSaver",
     "FileAccess",
+    "DirAccess",
+    "ConfigFile",
+    "ProjectSettings",
+    "JSON",
     //
     // Scene (node_test, rpc_test)
     "MainLoop", // base of SceneTree
@@ -183,6 +187,15 @@ const SELECTED_CLASSES: &[&str] = &[
     "InputEvent",
     "InputEventAction",
     //
+    // Input actions (tools::InputActions)
+    "InputMap",
+    "InputEventFromWindow",
+    "InputEventWithModifiers",
+    "InputEventKey",
+    "InputEventMouse",
+    "InputEventMouseButton",
+    "InputEventJoypadButton",
+    //
     // Godot servers (for RID support)
     "RenderingServer",
     //
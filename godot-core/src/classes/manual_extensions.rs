@@ -4,10 +4,79 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
-use crate::builtin::NodePath;
-use crate::classes::{Node, PackedScene};
-use crate::meta::{arg_into_ref, AsArg};
+use std::collections::VecDeque;
+
+use crate::builtin::{GString, NodePath, StringName, Variant};
+use crate::classes::{Node, Object, PackedScene, SceneTree};
+use crate::meta::error::CallError;
+use crate::meta::{
+    arg_into_owned, arg_into_ref, AsArg, CallContext, FromGodot, ParamTuple, ToGodot,
+};
 use crate::obj::{Gd, Inherits};
+use crate::sys;
+
+/// Manual extensions for the `Object` class.
+impl Object {
+    /// Calls `method` dynamically, with typed arguments and return value.
+    ///
+    /// Arguments are passed as a tuple, e.g. `(1, GString::from("x"))`, and converted to variants on the stack. The return value is
+    /// converted to `R`. Unlike [`call()`][Self::call], no `Vec<Variant>` needs to be built and failures are returned instead of panicking.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # fn test(mut node: Gd<Node>) -> Result<(), godot::meta::error::CallError> {
+    /// let died = node.call_typed::<(i32, GString), bool>("take_damage", (10, "fire".into()))?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// The returned error's [`kind()`](crate::meta::error::CallError::kind) tells apart a missing method, a wrong number of arguments,
+    /// and failed argument or return value conversions. Its [`class_name()`](crate::meta::error::CallError::class_name) is the dynamic
+    /// class of the object.
+    pub fn call_typed<Args, R>(
+        &mut self,
+        method: impl AsArg<StringName>,
+        args: Args,
+    ) -> Result<R, CallError>
+    where
+        Args: ParamTuple,
+        R: FromGodot,
+    {
+        arg_into_ref!(method);
+
+        let class_name = self.get_class().to_string();
+        let method_name = method.to_string();
+        let call_ctx = CallContext::outbound(&class_name, &method_name);
+
+        let self_variant = crate::private::rebuild_gd(self).to_variant();
+
+        let result = args.with_variant_ptrs(|variants, variant_ptrs| {
+            let mut err = sys::default_call_error();
+
+            // SAFETY: self_variant holds a valid object, and all pointers point to variants that outlive the call.
+            let result = unsafe {
+                Variant::new_with_var_uninit(|return_ptr| {
+                    sys::interface_fn!(variant_call)(
+                        sys::SysPtr::force_mut(self_variant.var_sys()),
+                        method.string_sys(),
+                        variant_ptrs.as_ptr(),
+                        variant_ptrs.len() as i64,
+                        return_ptr,
+                        std::ptr::addr_of_mut!(err),
+                    )
+                })
+            };
+
+            CallError::check_out_varcall::<Variant>(&call_ctx, err, &[], variants)?;
+            Ok(result)
+        })?;
+
+        result
+            .try_to::<R>()
+            .map_err(|err| CallError::failed_return_conversion::<R>(&call_ctx, err))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
 
 /// Manual extensions for the `Node` class.
 impl Node {
@@ -43,6 +112,175 @@ impl Node {
         self.get_node_or_null(path)
             .and_then(|node| node.try_cast::<T>().ok())
     }
+
+    /// Iterates over the direct children of type `T` (or inherited), in tree order.
+    ///
+    /// Unlike [`get_children()`][Self::get_children], children are fetched lazily, without building an intermediate `Array`. Adding or
+    /// removing children during iteration thus affects the remaining iteration. Internal children are not included.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # use godot::classes::Label;
+    /// # fn test(node: Gd<Node>) {
+    /// for mut label in node.children_of::<Label>() {
+    ///     label.set_text("");
+    /// }
+    /// # }
+    /// ```
+    pub fn children_of<T>(&self) -> impl Iterator<Item = Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        let this = self.to_owned_node();
+        let mut index = 0;
+
+        // Re-check the count on each step, as children may be removed during iteration.
+        std::iter::from_fn(move || {
+            if index >= this.get_child_count() {
+                return None;
+            }
+
+            let child = this.get_child(index);
+            index += 1;
+            Some(child)
+        })
+        .flatten()
+        .filter_map(|child| child.try_cast::<T>().ok())
+    }
+
+    /// Iterates over all descendants of type `T` (or inherited), depth-first in tree order.
+    ///
+    /// This node itself is not included. Nodes are visited lazily, so adding or removing children during iteration affects the remaining
+    /// traversal. Internal children are not included.
+    pub fn descendants_of<T>(&self) -> impl Iterator<Item = Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        DepthFirst {
+            stack: vec![(self.to_owned_node(), 0)],
+        }
+        .filter_map(|node| node.try_cast::<T>().ok())
+    }
+
+    /// Iterates over all descendants of type `T` (or inherited), breadth-first (level by level).
+    ///
+    /// Same as [`descendants_of()`][Self::descendants_of], but visits all children before any grandchildren.
+    pub fn descendants_breadth_first_of<T>(&self) -> impl Iterator<Item = Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        BreadthFirst {
+            queue: VecDeque::from([(self.to_owned_node(), 0)]),
+        }
+        .filter_map(|node| node.try_cast::<T>().ok())
+    }
+
+    /// Finds the first descendant of type `T` (or inherited) whose name matches `pattern`, searching depth-first.
+    ///
+    /// `pattern` is matched with [`GString::match_glob()`], i.e. `*` matches any sequence of characters and `?` a single character.
+    ///
+    /// In contrast to [`find_child()`][Self::find_child], this also considers nodes without an owner, such as nodes created in code.
+    pub fn find_child_of<T>(&self, pattern: impl AsArg<GString>) -> Option<Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        arg_into_owned!(pattern);
+
+        self.descendants_of::<T>()
+            .find(|node| node.upcast_ref::<Node>().get_name().match_glob(&pattern))
+    }
+
+    /// Returns the closest ancestor of type `T` (or inherited), starting from the parent.
+    ///
+    /// Returns `None` if no ancestor has type `T`.
+    pub fn ancestor_of<T>(&self) -> Option<Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        std::iter::successors(self.get_parent(), |node| node.get_parent())
+            .find_map(|node| node.try_cast::<T>().ok())
+    }
+
+    fn to_owned_node(&self) -> Gd<Node> {
+        crate::private::rebuild_gd(self).cast::<Node>()
+    }
+}
+
+/// Depth-first pre-order traversal of all descendants of a node.
+struct DepthFirst {
+    /// Nodes whose children are being visited, each with the index of the next child.
+    stack: Vec<(Gd<Node>, i32)>,
+}
+
+impl Iterator for DepthFirst {
+    type Item = Gd<Node>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (parent, index) = self.stack.last_mut()?;
+            if *index >= parent.get_child_count() {
+                self.stack.pop();
+                continue;
+            }
+
+            let child = parent.get_child(*index);
+            *index += 1;
+
+            if let Some(child) = child {
+                self.stack.push((child.clone(), 0));
+                return Some(child);
+            }
+        }
+    }
+}
+
+/// Breadth-first traversal of all descendants of a node.
+struct BreadthFirst {
+    /// Nodes whose children are yet to be visited, each with the index of the next child.
+    queue: VecDeque<(Gd<Node>, i32)>,
+}
+
+impl Iterator for BreadthFirst {
+    type Item = Gd<Node>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (parent, index) = self.queue.front_mut()?;
+            if *index >= parent.get_child_count() {
+                self.queue.pop_front();
+                continue;
+            }
+
+            let child = parent.get_child(*index);
+            *index += 1;
+
+            if let Some(child) = child {
+                self.queue.push_back((child.clone(), 0));
+                return Some(child);
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `SceneTree` class.
+impl SceneTree {
+    /// Iterates over all nodes in `group` that have type `T` (or inherited), in tree order.
+    ///
+    /// Nodes of other types are skipped. The group members are queried from the engine once, when this method is called.
+    pub fn nodes_in_group_of<T>(&self, group: impl AsArg<StringName>) -> impl Iterator<Item = Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        arg_into_ref!(group);
+
+        let nodes = self.get_nodes_in_group(group);
+
+        (0..nodes.len())
+            .filter_map(move |index| nodes.get(index))
+            .filter_map(|node| node.try_cast::<T>().ok())
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -54,6 +54,10 @@ pub const fn class_hidden() {}
     More information on https://github.com/godot-rust/gdext/pull/959"]
 pub const fn gdextension_entry_point() {}
 
+#[deprecated = "\n#[rpc] in a #[godot_api(secondary)] impl block has no effect: the method is registered as #[func], but not configured \
+    as RPC. Move it to the primary impl block."]
+pub const fn rpc_secondary_impl() {}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Godot-side deprecations
 
@@ -164,6 +164,8 @@ unsafe fn gdext_on_level_init(level: InitLevel) {
         InitLevel::Scene => {
             // SAFETY: On the main thread, api initialized, `Scene` was initialized above.
             unsafe { ensure_godot_features_compatible() };
+
+            crate::tools::register_project_settings();
         }
         InitLevel::Editor => {
             #[cfg(all(since_api = "4.3", feature = "register-docs"))]
@@ -188,6 +190,7 @@ fn gdext_on_level_deinit(level: InitLevel) {
 
         #[cfg(since_api = "4.2")]
         crate::task::cleanup();
+        crate::tools::cleanup();
 
         // Garbage-collect various statics.
         // SAFETY: this is the last time meta APIs are used.
@@ -74,3 +74,38 @@ pub mod private;
 /// Re-export logging macro.
 #[doc(hidden)]
 pub use godot_ffi::out;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Proc-macro paths
+
+// Some optional classes in `tools` are defined via the proc-macro API, whose generated code refers to `::godot`. The alias and the
+// `register` module below mirror the paths of the `godot` crate that are used in such code.
+#[cfg(any(
+    feature = "embedded-resources",
+    feature = "loopback-peer",
+    feature = "script-toolkit"
+))]
+extern crate self as godot;
+
+/// Mirrors `godot::register` for proc-macro generated code inside this crate.
+#[cfg(any(
+    feature = "embedded-resources",
+    feature = "loopback-peer",
+    feature = "script-toolkit"
+))]
+#[doc(hidden)]
+pub mod register {
+    pub use crate::registry::property;
+    pub use crate::registry::signal::re_export::*;
+
+    #[cfg(feature = "codegen-full")]
+    pub use crate::registry::{RpcCall, RpcConfig};
+
+    pub mod private {
+        #[cfg(feature = "codegen-full")]
+        pub use crate::registry::class::auto_register_rpcs;
+
+        pub use crate::registry::godot_register_wrappers::*;
+        pub use crate::registry::{constant, method};
+    }
+}
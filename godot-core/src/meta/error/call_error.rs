@@ -75,6 +75,7 @@ pub struct CallError {
     class_name: String,
     function_name: String,
     call_expr: String,
+    kind: CallErrorKind,
     reason: String,
     source: Option<SourceError>,
 }
@@ -103,6 +104,13 @@ impl CallError {
         &self.function_name
     }
 
+    /// Category of the failure, for programmatic handling.
+    ///
+    /// For errors that were propagated from another call (see [`source()`](Error::source)), this is the kind of the original error.
+    pub fn kind(&self) -> CallErrorKind {
+        self.kind
+    }
+
     // ------------------------------------------------------------------------------------------------------------------------------------------
     // Constructors returning Result<(), Self>; possible failure
 
@@ -122,6 +130,31 @@ impl CallError {
         Err(call_error)
     }
 
+    /// Like [`check_arg_count()`][Self::check_arg_count], but for variadic functions, which accept additional arguments.
+    pub(crate) fn check_min_arg_count(
+        call_ctx: &CallContext,
+        arg_count: usize,
+        param_count: usize,
+    ) -> Result<(), Self> {
+        if arg_count >= param_count {
+            return Ok(());
+        }
+
+        let param_plural = plural(param_count);
+        let arg_plural = plural(arg_count);
+
+        let call_error = Self::new(
+            call_ctx,
+            CallErrorKind::ArgumentCount,
+            format!(
+                "function has at least {param_count} parameter{param_plural}, but received {arg_count} argument{arg_plural}"
+            ),
+            None,
+        );
+
+        Err(call_error)
+    }
+
     /// Checks the Godot side of a varcall (low-level `sys::GDExtensionCallError`).
     pub(crate) fn check_out_varcall<T: ToGodot>(
         call_ctx: &CallContext,
@@ -176,6 +209,7 @@ impl CallError {
 
         Self::new(
             call_ctx,
+            CallErrorKind::ArgumentConversion,
             format!("parameter #{param_index} ({param_ty}) conversion"),
             Some(convert_error),
         )
@@ -191,7 +225,7 @@ impl CallError {
         let reason =
             format!("parameter #{param_index} -- cannot convert from {actual:?} to {expected:?}");
 
-        Self::new(call_ctx, reason, None)
+        Self::new(call_ctx, CallErrorKind::ArgumentConversion, reason, None)
     }
 
     /// Returns an error for a failed return type conversion.
@@ -210,6 +244,7 @@ impl CallError {
 
         Self::new(
             call_ctx,
+            CallErrorKind::ReturnConversion,
             format!("return value {return_ty} conversion"),
             Some(convert_error),
         )
@@ -225,6 +260,7 @@ impl CallError {
 
         Self::new(
             call_ctx,
+            CallErrorKind::ArgumentCount,
             format!(
                 "function has {param_count} parameter{param_plural}, but received {arg_count} argument{arg_plural}"
             ),
@@ -252,9 +288,12 @@ impl CallError {
         } = err;
 
         let mut call_error = match error {
-            sys::GDEXTENSION_CALL_ERROR_INVALID_METHOD => {
-                Self::new(call_ctx, "method not found", None)
-            }
+            sys::GDEXTENSION_CALL_ERROR_INVALID_METHOD => Self::new(
+                call_ctx,
+                CallErrorKind::MethodNotFound,
+                "method not found",
+                None,
+            ),
             sys::GDEXTENSION_CALL_ERROR_INVALID_ARGUMENT => {
                 // Index calculation relies on patterns like call("...", varargs), might not always work...
                 let from = arg_types[vararg_offset + argument as usize];
@@ -270,17 +309,18 @@ impl CallError {
                 Self::failed_param_count(call_ctx, arg_count, param_count)
             }
             sys::GDEXTENSION_CALL_ERROR_INSTANCE_IS_NULL => {
-                Self::new(call_ctx, "instance is null", None)
+                Self::new(call_ctx, CallErrorKind::Other, "instance is null", None)
             }
             sys::GDEXTENSION_CALL_ERROR_METHOD_NOT_CONST => {
-                Self::new(call_ctx, "method is not const", None)
+                Self::new(call_ctx, CallErrorKind::Other, "method is not const", None)
             }
             sys::GODOT_RUST_CUSTOM_CALL_ERROR => {
                 // Not emitted by Godot.
-                Self::new(call_ctx, String::new(), None)
+                Self::new(call_ctx, CallErrorKind::Other, String::new(), None)
             }
             _ => Self::new(
                 call_ctx,
+                CallErrorKind::Other,
                 format!("unknown reason (error code {error})"),
                 None,
             ),
@@ -294,6 +334,11 @@ impl CallError {
         //     source,
         // }
 
+        // Errors raised by godot-rust on the callee side carry the actual cause.
+        if let Some(SourceError::Call(inner)) = &source {
+            call_error.kind = inner.kind;
+        }
+
         call_error.source = source;
         call_error.call_expr = call_expr;
         call_error
@@ -305,11 +350,17 @@ impl CallError {
         // But in other cases (e.g. itest `dynamic_call_with_panic`), it is only printed once.
         // Would need some work to have a consistent experience.
 
-        Self::new(call_ctx, format!("function panicked: {reason}"), None)
+        Self::new(
+            call_ctx,
+            CallErrorKind::Panic,
+            format!("function panicked: {reason}"),
+            None,
+        )
     }
 
     fn new(
         call_ctx: &CallContext,
+        kind: CallErrorKind,
         reason: impl Into<String>,
         source: Option<ConvertError>,
     ) -> Self {
@@ -317,6 +368,7 @@ impl CallError {
             class_name: call_ctx.class_name.to_string(),
             function_name: call_ctx.function_name.to_string(),
             call_expr: format!("{call_ctx}()"),
+            kind,
             reason: reason.into(),
             source: source.map(|e| SourceError::Convert {
                 value: e.value().map_or_else(String::new, |v| format!("{:?}", v)),
@@ -384,6 +436,31 @@ impl Error for CallError {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Category of a [`CallError`], returned by [`CallError::kind()`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum CallErrorKind {
+    /// The method does not exist on the object.
+    MethodNotFound,
+
+    /// The number of arguments does not match the number of parameters.
+    ArgumentCount,
+
+    /// An argument could not be converted to the declared parameter type.
+    ArgumentConversion,
+
+    /// The returned value could not be converted to the expected type.
+    ReturnConversion,
+
+    /// A Rust function panicked.
+    Panic,
+
+    /// Any other reason, e.g. a null instance.
+    Other,
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Implementation
 
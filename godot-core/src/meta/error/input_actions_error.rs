@@ -0,0 +1,65 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+/// Error when actions of an [`InputActions`][crate::tools::InputActions] enum are not registered in the `InputMap`.
+///
+/// Lists all missing actions at once, rather than only the first one.
+///
+/// Returned by [`InputActions::verify_input_map()`][crate::tools::InputActions::verify_input_map].
+#[derive(Debug)]
+pub struct InputActionsError {
+    enum_name: &'static str,
+    missing_actions: Vec<&'static str>,
+}
+
+impl InputActionsError {
+    pub(crate) fn new(enum_name: &'static str) -> Self {
+        Self {
+            enum_name,
+            missing_actions: vec![],
+        }
+    }
+
+    pub(crate) fn add_missing(&mut self, action: &'static str) {
+        self.missing_actions.push(action);
+    }
+
+    pub(crate) fn has_problems(&self) -> bool {
+        !self.missing_actions.is_empty()
+    }
+
+    /// Name of the enum deriving `InputActions`, e.g. `Action`.
+    pub fn enum_name(&self) -> &str {
+        self.enum_name
+    }
+
+    /// Names of actions that are not present in the `InputMap`, e.g. `"move_left"`.
+    pub fn missing_actions(&self) -> impl Iterator<Item = &str> {
+        self.missing_actions.iter().copied()
+    }
+}
+
+impl fmt::Display for InputActionsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "input actions of {} are not registered in the InputMap (Project Settings > Input Map)",
+            self.enum_name
+        )?;
+
+        for action in &self.missing_actions {
+            write!(f, "\n  missing action `{action}`")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for InputActionsError {}
@@ -0,0 +1,51 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+/// Error when converting between `Variant` and `serde_json::Value`.
+///
+/// Reports the location of the offending value as [JSON pointer](https://datatracker.ietf.org/doc/html/rfc6901), e.g. `/players/0/position`.
+///
+/// Returned by [`JsonConversion`][crate::tools::JsonConversion] and the related functions.
+#[derive(Debug)]
+pub struct JsonValueError {
+    message: String,
+    pointer: String,
+}
+
+impl JsonValueError {
+    pub(crate) fn new(message: impl fmt::Display, pointer: &str) -> Self {
+        Self {
+            message: message.to_string(),
+            pointer: pointer.to_string(),
+        }
+    }
+
+    /// Location of the value that could not be converted, as JSON pointer. Empty for the root value.
+    pub fn pointer(&self) -> &str {
+        &self.pointer
+    }
+
+    /// Description of the problem, without the location.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for JsonValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.pointer.is_empty() {
+            f.write_str(&self.message)
+        } else {
+            write!(f, "at `{}`: {}", self.pointer, self.message)
+        }
+    }
+}
+
+impl Error for JsonValueError {}
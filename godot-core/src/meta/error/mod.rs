@@ -9,10 +9,24 @@
 
 mod call_error;
 mod convert_error;
+mod input_actions_error;
 mod io_error;
+#[cfg(feature = "serde_json")]
+mod json_value_error;
+mod proxy_error;
+mod scene_nodes_error;
+#[cfg(feature = "serde")]
+mod settings_error;
 mod string_error;
 
 pub use call_error::*;
 pub use convert_error::*;
+pub use input_actions_error::*;
 pub use io_error::*;
+#[cfg(feature = "serde_json")]
+pub use json_value_error::*;
+pub use proxy_error::*;
+pub use scene_nodes_error::*;
+#[cfg(feature = "serde")]
+pub use settings_error::*;
 pub use string_error::*;
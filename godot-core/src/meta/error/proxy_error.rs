@@ -0,0 +1,107 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+/// Error when an object does not provide the methods required by a proxy.
+///
+/// Returned by [`GodotProxy::try_from_object()`][crate::obj::GodotProxy::try_from_object].
+#[derive(Debug)]
+pub struct ProxyError {
+    proxy_name: &'static str,
+    class_name: String,
+    missing_methods: Vec<String>,
+    mismatched_methods: Vec<MethodMismatch>,
+}
+
+#[derive(Debug)]
+struct MethodMismatch {
+    method_name: String,
+    expected_params: usize,
+    actual_params: String,
+}
+
+impl ProxyError {
+    pub(crate) fn new(proxy_name: &'static str, class_name: String) -> Self {
+        Self {
+            proxy_name,
+            class_name,
+            missing_methods: vec![],
+            mismatched_methods: vec![],
+        }
+    }
+
+    pub(crate) fn add_missing(&mut self, method_name: &str) {
+        self.missing_methods.push(method_name.to_string());
+    }
+
+    pub(crate) fn add_mismatch(
+        &mut self,
+        method_name: &str,
+        expected_params: usize,
+        actual_params: String,
+    ) {
+        self.mismatched_methods.push(MethodMismatch {
+            method_name: method_name.to_string(),
+            expected_params,
+            actual_params,
+        });
+    }
+
+    pub(crate) fn has_problems(&self) -> bool {
+        !self.missing_methods.is_empty() || !self.mismatched_methods.is_empty()
+    }
+
+    /// Name of the proxy trait, e.g. `EnemyApi`.
+    pub fn proxy_name(&self) -> &str {
+        self.proxy_name
+    }
+
+    /// Dynamic class of the object, e.g. `Node2D`.
+    pub fn class_name(&self) -> &str {
+        &self.class_name
+    }
+
+    /// Methods that the object does not have at all.
+    pub fn missing_methods(&self) -> impl Iterator<Item = &str> {
+        self.missing_methods.iter().map(String::as_str)
+    }
+
+    /// Methods that exist on the object, but cannot be called with the number of arguments declared in the proxy.
+    pub fn mismatched_methods(&self) -> impl Iterator<Item = &str> {
+        self.mismatched_methods
+            .iter()
+            .map(|mismatch| mismatch.method_name.as_str())
+    }
+}
+
+impl fmt::Display for ProxyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "object of class {} does not implement proxy {}",
+            self.class_name, self.proxy_name
+        )?;
+
+        for method in &self.missing_methods {
+            write!(f, "\n  missing method `{method}`")?;
+        }
+
+        for mismatch in &self.mismatched_methods {
+            write!(
+                f,
+                "\n  method `{}` takes {} parameters, but proxy declares {}",
+                mismatch.method_name, mismatch.actual_params, mismatch.expected_params
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ProxyError {}
@@ -0,0 +1,120 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+/// Error when nodes of a scene cannot be bound to the fields of a [`SceneNodes`][crate::obj::SceneNodes] struct.
+///
+/// Lists all missing and mistyped node paths at once, rather than only the first one.
+///
+/// Returned by [`SceneNodes::try_from_node()`][crate::obj::SceneNodes::try_from_node].
+#[derive(Debug)]
+pub struct SceneNodesError {
+    struct_name: &'static str,
+    root_path: String,
+    missing_paths: Vec<MissingNode>,
+    mismatched_paths: Vec<MismatchedNode>,
+}
+
+#[derive(Debug)]
+struct MissingNode {
+    path: String,
+    expected_class: String,
+}
+
+#[derive(Debug)]
+struct MismatchedNode {
+    path: String,
+    expected_class: String,
+    actual_class: String,
+}
+
+impl SceneNodesError {
+    pub(crate) fn new(struct_name: &'static str, root_path: String) -> Self {
+        Self {
+            struct_name,
+            root_path,
+            missing_paths: vec![],
+            mismatched_paths: vec![],
+        }
+    }
+
+    pub(crate) fn add_missing(&mut self, path: &str, expected_class: String) {
+        self.missing_paths.push(MissingNode {
+            path: path.to_string(),
+            expected_class,
+        });
+    }
+
+    pub(crate) fn add_mismatch(
+        &mut self,
+        path: &str,
+        expected_class: String,
+        actual_class: String,
+    ) {
+        self.mismatched_paths.push(MismatchedNode {
+            path: path.to_string(),
+            expected_class,
+            actual_class,
+        });
+    }
+
+    pub(crate) fn has_problems(&self) -> bool {
+        !self.missing_paths.is_empty() || !self.mismatched_paths.is_empty()
+    }
+
+    /// Name of the struct deriving `SceneNodes`, e.g. `HudNodes`.
+    pub fn struct_name(&self) -> &str {
+        self.struct_name
+    }
+
+    /// Path of the node relative to which the nodes were looked up.
+    pub fn root_path(&self) -> &str {
+        &self.root_path
+    }
+
+    /// Paths that do not point to any node.
+    pub fn missing_paths(&self) -> impl Iterator<Item = &str> {
+        self.missing_paths.iter().map(|node| node.path.as_str())
+    }
+
+    /// Paths that point to a node, which however is not of the declared class (or a subclass).
+    pub fn mismatched_paths(&self) -> impl Iterator<Item = &str> {
+        self.mismatched_paths.iter().map(|node| node.path.as_str())
+    }
+}
+
+impl fmt::Display for SceneNodesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cannot bind scene nodes of {} relative to node `{}`",
+            self.struct_name, self.root_path
+        )?;
+
+        for node in &self.missing_paths {
+            write!(
+                f,
+                "\n  missing node `{}` (expected {})",
+                node.path, node.expected_class
+            )?;
+        }
+
+        for node in &self.mismatched_paths {
+            write!(
+                f,
+                "\n  node `{}` is {}, expected {}",
+                node.path, node.actual_class, node.expected_class
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for SceneNodesError {}
@@ -0,0 +1,102 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+/// Error when (de)serializing settings from/to a `ConfigFile` or `ProjectSettings`.
+///
+/// Reports the key at which the problem occurred, e.g. `audio/volume` for key `volume` in section `audio`.
+///
+/// Returned by the serde adapters in [`tools`][crate::tools], such as [`from_config_file()`][crate::tools::from_config_file].
+#[derive(Debug)]
+pub struct SettingsError {
+    message: String,
+    key: Option<String>,
+
+    /// Field reported by serde (e.g. for missing fields), relative to the struct currently being processed.
+    field: Option<String>,
+}
+
+impl SettingsError {
+    pub(crate) fn new(message: impl fmt::Display) -> Self {
+        Self {
+            message: message.to_string(),
+            key: None,
+            field: None,
+        }
+    }
+
+    /// Sets the key, unless a more specific one has been set already by an inner value.
+    pub(crate) fn at_key(mut self, path: &str) -> Self {
+        if self.key.is_some() {
+            return self;
+        }
+
+        let key = match self.field.take() {
+            Some(field) if path.is_empty() => field,
+            Some(field) => format!("{path}/{field}"),
+            None => path.to_string(),
+        };
+
+        if !key.is_empty() {
+            self.key = Some(key);
+        }
+        self
+    }
+
+    /// Full key of the offending entry, with sections and nested structs separated by `/`.
+    ///
+    /// Elements of arrays are denoted as `key[index]`. `None` if the error is not specific to a key.
+    pub fn key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+
+    /// Description of the problem, without the key.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for SettingsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.key {
+            Some(key) => write!(f, "key `{key}`: {}", self.message),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+impl Error for SettingsError {}
+
+impl serde::de::Error for SettingsError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::new(msg)
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        Self {
+            message: "missing key".to_string(),
+            key: None,
+            field: Some(field.to_string()),
+        }
+    }
+
+    fn unknown_field(field: &str, expected: &'static [&'static str]) -> Self {
+        Self {
+            message: format!("unknown key, expected one of {expected:?}"),
+            key: None,
+            field: Some(field.to_string()),
+        }
+    }
+}
+
+impl serde::ser::Error for SettingsError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::new(msg)
+    }
+}
@@ -4,7 +4,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
-use crate::builtin::{StringName, Variant};
+use crate::builtin::{dict, Array, Dictionary, StringName, Variant, VariantArray};
 use crate::global::MethodFlags;
 use crate::meta::{ClassName, PropertyInfo};
 use crate::sys;
@@ -27,6 +27,30 @@ pub struct MethodInfo {
 }
 
 impl MethodInfo {
+    /// Converts to a dictionary, as used by Godot's reflection APIs (e.g. [`Object::get_method_list()`][crate::classes::Object::get_method_list]).
+    ///
+    /// Contains the keys `name`, `args`, `default_args`, `flags`, `id` and `return`.
+    pub fn to_dictionary(&self) -> Dictionary {
+        use crate::obj::EngineBitfield as _;
+
+        let args: Array<Dictionary> = self
+            .arguments
+            .iter()
+            .map(PropertyInfo::to_dictionary)
+            .collect();
+
+        let default_args: VariantArray = self.default_arguments.iter().cloned().collect();
+
+        dict! {
+            "name": self.method_name.clone(),
+            "args": args,
+            "default_args": default_args,
+            "flags": self.flags.ord() as i64,
+            "id": self.id,
+            "return": self.return_type.to_dictionary(),
+        }
+    }
+
     /// Consumes self and turns it into a `sys::GDExtensionMethodInfo`, should be used together with
     /// [`free_owned_method_sys`](Self::free_owned_method_sys).
     ///
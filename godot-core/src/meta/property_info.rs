@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::builtin::{GString, StringName};
+use crate::builtin::{dict, Dictionary, GString, StringName};
 use crate::global::{PropertyHint, PropertyUsageFlags};
 use crate::meta::{
     element_godot_type_name, ArrayElement, ClassName, GodotType, PackedArrayElement,
@@ -134,6 +134,20 @@ impl PropertyInfo {
         }
     }
 
+    /// Converts to a dictionary, as used by Godot's reflection APIs (e.g. [`Object::get_property_list()`][crate::classes::Object::get_property_list]).
+    ///
+    /// Contains the keys `name`, `class_name`, `type`, `hint`, `hint_string` and `usage`.
+    pub fn to_dictionary(&self) -> Dictionary {
+        dict! {
+            "name": self.property_name.clone(),
+            "class_name": self.class_name.to_string_name(),
+            "type": self.variant_type.ord(),
+            "hint": self.hint_info.hint.ord(),
+            "hint_string": self.hint_info.hint_string.clone(),
+            "usage": self.usage.ord() as i64,
+        }
+    }
+
     /// Converts to the FFI type. Keep this object allocated while using that!
     pub fn property_sys(&self) -> sys::GDExtensionPropertyInfo {
         use crate::obj::EngineBitfield as _;
@@ -43,6 +43,17 @@ pub trait VarcallSignatureTuple: PtrcallSignatureTuple {
         func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Self::Ret,
     ) -> Result<(), CallError>;
 
+    /// Like [`in_varcall()`][Self::in_varcall], but for variadic functions: arguments beyond `PARAM_COUNT` are passed as a slice.
+    unsafe fn in_varcall_varargs(
+        instance_ptr: sys::GDExtensionClassInstancePtr,
+        call_ctx: &CallContext,
+        args_ptr: *const sys::GDExtensionConstVariantPtr,
+        arg_count: i64,
+        ret: sys::GDExtensionVariantPtr,
+        err: *mut sys::GDExtensionCallError,
+        func: fn(sys::GDExtensionClassInstancePtr, Self::Params, &[Variant]) -> Self::Ret,
+    ) -> Result<(), CallError>;
+
     unsafe fn out_class_varcall(
         method_bind: ClassMethodBind,
         // Separate parameters to reduce tokens in generated class API.
@@ -194,6 +205,35 @@ macro_rules! impl_varcall_signature_for_tuple {
                 Ok(())
             }
 
+            #[inline]
+            unsafe fn in_varcall_varargs(
+                instance_ptr: sys::GDExtensionClassInstancePtr,
+                call_ctx: &CallContext,
+                args_ptr: *const sys::GDExtensionConstVariantPtr,
+                arg_count: i64,
+                ret: sys::GDExtensionVariantPtr,
+                err: *mut sys::GDExtensionCallError,
+                func: fn(sys::GDExtensionClassInstancePtr, Self::Params, &[Variant]) -> Self::Ret,
+            ) -> Result<(), CallError> {
+                //$crate::out!("in_varcall_varargs: {call_ctx}");
+                CallError::check_min_arg_count(call_ctx, arg_count as usize, $PARAM_COUNT)?;
+
+                #[cfg(feature = "trace")]
+                trace::push(true, false, &call_ctx);
+
+                let args = ($(
+                    unsafe { varcall_arg::<$Pn, $n>(args_ptr, call_ctx)? },
+                )*) ;
+
+                let varargs = ($PARAM_COUNT..arg_count as usize)
+                    .map(|i| Variant::borrow_var_sys(*args_ptr.add(i)).clone())
+                    .collect::<Vec<_>>();
+
+                let rust_result = func(instance_ptr, args, &varargs);
+                varcall_return::<$R>(rust_result, ret, err);
+                Ok(())
+            }
+
             #[inline]
             unsafe fn out_class_varcall(
                 method_bind: ClassMethodBind,
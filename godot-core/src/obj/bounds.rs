@@ -47,8 +47,9 @@
 //!
 // Note that depending on if you want to exclude `Object`, you should use `DynMemory` instead of `Memory`.
 
+use crate::meta::ClassName;
 use crate::obj::cap::GodotDefault;
-use crate::obj::{Bounds, Gd, GodotClass, RawGd};
+use crate::obj::{Bounds, Gd, GdDerefTarget, GodotClass, RawGd};
 use crate::storage::{InstanceCache, Storage};
 use crate::{out, sys};
 use private::Sealed;
@@ -369,6 +370,14 @@ pub trait Declarer: Sealed {
     fn create_gd<T>() -> Gd<T>
     where
         T: GodotDefault + Bounds<Declarer = Self>;
+
+    /// Name of the engine class that is instantiated to back an object of class `T`.
+    ///
+    /// For engine classes, this is `T` itself; for user classes, the closest engine class in the inheritance chain.
+    #[doc(hidden)]
+    fn native_class_name<T>() -> ClassName
+    where
+        T: GodotClass + Bounds<Declarer = Self>;
 }
 
 /// Expresses that a class is declared by the Godot engine.
@@ -395,13 +404,21 @@ impl Declarer for DeclEngine {
             Gd::from_obj_sys(object_ptr)
         }
     }
+
+    fn native_class_name<T>() -> ClassName
+    where
+        T: GodotClass + Bounds<Declarer = Self>,
+    {
+        T::class_name()
+    }
 }
 
 /// Expresses that a class is declared by the user.
 pub enum DeclUser {}
 impl Sealed for DeclUser {}
 impl Declarer for DeclUser {
-    type DerefTarget<T: GodotClass> = T::Base;
+    // Recurses for user classes inheriting other user classes, until an engine class is reached.
+    type DerefTarget<T: GodotClass> = GdDerefTarget<T::Base>;
     type InstanceCache = std::cell::Cell<sys::GDExtensionClassInstancePtr>;
 
     unsafe fn is_currently_bound<T>(obj: &RawGd<T>) -> bool
@@ -417,6 +434,13 @@ impl Declarer for DeclUser {
     {
         Gd::default_instance()
     }
+
+    fn native_class_name<T>() -> ClassName
+    where
+        T: GodotClass + Bounds<Declarer = Self>,
+    {
+        <<T::Base as Bounds>::Declarer as Declarer>::native_class_name::<T::Base>()
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
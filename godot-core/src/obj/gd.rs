@@ -726,6 +726,20 @@ where
     }
 }
 
+#[cfg(feature = "codegen-full")]
+impl<T> Gd<T>
+where
+    T: crate::obj::WithUserRpcs,
+{
+    /// Access user-defined RPCs of this object.
+    ///
+    /// Returns a collection with a method for each `#[rpc]`, allowing to send it in a type-safe way. This method is the equivalent of
+    /// [`WithUserRpcs::rpcs()`][crate::obj::WithUserRpcs::rpcs], but when called externally (not from `self`).
+    pub fn rpcs(&self) -> T::RpcCollection {
+        T::__rpcs_from_node(self.clone().upcast())
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Trait impls
 
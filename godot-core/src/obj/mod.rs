@@ -18,7 +18,9 @@ mod guards;
 mod instance_id;
 mod on_editor;
 mod on_ready;
+mod proxy;
 mod raw_gd;
+mod scene_nodes;
 mod traits;
 
 pub(crate) mod rtti;
@@ -30,7 +32,9 @@ pub use guards::{BaseMut, BaseRef, DynGdMut, DynGdRef, GdMut, GdRef};
 pub use instance_id::*;
 pub use on_editor::*;
 pub use on_ready::*;
+pub use proxy::*;
 pub use raw_gd::*;
+pub use scene_nodes::*;
 pub use traits::*;
 
 pub mod bounds;
@@ -38,6 +38,16 @@ use crate::registry::property::{BuiltinExport, Export, Var};
 /// `OnEditor<T>` should always be used as a struct field, preferably in tandem with an `#[export]` or `#[var]`.
 /// Initializing `OnEditor` values via code before the first use is supported, but should be limited to use cases involving builder or factory patterns.
 ///
+/// # Configuration warnings
+///
+/// In `#[class(tool)]` node classes, uninitialized `OnEditor` fields are reported as configuration warnings, so the editor shows a warning
+/// icon next to the node while the scene is being edited. If the class implements `get_configuration_warnings()` itself, the generated
+/// warnings are appended to the returned ones.
+///
+/// When an `OnEditor` field with `#[export]` is changed through its generated setter, the warnings are refreshed automatically. This
+/// requires a `Base<T>` field. Custom setters should call [`Node::update_configuration_warnings()`](crate::classes::Node::update_configuration_warnings)
+/// themselves.
+///
 ///
 /// # Using `OnEditor` with classes
 ///
@@ -8,7 +8,7 @@
 use crate::builtin::{GString, NodePath};
 use crate::classes::{Node, Resource};
 use crate::meta::{arg_into_owned, AsArg, GodotConvert};
-use crate::obj::{Gd, Inherits};
+use crate::obj::{Gd, Inherits, SceneNodes};
 use crate::registry::property::Var;
 use std::fmt::{self, Debug, Formatter};
 use std::mem;
@@ -27,7 +27,7 @@ use std::mem;
 /// `OnReady<T>` should always be used as a struct field. There are two modes to use it:
 ///
 /// 1. **Automatic mode, using [`new()`](OnReady::new), [`from_base_fn()`](OnReady::from_base_fn),
-///    [`from_node()`][Self::from_node] or [`from_loaded()`][Self::from_loaded].**<br>
+///    [`from_node()`][Self::from_node], [`from_loaded()`][Self::from_loaded] or [`from_scene_nodes()`][Self::from_scene_nodes].**<br>
 ///    Before `ready()` is called, all `OnReady` fields constructed with the above methods are automatically initialized,
 ///    in the order of declaration. This means that you can safely access them in `ready()`.<br>
 /// 2. **Manual mode, using [`manual()`](Self::manual).**<br>
@@ -163,6 +163,23 @@ impl<T: Inherits<Resource>> OnReady<Gd<T>> {
     }
 }
 
+impl<T: SceneNodes + 'static> OnReady<T> {
+    /// Variant of [`OnReady::new()`], binding all nodes of a [`SceneNodes`] struct before `ready()`.
+    ///
+    /// Node paths are resolved relative to the base node. See [`SceneNodes::from_node()`] for details.
+    ///
+    /// When used with `#[class(init)]`, the field can be annotated with `#[init(scene_nodes)]` to call this constructor.
+    ///
+    /// # Panics (deferred)
+    /// - If any node path of `T` does not point to a valid node, or the node's type does not match. The panic message lists all such paths.
+    ///
+    /// Note that the panic will only happen if and when the node enters the SceneTree for the first time
+    /// (i.e. it receives the `READY` notification).
+    pub fn from_scene_nodes() -> Self {
+        Self::from_base_fn(|base| T::from_node(base))
+    }
+}
+
 impl<T> OnReady<T> {
     /// Schedule automatic initialization before `ready()`.
     ///
@@ -0,0 +1,142 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{Dictionary, VariantArray};
+use crate::classes::Object;
+use crate::global::MethodFlags;
+use crate::meta::error::ProxyError;
+use crate::obj::{EngineBitfield, Gd, Inherits};
+
+/// Typed view on an object whose methods are resolved dynamically, e.g. a GDScript instance.
+///
+/// This trait is implemented by the `{Trait}Proxy` structs generated by the [`#[godot_proxy]`](../register/attr.godot_proxy.html)
+/// attribute. Calls through the proxy dispatch by name to [`Object::call()`], converting arguments and return values from/to Rust types.
+///
+/// On construction, the object is checked to provide every method declared in the trait, with a compatible number of parameters.
+/// This catches renamed or missing GDScript methods early, instead of at the first call site.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[godot_proxy]
+/// trait EnemyApi {
+///     fn take_damage(&mut self, amount: i32) -> bool;
+///     fn get_health(&self) -> i32;
+/// }
+///
+/// fn hit(enemy: Gd<Node>) {
+///     let mut enemy = EnemyApiProxy::from_object(enemy);
+///     let died = enemy.take_damage(10);
+///     godot_print!("health: {}, died: {died}", enemy.get_health());
+/// }
+/// ```
+pub trait GodotProxy: Sized {
+    #[doc(hidden)]
+    const __PROXY_NAME: &'static str;
+
+    /// Name and parameter count of every method declared in the proxy trait.
+    #[doc(hidden)]
+    const __METHODS: &'static [(&'static str, usize)];
+
+    #[doc(hidden)]
+    fn __from_object_unchecked(object: Gd<Object>) -> Self;
+
+    /// The object that calls are dispatched to.
+    fn object(&self) -> &Gd<Object>;
+
+    /// Returns the object that calls are dispatched to, consuming the proxy.
+    fn into_object(self) -> Gd<Object>;
+
+    /// Creates a proxy for `object`, if the object provides all methods declared in the proxy trait.
+    fn try_from_object<T>(object: Gd<T>) -> Result<Self, ProxyError>
+    where
+        T: Inherits<Object>,
+    {
+        let object = object.upcast::<Object>();
+        validate_methods(&object, Self::__PROXY_NAME, Self::__METHODS)?;
+
+        Ok(Self::__from_object_unchecked(object))
+    }
+
+    /// ⚠️ Creates a proxy for `object`, panicking if the object does not provide all methods declared in the proxy trait.
+    ///
+    /// # Panics
+    /// If [`try_from_object()`][Self::try_from_object] fails.
+    fn from_object<T>(object: Gd<T>) -> Self
+    where
+        T: Inherits<Object>,
+    {
+        Self::try_from_object(object).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+fn validate_methods(
+    object: &Gd<Object>,
+    proxy_name: &'static str,
+    methods: &[(&'static str, usize)],
+) -> Result<(), ProxyError> {
+    let mut error = ProxyError::new(proxy_name, object.get_class().to_string());
+
+    // Only fetched if needed; get_method_list() builds a dictionary per method.
+    let mut method_list = None;
+
+    for &(method_name, param_count) in methods {
+        if !object.has_method(method_name) {
+            error.add_missing(method_name);
+            continue;
+        }
+
+        let method_list = method_list.get_or_insert_with(|| object.get_method_list());
+        let Some(method) = method_list.iter_shared().find(|dict| {
+            dict.get("name")
+                .is_some_and(|name| name.to_string() == method_name)
+        }) else {
+            // Some objects (e.g. scripts providing methods via _call) don't list all callable methods; trust has_method().
+            continue;
+        };
+
+        if let Some(actual_params) = incompatible_arity(&method, param_count) {
+            error.add_mismatch(method_name, param_count, actual_params);
+        }
+    }
+
+    if error.has_problems() {
+        Err(error)
+    } else {
+        Ok(())
+    }
+}
+
+/// If `method` cannot be called with `param_count` arguments, returns a description of the accepted parameter count.
+fn incompatible_arity(method: &Dictionary, param_count: usize) -> Option<String> {
+    let flags = method
+        .get("flags")
+        .and_then(|flags| flags.try_to::<i64>().ok())
+        .unwrap_or(0);
+    if flags as u64 & MethodFlags::VARARG.ord() != 0 {
+        return None;
+    }
+
+    let len_of = |key: &str| {
+        method
+            .get(key)
+            .and_then(|array| array.try_to::<VariantArray>().ok())
+            .map_or(0, |array| array.len())
+    };
+
+    let max_params = len_of("args");
+    let min_params = max_params.saturating_sub(len_of("default_args"));
+
+    if (min_params..=max_params).contains(&param_count) {
+        None
+    } else if min_params == max_params {
+        Some(max_params.to_string())
+    } else {
+        Some(format!("{min_params}..={max_params}"))
+    }
+}
@@ -0,0 +1,139 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::classes::Node;
+use crate::meta::error::SceneNodesError;
+use crate::obj::{Gd, GodotClass, Inherits};
+
+/// Struct whose fields are bound to nodes of a scene, by path.
+///
+/// This trait is implemented via [`#[derive(SceneNodes)]`](../register/derive.SceneNodes.html). Each field annotated with
+/// `#[node("path")]` is looked up relative to a root node, typically the root of an instantiated scene. Fields of type `Gd<T>` are
+/// required, fields of type `Option<Gd<T>>` may be absent. All other fields are initialized with `Default::default()`.
+///
+/// All paths are resolved at once; if any node is missing or has the wrong type, the returned [`SceneNodesError`] lists every problem,
+/// not just the first one.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::{Label, ProgressBar};
+///
+/// #[derive(SceneNodes)]
+/// struct HudNodes {
+///     #[node("Top/Health")]
+///     health: Gd<ProgressBar>,
+///
+///     #[node("Top/Score")]
+///     score: Gd<Label>,
+///
+///     #[node("Debug")]
+///     debug: Option<Gd<Label>>,
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Control)]
+/// struct Hud {
+///     // Resolved before ready(), relative to this node.
+///     #[init(scene_nodes)]
+///     nodes: OnReady<HudNodes>,
+/// }
+/// ```
+pub trait SceneNodes: Sized {
+    #[doc(hidden)]
+    const __STRUCT_NAME: &'static str;
+
+    /// Resolves all fields; returns `None` if any required node could not be bound. Problems are recorded in `error`.
+    #[doc(hidden)]
+    fn __resolve(root: &Gd<Node>, error: &mut SceneNodesError) -> Option<Self>;
+
+    /// Binds all nodes, with paths relative to `root`.
+    fn try_from_node<T>(root: &Gd<T>) -> Result<Self, SceneNodesError>
+    where
+        T: Inherits<Node>,
+    {
+        let root = root.clone().upcast::<Node>();
+
+        let root_path = if root.is_inside_tree() {
+            root.get_path().to_string()
+        } else {
+            root.get_name().to_string()
+        };
+        let mut error = SceneNodesError::new(Self::__STRUCT_NAME, root_path);
+
+        match Self::__resolve(&root, &mut error) {
+            Some(nodes) if !error.has_problems() => Ok(nodes),
+            _ => Err(error),
+        }
+    }
+
+    /// ⚠️ Binds all nodes, with paths relative to `root`, panicking if any node is missing or has the wrong type.
+    ///
+    /// # Panics
+    /// If [`try_from_node()`][Self::try_from_node] fails.
+    fn from_node<T>(root: &Gd<T>) -> Self
+    where
+        T: Inherits<Node>,
+    {
+        Self::try_from_node(root).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+/// Field type that can be annotated with `#[node]` inside `#[derive(SceneNodes)]`.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "`#[node]` fields must be of type `Gd<T>` or `Option<Gd<T>>`, where `T` inherits `Node`",
+    label = "not a node type"
+)]
+pub trait SceneNodeField: Sized {
+    /// Class of the node, used for compile-time checks against `.tscn` files.
+    type Class: Inherits<Node>;
+
+    /// Returns `None` if the node could not be bound. Problems are recorded in `error`.
+    fn __resolve_field(root: &Gd<Node>, path: &str, error: &mut SceneNodesError) -> Option<Self>;
+}
+
+impl<T: Inherits<Node>> SceneNodeField for Gd<T> {
+    type Class = T;
+
+    fn __resolve_field(root: &Gd<Node>, path: &str, error: &mut SceneNodesError) -> Option<Self> {
+        let Some(node) = root.get_node_or_null(path) else {
+            error.add_missing(path, T::class_name().to_string());
+            return None;
+        };
+
+        cast_node(node, path, error)
+    }
+}
+
+impl<T: Inherits<Node>> SceneNodeField for Option<Gd<T>> {
+    type Class = T;
+
+    fn __resolve_field(root: &Gd<Node>, path: &str, error: &mut SceneNodesError) -> Option<Self> {
+        match root.get_node_or_null(path) {
+            Some(node) => cast_node(node, path, error).map(Some),
+            None => Some(None),
+        }
+    }
+}
+
+fn cast_node<T>(node: Gd<Node>, path: &str, error: &mut SceneNodesError) -> Option<Gd<T>>
+where
+    T: Inherits<Node>,
+{
+    match node.try_cast::<T>() {
+        Ok(node) => Some(node),
+        Err(node) => {
+            error.add_mismatch(
+                path,
+                T::class_name().to_string(),
+                node.get_class().to_string(),
+            );
+            None
+        }
+    }
+}
@@ -169,6 +169,75 @@ pub trait ScriptInstance: Sized {
     fn get_method_argument_count(&self, _method: StringName) -> Option<u32>;
 }
 
+/// Script and language of a script instance, whose remaining [`ScriptInstance`] methods are derived.
+///
+/// `#[derive(ScriptInstance)]` generates property access, reflection and call dispatch from `#[var]`/`#[export]` fields and `#[func]`
+/// methods. The only two methods which cannot be derived are declared by this trait, and must be implemented by hand.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::{Script, ScriptLanguage};
+/// use godot::obj::script::ScriptInstanceSource;
+/// use godot::register::ScriptInstance;
+///
+/// #[derive(ScriptInstance)]
+/// #[script_instance(base = Node, class_name = "Player")]
+/// struct PlayerInstance {
+///     #[var]
+///     health: i64,
+///
+///     script: Gd<Script>,
+///     language: Gd<ScriptLanguage>,
+/// }
+///
+/// // Declares the #[func] methods; optional.
+/// #[godot_api(script_instance)]
+/// impl PlayerInstance {
+///     #[func]
+///     fn heal(&mut self, amount: i64) -> i64 {
+///         self.health += amount;
+///         self.health
+///     }
+/// }
+///
+/// impl ScriptInstanceSource for PlayerInstance {
+///     fn get_script(&self) -> &Gd<Script> {
+///         &self.script
+///     }
+///
+///     fn get_language(&self) -> Gd<ScriptLanguage> {
+///         self.language.clone()
+///     }
+/// }
+/// ```
+pub trait ScriptInstanceSource {
+    /// The script this instance was created for. See [`ScriptInstance::get_script()`].
+    fn get_script(&self) -> &Gd<Script>;
+
+    /// The language this instance belongs to. See [`ScriptInstance::get_language()`].
+    fn get_language(&self) -> Gd<ScriptLanguage>;
+}
+
+/// Reflection and call dispatch for `#[func]` methods of a derived script instance.
+///
+/// Implemented by `#[godot_api(script_instance)]`. Without such a block, the derive falls back to no methods, see
+/// [`ScriptApi`](crate::private::ScriptApi).
+#[doc(hidden)]
+pub trait ScriptInstanceApi {
+    fn __script_methods() -> Vec<MethodInfo>;
+
+    /// Returns `None` if there is no `#[func]` with the given name.
+    fn __script_method_argument_count(method: &str) -> Option<u32>;
+
+    /// Returns `None` if there is no `#[func]` with the given name.
+    fn __script_call(
+        &mut self,
+        method: &str,
+        args: &[&Variant],
+    ) -> Option<Result<Variant, sys::GDExtensionCallErrorType>>;
+}
+
 #[cfg(before_api = "4.2")]
 type ScriptInstanceInfo = sys::GDExtensionScriptInstanceInfo;
 #[cfg(all(since_api = "4.2", before_api = "4.3"))]
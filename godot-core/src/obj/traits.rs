@@ -6,7 +6,7 @@
  */
 
 use crate::builder::ClassBuilder;
-use crate::builtin::GString;
+use crate::builtin::{GString, PackedStringArray};
 use crate::init::InitLevel;
 use crate::meta::ClassName;
 use crate::obj::{bounds, Base, BaseMut, BaseRef, Bounds, Gd};
@@ -30,7 +30,9 @@ pub trait GodotClass: Bounds + 'static
 where
     Self: Sized,
 {
-    /// The immediate superclass of `T`. This is always a Godot engine class.
+    /// The immediate superclass of `T`.
+    ///
+    /// For engine classes, this is always another engine class. User classes can additionally inherit other user classes.
     type Base: GodotClass; // not EngineClass because it can be ()
 
     /// The name of the class, under which it is registered in Godot.
@@ -58,6 +60,30 @@ where
             Self::Base::inherits::<U>()
         }
     }
+
+    /// Whether the Rust part of `Self` can be constructed, which is needed if another user class inherits `Self`.
+    ///
+    /// False for user classes without init. Always true for engine classes, which have no Rust part.
+    #[doc(hidden)]
+    const __IS_INSTANTIABLE: bool = true;
+
+    /// Constructs the Rust part of `Self` for an existing object, when `Self` acts as the base of another user class.
+    ///
+    /// Returns `None` for engine classes, which have no Rust part.
+    #[doc(hidden)]
+    fn __godot_inherited_storage(
+        _object_ptr: sys::GDExtensionObjectPtr,
+    ) -> Option<crate::storage::InheritedStorage> {
+        None
+    }
+    /// Runs `__before_ready()` (e.g. `OnReady` initialization) of `Self` and its Rust base classes, from the root towards `Self`.
+    ///
+    /// Does nothing for engine classes.
+    ///
+    /// # Safety
+    /// `instance_ptr` must point to the instance of a class which is `Self` or derives from it.
+    #[doc(hidden)]
+    unsafe fn __godot_before_ready_chain(_instance_ptr: sys::GDExtensionClassInstancePtr) {}
 }
 
 /// Type representing the absence of a base class, at the root of the hierarchy.
@@ -138,6 +164,19 @@ pub unsafe trait Inherits<Base: GodotClass>: GodotClass {}
 // SAFETY: Every class is a subclass of itself.
 unsafe impl<T: GodotClass> Inherits<T> for T {}
 
+/// Links a class to the engine class that is closest to it in the inheritance chain.
+///
+/// For engine classes, this is the class itself. For user classes, it is usually `Base` -- unless the class inherits another user class,
+/// in which case that one's native base is used. Determines which `I*` interface trait a user class can implement.
+#[doc(hidden)]
+pub trait WithNativeBase: GodotClass {
+    type NativeBase: GodotClass;
+}
+
+impl<T: GodotClass> WithNativeBase for T {
+    type NativeBase = super::GdDerefTarget<T>;
+}
+
 /// Trait that defines a `T` -> `dyn Trait` relation for use in [`DynGd`][crate::obj::DynGd].
 ///
 /// You should typically not implement this manually, but use the [`#[godot_dyn]`](../register/attr.godot_dyn.html) macro.
@@ -162,6 +201,18 @@ pub trait UserClass: Bounds<Declarer = bounds::DeclUser> {
     #[doc(hidden)]
     fn __before_ready(&mut self);
 
+    /// Whether `#[derive(GodotClass)]` generates [`__configuration_warnings()`][Self::__configuration_warnings].
+    #[doc(hidden)]
+    const __HAS_CONFIGURATION_WARNINGS: bool = false;
+
+    /// Configuration warnings generated by `#[derive(GodotClass)]`, e.g. for unset `OnEditor` fields in `#[class(tool)]` classes.
+    ///
+    /// Merged with the user's `get_configuration_warnings()`, if implemented.
+    #[doc(hidden)]
+    fn __configuration_warnings(&self) -> PackedStringArray {
+        PackedStringArray::new()
+    }
+
     #[doc(hidden)]
     fn __default_virtual_call(
         _method_name: &str,
@@ -506,6 +557,27 @@ pub trait WithUserSignals: WithSignals + WithBaseField {
     fn signals(&mut self) -> Self::SignalCollection<'_, Self>;
 }
 
+/// Implemented for user-defined classes with at least one `#[rpc]` method.
+///
+/// Allows to send RPCs in a type-safe way, as `self.rpcs().my_rpc(args...).to_all()`. This requires a `Base<T>` field.
+///
+/// From outside the class, use [`Gd::rpcs()`] instead.
+#[cfg(feature = "codegen-full")]
+pub trait WithUserRpcs: WithBaseField + Inherits<crate::classes::Node> {
+    /// The associated struct listing all RPCs of this class.
+    ///
+    /// Contains a method for each `#[rpc]`, with the same name and parameters. Each returns a [`RpcCall`][crate::registry::RpcCall].
+    type RpcCollection;
+
+    #[doc(hidden)]
+    fn __rpcs_from_node(node: Gd<crate::classes::Node>) -> Self::RpcCollection;
+
+    /// Access RPCs of the current object `self`.
+    fn rpcs(&self) -> Self::RpcCollection {
+        Self::__rpcs_from_node(self.to_gd().upcast())
+    }
+}
+
 /// Extension trait for all reference-counted classes.
 pub trait NewGd: GodotClass {
     /// Return a new, ref-counted `Gd` containing a default-constructed instance.
@@ -14,7 +14,9 @@ pub use crate::registry::plugin::{
 };
 #[cfg(since_api = "4.2")]
 pub use crate::registry::signal::priv_re_export::*;
-pub use crate::storage::{as_storage, Storage};
+pub use crate::storage::{as_storage, InheritedStorage, Storage};
+#[cfg(since_api = "4.2")]
+pub use crate::task::async_func::spawn_async_func;
 pub use sys::out;
 
 #[cfg(feature = "trace")]
@@ -184,6 +186,22 @@ pub unsafe fn has_virtual_script_method(
     sys::interface_fn!(object_has_script_method)(sys::to_const_ptr(object_ptr), method_sname) != 0
 }
 
+/// Runs `ready()` for the Rust base classes of `T`, when `T` itself doesn't override it.
+///
+/// If a Rust base class overrides `ready()`, that one is invoked. Otherwise, only their `__before_ready()` (`OnReady` fields etc.) runs.
+///
+/// # Safety
+/// `instance_ptr` must point to the instance of a class which is `T` or derives from it.
+pub unsafe fn ready_inherited<T: crate::obj::GodotClass>(
+    instance_ptr: sys::GDExtensionClassInstancePtr,
+) {
+    use crate::obj::GodotClass;
+
+    if !crate::registry::class::call_inherited_ready(T::Base::class_name(), instance_ptr) {
+        T::Base::__godot_before_ready_chain(instance_ptr);
+    }
+}
+
 /// Ensure `T` is an editor plugin.
 pub const fn is_editor_plugin<T: crate::obj::Inherits<crate::classes::EditorPlugin>>() {}
 
@@ -217,6 +235,250 @@ pub fn is_class_runtime(is_tool: bool) -> bool {
     global_config.tool_only_in_editor
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Default arguments
+
+/// Varcall arguments, where omitted trailing parameters have been filled with their default values.
+pub struct FilledDefaultArgs {
+    // Owns the variants that `args` points to.
+    _defaults: Vec<crate::builtin::Variant>,
+    args: Vec<sys::GDExtensionConstVariantPtr>,
+}
+
+impl FilledDefaultArgs {
+    pub fn args_ptr(&self) -> *const sys::GDExtensionConstVariantPtr {
+        self.args.as_ptr()
+    }
+
+    pub fn arg_count(&self) -> sys::GDExtensionInt {
+        self.args.len() as sys::GDExtensionInt
+    }
+}
+
+/// Appends default values for trailing parameters not provided by a varcall.
+///
+/// Returns `None` if no arguments are missing, or if too many are missing to be covered by defaults. In the latter case, the regular
+/// argument-count check reports the error.
+///
+/// # Safety
+/// `args_ptr` must point to `arg_count` valid variant pointers.
+pub unsafe fn fill_default_args(
+    args_ptr: *const sys::GDExtensionConstVariantPtr,
+    arg_count: sys::GDExtensionInt,
+    param_count: usize,
+    default_args: impl FnOnce() -> Vec<crate::builtin::Variant>,
+) -> Option<FilledDefaultArgs> {
+    let arg_count = usize::try_from(arg_count).ok()?;
+    if arg_count >= param_count {
+        return None;
+    }
+
+    let defaults = default_args();
+    let missing = param_count - arg_count;
+    if missing > defaults.len() {
+        return None;
+    }
+
+    let mut args = Vec::with_capacity(param_count);
+    if arg_count > 0 {
+        args.extend_from_slice(std::slice::from_raw_parts(args_ptr, arg_count));
+    }
+    args.extend(
+        defaults[defaults.len() - missing..]
+            .iter()
+            .map(|v| v.var_sys()),
+    );
+
+    Some(FilledDefaultArgs {
+        _defaults: defaults,
+        args,
+    })
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Derived script instances
+
+/// Reflection info of a `#[func]` in a derived script instance.
+pub fn script_method_info<Sig>(
+    id: i32,
+    method_name: &str,
+    param_names: &[&str],
+    flags: crate::global::MethodFlags,
+    default_arguments: Vec<crate::builtin::Variant>,
+) -> crate::meta::MethodInfo
+where
+    Sig: crate::meta::VarcallSignatureTuple,
+    Sig::Ret: crate::meta::GodotConvert,
+{
+    use crate::meta::{GodotConvert, GodotType, VarcallSignatureTuple};
+
+    crate::meta::MethodInfo {
+        id,
+        method_name: method_name.into(),
+        class_name: crate::meta::ClassName::none(),
+        return_type: <<Sig::Ret as GodotConvert>::Via as GodotType>::property_info(""),
+        arguments: param_names
+            .iter()
+            .enumerate()
+            .map(|(index, name)| Sig::param_property_info(index, name))
+            .collect(),
+        default_arguments,
+        flags,
+    }
+}
+
+/// Arguments of a call to a derived script instance, with omitted trailing parameters filled by their default values.
+pub fn script_call_args(
+    args: &[&crate::builtin::Variant],
+    param_count: usize,
+    default_args: impl FnOnce() -> Vec<crate::builtin::Variant>,
+) -> Result<Vec<crate::builtin::Variant>, sys::GDExtensionCallErrorType> {
+    if args.len() > param_count {
+        return Err(sys::GDEXTENSION_CALL_ERROR_TOO_MANY_ARGUMENTS);
+    }
+
+    let mut filled: Vec<_> = args.iter().map(|&arg| arg.clone()).collect();
+    if filled.len() < param_count {
+        let defaults = default_args();
+        let missing = param_count - filled.len();
+        if missing > defaults.len() {
+            return Err(sys::GDEXTENSION_CALL_ERROR_TOO_FEW_ARGUMENTS);
+        }
+
+        filled.extend_from_slice(&defaults[defaults.len() - missing..]);
+    }
+
+    Ok(filled)
+}
+
+/// Converts one argument of a call to a derived script instance.
+pub fn script_arg<T: crate::meta::FromGodot>(
+    arg: &crate::builtin::Variant,
+) -> Result<T, sys::GDExtensionCallErrorType> {
+    T::try_from_variant(arg).map_err(|_| sys::GDEXTENSION_CALL_ERROR_INVALID_ARGUMENT)
+}
+
+/// Methods of a derived script instance, which may or may not have a `#[godot_api(script_instance)]` block.
+///
+/// Uses autoref specialization: `(&&ScriptApi::<T>::new()).script_methods()` resolves to [`ScriptApiImplemented`] if `T` implements
+/// [`ScriptInstanceApi`], and to [`ScriptApiMissing`] (no methods) otherwise. This only works for concrete types, as in generated code.
+///
+/// [`ScriptInstanceApi`]: crate::obj::script::ScriptInstanceApi
+pub struct ScriptApi<T>(std::marker::PhantomData<T>);
+
+impl<T> ScriptApi<T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self(std::marker::PhantomData)
+    }
+}
+
+pub trait ScriptApiImplemented<T> {
+    fn script_methods(&self) -> Vec<crate::meta::MethodInfo>;
+
+    fn script_method_argument_count(&self, method: &str) -> Option<u32>;
+
+    fn script_call(
+        &self,
+        instance: &mut T,
+        method: &str,
+        args: &[&crate::builtin::Variant],
+    ) -> Option<Result<crate::builtin::Variant, sys::GDExtensionCallErrorType>>;
+}
+
+impl<T: crate::obj::script::ScriptInstanceApi> ScriptApiImplemented<T> for &ScriptApi<T> {
+    fn script_methods(&self) -> Vec<crate::meta::MethodInfo> {
+        T::__script_methods()
+    }
+
+    fn script_method_argument_count(&self, method: &str) -> Option<u32> {
+        T::__script_method_argument_count(method)
+    }
+
+    fn script_call(
+        &self,
+        instance: &mut T,
+        method: &str,
+        args: &[&crate::builtin::Variant],
+    ) -> Option<Result<crate::builtin::Variant, sys::GDExtensionCallErrorType>> {
+        instance.__script_call(method, args)
+    }
+}
+
+pub trait ScriptApiMissing<T> {
+    fn script_methods(&self) -> Vec<crate::meta::MethodInfo> {
+        Vec::new()
+    }
+
+    fn script_method_argument_count(&self, _method: &str) -> Option<u32> {
+        None
+    }
+
+    fn script_call(
+        &self,
+        _instance: &mut T,
+        _method: &str,
+        _args: &[&crate::builtin::Variant],
+    ) -> Option<Result<crate::builtin::Variant, sys::GDExtensionCallErrorType>> {
+        None
+    }
+}
+
+impl<T> ScriptApiMissing<T> for ScriptApi<T> {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Configuration warnings
+
+/// Called by generated setters of `OnEditor` exports in `#[class(tool)]` classes, so the editor re-queries configuration warnings.
+pub fn update_configuration_warnings<T>(this: &T)
+where
+    T: crate::obj::WithBaseField + crate::obj::Inherits<classes::Object>,
+{
+    if !classes::Engine::singleton().is_editor_hint() {
+        return;
+    }
+
+    // Deferred: the editor may query warnings right away, while the instance is still bound by the setter.
+    if let Ok(mut node) = this
+        .to_gd()
+        .upcast::<classes::Object>()
+        .try_cast::<classes::Node>()
+    {
+        node.call_deferred("update_configuration_warnings", &[]);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Typed proxies
+
+/// Dynamic call through a `#[godot_proxy]`, panicking on failure.
+pub fn proxy_call<R: crate::meta::FromGodot>(
+    object: &Gd<classes::Object>,
+    method_name: &str,
+    args: &[crate::builtin::Variant],
+) -> R {
+    let mut object = object.clone();
+    object.call(method_name, args).to::<R>()
+}
+
+/// Dynamic call through a `#[godot_proxy]`, for trait methods returning `Result<R, CallError>`.
+pub fn proxy_try_call<R: crate::meta::FromGodot>(
+    object: &Gd<classes::Object>,
+    proxy_name: &str,
+    method_name: &str,
+    args: &[crate::builtin::Variant],
+) -> Result<R, CallError> {
+    let mut object = object.clone();
+    let result = object.try_call(method_name, args)?;
+
+    result.try_to::<R>().map_err(|err| {
+        CallError::failed_return_conversion::<R>(
+            &CallContext::outbound(proxy_name, method_name),
+            err,
+        )
+    })
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Panic handling
 
@@ -466,7 +728,7 @@ fn report_call_error(call_error: CallError, track_globally: bool) -> i32 {
     }
 }
 
-// Currently unused; implemented due to temporary need and may come in handy.
+/// Obtains a `Gd` from a reference to the engine object, e.g. inside manual class extensions.
 pub fn rebuild_gd(object_ref: &classes::Object) -> Gd<classes::Object> {
     let ptr = object_ref.__object_ptr();
 
@@ -15,10 +15,12 @@ use crate::builtin::{StringName, Variant};
 use crate::classes::Object;
 use crate::meta::PropertyInfo;
 use crate::obj::{bounds, cap, AsDyn, Base, Bounds, Gd, GodotClass, Inherits, UserClass};
+use crate::registry::class::VirtualChain;
 use crate::registry::plugin::ErasedDynGd;
-use crate::storage::{as_storage, InstanceStorage, Storage, StorageRefCounted};
+use crate::storage::{as_storage, InheritedStorage, InstanceStorage, Storage, StorageRefCounted};
 use godot_ffi as sys;
 use std::any::Any;
+use std::ptr;
 use sys::conv::u32_to_usize;
 use sys::interface_fn;
 
@@ -51,7 +53,8 @@ where
     T: GodotClass,
     F: FnOnce(Base<T::Base>) -> T,
 {
-    let base_class_name = T::Base::class_name();
+    // If `T` inherits another user class, the object must be created from the closest engine class.
+    let base_class_name = <T::Declarer as bounds::Declarer>::native_class_name::<T>();
 
     let base_ptr = unsafe { interface_fn!(classdb_construct_object)(base_class_name.string_sys()) };
 
@@ -78,10 +81,7 @@ where
 
     //out!("create callback: {}", class_name.backing);
 
-    let base = unsafe { Base::from_sys(base_ptr) };
-    let user_instance = make_user_instance(unsafe { Base::from_base(&base) });
-
-    let instance = InstanceStorage::<T>::construct(user_instance, base);
+    let instance = make_storage(make_user_instance, base_ptr);
     let instance_ptr = instance.into_raw();
     let instance_ptr = instance_ptr as sys::GDExtensionClassInstancePtr;
 
@@ -100,6 +100,37 @@ where
     instance_ptr
 }
 
+/// Creates the Rust part of a user class `T`, which is used as the base of another user class.
+///
+/// Called from the `GodotClass` impl generated by `#[derive(GodotClass)]`.
+pub fn create_inherited_storage<T: cap::GodotDefault>(
+    base_ptr: sys::GDExtensionObjectPtr,
+) -> InheritedStorage {
+    InheritedStorage::new(make_storage(T::__godot_user_init, base_ptr))
+}
+
+fn make_storage<T, F>(
+    make_user_instance: F,
+    base_ptr: sys::GDExtensionObjectPtr,
+) -> InstanceStorage<T>
+where
+    T: GodotClass,
+    F: FnOnce(Base<T::Base>) -> T,
+{
+    let base = unsafe { Base::from_sys(base_ptr) };
+
+    // Rust base classes are initialized before derived ones, like in C++ or GDScript.
+    let inherited = T::Base::__godot_inherited_storage(base_ptr);
+    let user_instance = make_user_instance(unsafe { Base::from_base(&base) });
+
+    let mut instance = InstanceStorage::<T>::construct(user_instance, base);
+    if let Some(inherited) = inherited {
+        instance.set_inherited(inherited);
+    }
+
+    instance
+}
+
 pub unsafe extern "C" fn free<T: GodotClass>(
     _class_user_data: *mut std::ffi::c_void,
     instance: sys::GDExtensionClassInstancePtr,
@@ -107,6 +138,10 @@ pub unsafe extern "C" fn free<T: GodotClass>(
     {
         let storage = as_storage::<T>(instance);
         storage.mark_destroyed_by_godot();
+
+        // Async #[func] tasks of this object must not outlive it.
+        #[cfg(since_api = "4.2")]
+        crate::task::cancel_owned_tasks(storage.base().instance_id_unchecked());
     } // Ref no longer valid once next statement is executed.
 
     crate::storage::destroy_storage::<T>(instance);
@@ -162,6 +197,35 @@ pub unsafe extern "C" fn default_get_virtual<T: UserClass>(
     T::__default_virtual_call(method_name.as_str())
 }
 
+#[cfg(since_api = "4.4")]
+pub unsafe extern "C" fn get_virtual_inherited(
+    class_user_data: *mut std::ffi::c_void,
+    name: sys::GDExtensionConstStringNamePtr,
+    hash: u32,
+) -> sys::GDExtensionClassCallVirtual {
+    // The user data is set up during registration and lives as long as the class.
+    let chain = &*(class_user_data as *const VirtualChain);
+
+    chain
+        .get_virtual_fns
+        .iter()
+        .find_map(|get_virtual| get_virtual(ptr::null_mut(), name, hash))
+}
+
+#[cfg(before_api = "4.4")]
+pub unsafe extern "C" fn get_virtual_inherited(
+    class_user_data: *mut std::ffi::c_void,
+    name: sys::GDExtensionConstStringNamePtr,
+) -> sys::GDExtensionClassCallVirtual {
+    // The user data is set up during registration and lives as long as the class.
+    let chain = &*(class_user_data as *const VirtualChain);
+
+    chain
+        .get_virtual_fns
+        .iter()
+        .find_map(|get_virtual| get_virtual(ptr::null_mut(), name))
+}
+
 pub unsafe extern "C" fn to_string<T: cap::GodotToString>(
     instance: sys::GDExtensionClassInstancePtr,
     _is_valid: *mut sys::GDExtensionBool,
@@ -241,6 +305,10 @@ pub unsafe extern "C" fn reference<T: GodotClass>(instance: sys::GDExtensionClas
 pub unsafe extern "C" fn unreference<T: GodotClass>(instance: sys::GDExtensionClassInstancePtr) {
     let storage = as_storage::<T>(instance);
     storage.on_dec_ref();
+
+    // Godot only calls this once the reference count drops to 1 or 0. The remaining reference may be held by an async #[func] task.
+    #[cfg(since_api = "4.2")]
+    crate::task::release_owned_tasks(storage.base().instance_id_unchecked());
 }
 
 /// # Safety
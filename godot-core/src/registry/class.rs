@@ -6,9 +6,10 @@
  */
 
 use godot_ffi::join_with;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::{any, ptr};
 
+use crate::builtin::StringName;
 use crate::classes::ClassDb;
 use crate::init::InitLevel;
 use crate::meta::error::{ConvertError, FromGodotError};
@@ -60,12 +61,16 @@ fn global_dyn_traits_by_typeid() -> GlobalGuard<'static, HashMap<any::TypeId, Ve
 pub struct LoadedClass {
     name: ClassName,
     is_editor_plugin: bool,
+
+    /// Passed to Godot as class userdata; must outlive the registration.
+    virtual_chain: Option<Box<VirtualChain>>,
 }
 
 /// Represents a class which is currently loaded and retained in memory -- including metadata.
-//
-// Currently empty, but should already work for per-class queries.
-pub struct ClassMetadata {}
+pub struct ClassMetadata {
+    /// `get_virtual` functions of the class, followed by those of its Rust base classes (if any).
+    virtual_fns: Vec<GodotGetVirtual>,
+}
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
@@ -84,6 +89,15 @@ pub(crate) type GodotGetVirtual = <sys::GDExtensionClassGetVirtual as sys::Inner
 #[cfg(since_api = "4.4")]
 pub(crate) type GodotGetVirtual = <sys::GDExtensionClassGetVirtual2 as sys::Inner>::FnPtr;
 
+/// `get_virtual` functions of a user class inheriting another user class, followed by those of its Rust base classes.
+///
+/// Godot only looks up virtual methods in the most-derived extension class, so lookups are chained through
+/// [`callbacks::get_virtual_inherited`], which receives this list as class userdata.
+#[derive(Debug)]
+pub(crate) struct VirtualChain {
+    pub(crate) get_virtual_fns: Vec<GodotGetVirtual>,
+}
+
 #[derive(Debug)]
 struct ClassRegistrationInfo {
     class_name: ClassName,
@@ -94,6 +108,7 @@ struct ClassRegistrationInfo {
     user_register_fn: Option<ErasedRegisterFn>,
     default_virtual_fn: Option<GodotGetVirtual>, // Optional (set if there is at least one OnReady field)
     user_virtual_fn: Option<GodotGetVirtual>, // Optional (set if there is a `#[godot_api] impl I*`)
+    virtual_chain: Option<Box<VirtualChain>>, // Optional (set if the base class is a Rust class)
 
     /// Godot low-level class creation parameters.
     godot_params: GodotCreationInfo,
@@ -133,6 +148,12 @@ impl ClassRegistrationInfo {
 
         self.component_already_filled[index] = true;
     }
+
+    /// Virtual functions of this class -- if the user provided some via #[godot_api], take those; otherwise, use the
+    /// ones generated alongside #[derive(GodotClass)]. The latter can also be null, if no OnReady is provided.
+    fn own_virtual_fn(&self) -> Option<GodotGetVirtual> {
+        self.user_virtual_fn.or(self.default_virtual_fn)
+    }
 }
 
 /// Registers a class with static type information.
@@ -176,6 +197,7 @@ pub fn register_class<
         }),
         user_virtual_fn: None,
         default_virtual_fn: None,
+        virtual_chain: None,
         godot_params,
         init_level: T::INIT_LEVEL,
         is_editor_plugin: false,
@@ -210,16 +232,21 @@ pub fn auto_register_classes(init_level: InitLevel) {
         fill_class_info(elem.item.clone(), class_info);
     });
 
+    // Godot requires base classes to be registered before derived ones. This matters for Rust classes inheriting other Rust classes.
+    let ordered_names = order_by_inheritance(&map);
+    for &class_name in &ordered_names {
+        inherit_from_rust_base(&mut map, class_name);
+    }
+
     // First register all the loaded classes and dyn traits.
     // We need all the dyn classes in the registry to properly register DynGd properties;
     // one can do it directly inside the loop – by locking and unlocking the mutex –
     // but it is much slower and doesn't guarantee that all the dependent classes will be already loaded in most cases.
-    register_classes_and_dyn_traits(&mut map, init_level);
+    register_classes_and_dyn_traits(&mut map, &ordered_names, init_level);
 
     // Actually register all the classes.
-    for info in map.into_values() {
-        #[cfg(feature = "debug-log")]
-        let class_name = info.class_name;
+    for class_name in ordered_names {
+        let info = map.remove(&class_name).expect("class info present");
 
         register_class_raw(info);
 
@@ -229,23 +256,127 @@ pub fn auto_register_classes(init_level: InitLevel) {
     out!("All classes for level `{init_level:?}` auto-registered.");
 }
 
+/// Returns all class names in `map`, ordered such that each class comes after its base class (if the latter is in `map`, too).
+fn order_by_inheritance(map: &HashMap<ClassName, ClassRegistrationInfo>) -> Vec<ClassName> {
+    fn visit(
+        class_name: ClassName,
+        map: &HashMap<ClassName, ClassRegistrationInfo>,
+        visited: &mut HashSet<ClassName>,
+        ordered: &mut Vec<ClassName>,
+    ) {
+        if !visited.insert(class_name) {
+            return;
+        }
+
+        if let Some(parent) = map[&class_name].parent_class_name {
+            if map.contains_key(&parent) {
+                visit(parent, map, visited, ordered);
+            }
+        }
+
+        ordered.push(class_name);
+    }
+
+    let mut visited = HashSet::new();
+    let mut ordered = Vec::with_capacity(map.len());
+    for &class_name in map.keys() {
+        visit(class_name, map, &mut visited, &mut ordered);
+    }
+
+    ordered
+}
+
+/// If the base of `class_name` is a Rust class registered at the same time, lets the class take over behavior from its base.
+///
+/// Godot itself only invokes the callbacks and virtual-method lookups of the most-derived extension class. Callbacks that the derived
+/// class doesn't provide are thus copied from its base, and virtual lookups are chained through the whole Rust hierarchy.
+///
+/// Must be called in the order returned by [`order_by_inheritance()`].
+fn inherit_from_rust_base(
+    map: &mut HashMap<ClassName, ClassRegistrationInfo>,
+    class_name: ClassName,
+) {
+    let Some(parent) = map[&class_name]
+        .parent_class_name
+        .and_then(|parent_name| map.get(&parent_name))
+    else {
+        return;
+    };
+
+    let parent_params = parent.godot_params;
+    let parent_virtual_fns = match &parent.virtual_chain {
+        Some(chain) => chain.get_virtual_fns.clone(),
+        None => parent.own_virtual_fn().into_iter().collect(),
+    };
+
+    let info = map.get_mut(&class_name).expect("class info present");
+    let params = &mut info.godot_params;
+
+    params.to_string_func = params.to_string_func.or(parent_params.to_string_func);
+    params.notification_func = params.notification_func.or(parent_params.notification_func);
+    params.set_func = params.set_func.or(parent_params.set_func);
+    params.get_func = params.get_func.or(parent_params.get_func);
+    params.property_can_revert_func = params
+        .property_can_revert_func
+        .or(parent_params.property_can_revert_func);
+    params.property_get_revert_func = params
+        .property_get_revert_func
+        .or(parent_params.property_get_revert_func);
+
+    // Allocation and deallocation of the property list must come from the same class.
+    if params.get_property_list_func.is_none() {
+        params.get_property_list_func = parent_params.get_property_list_func;
+        params.free_property_list_func = parent_params.free_property_list_func;
+    }
+
+    #[cfg(since_api = "4.2")]
+    {
+        params.validate_property_func = params
+            .validate_property_func
+            .or(parent_params.validate_property_func);
+    }
+
+    if parent_virtual_fns.is_empty() {
+        return;
+    }
+
+    let get_virtual_fns = info
+        .own_virtual_fn()
+        .into_iter()
+        .chain(parent_virtual_fns)
+        .collect();
+
+    let chain = Box::new(VirtualChain { get_virtual_fns });
+    info.godot_params.get_virtual_func = Some(callbacks::get_virtual_inherited);
+    info.godot_params.class_userdata = &*chain as *const VirtualChain as *mut std::ffi::c_void;
+    info.virtual_chain = Some(chain);
+}
+
 fn register_classes_and_dyn_traits(
     map: &mut HashMap<ClassName, ClassRegistrationInfo>,
+    ordered_names: &[ClassName],
     init_level: InitLevel,
 ) {
     let mut loaded_classes_by_level = global_loaded_classes_by_init_level();
     let mut loaded_classes_by_name = global_loaded_classes_by_name();
     let mut dyn_traits_by_typeid = global_dyn_traits_by_typeid();
 
-    for info in map.values_mut() {
-        let class_name = info.class_name;
+    // Same order as registration, so that unregistering (in reverse) removes derived classes before their bases.
+    for &class_name in ordered_names {
+        let info = map.get_mut(&class_name).expect("class info present");
         out!("Register class:   {class_name} at level `{init_level:?}`");
 
+        let metadata = ClassMetadata {
+            virtual_fns: match &info.virtual_chain {
+                Some(chain) => chain.get_virtual_fns.clone(),
+                None => info.own_virtual_fn().into_iter().collect(),
+            },
+        };
         let loaded_class = LoadedClass {
             name: class_name,
             is_editor_plugin: info.is_editor_plugin,
+            virtual_chain: info.virtual_chain.take(),
         };
-        let metadata = ClassMetadata {};
 
         // Transpose Class->Trait relations to Trait->Class relations.
         for (trait_type_id, mut dyn_trait_impl) in info.dynify_fns_by_trait.drain() {
@@ -286,6 +417,48 @@ pub fn unregister_classes(init_level: InitLevel) {
     }
 }
 
+/// Invokes `_ready` on an instance, as implemented by the user class `class_name` or its Rust base classes.
+///
+/// Returns `false` if `class_name` is not a loaded user class, or if none of the classes in its Rust hierarchy overrides `_ready`.
+///
+/// # Safety
+/// `instance_ptr` must point to the instance of a class which is `class_name` or derives from it.
+pub(crate) unsafe fn call_inherited_ready(
+    class_name: ClassName,
+    instance_ptr: sys::GDExtensionClassInstancePtr,
+) -> bool {
+    // Copy function pointers, so that the lock is released before user code runs.
+    let virtual_fns = match global_loaded_classes_by_name().get(&class_name) {
+        Some(metadata) => metadata.virtual_fns.clone(),
+        None => return false,
+    };
+
+    let method_name = StringName::from("_ready");
+
+    #[cfg(since_api = "4.4")]
+    let call_virtual = virtual_fns.iter().find_map(|get_virtual| {
+        get_virtual(
+            ptr::null_mut(),
+            method_name.string_sys(),
+            sys::known_virtual_hashes::Node::ready,
+        )
+    });
+
+    #[cfg(before_api = "4.4")]
+    let call_virtual = virtual_fns
+        .iter()
+        .find_map(|get_virtual| get_virtual(ptr::null_mut(), method_name.string_sys()));
+
+    match call_virtual {
+        Some(call_virtual) => {
+            // ready() has neither parameters nor return value.
+            call_virtual(instance_ptr, ptr::null(), ptr::null_mut());
+            true
+        }
+        None => false,
+    }
+}
+
 #[cfg(feature = "codegen-full")]
 pub fn auto_register_rpcs<T: GodotClass>(object: &mut T) {
     // Find the element that matches our class, and call the closure if it exists.
@@ -409,6 +582,8 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             is_editor_plugin,
             is_internal,
             is_instantiable,
+            is_abstract,
+            is_runtime,
             #[cfg(all(since_api = "4.3", feature = "register-docs"))]
                 docs: _,
         }) => {
@@ -425,7 +600,11 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             // For some reason, certain ABCs like PhysicsBody2D are not marked "virtual" but "abstract".
             //
             // See also: https://github.com/godotengine/godot/pull/58972
-            c.godot_params.is_abstract = sys::conv::bool_to_sys(!is_instantiable);
+            //
+            // Classes marked #[class(abstract)] are both: not instantiable through Godot, and "virtual", i.e. meant to be extended. Unlike
+            // #[class(no_init)], they may still have an init function, which is used when Rust classes inherit from them.
+            c.godot_params.is_abstract = sys::conv::bool_to_sys(is_abstract || !is_instantiable);
+            c.godot_params.is_virtual = sys::conv::bool_to_sys(is_abstract);
             c.godot_params.free_instance_func = Some(free_fn);
 
             fill_into(
@@ -451,11 +630,12 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             assert!(generated_recreate_fn.is_none()); // not used
 
             #[cfg(before_api = "4.3")]
-            let _ = is_tool; // mark used
+            let _ = (is_tool, is_runtime); // mark used
             #[cfg(since_api = "4.3")]
             {
+                // Explicit #[class(runtime)] classes are placeholders in the editor, regardless of the library's tool configuration.
                 c.godot_params.is_runtime =
-                    sys::conv::bool_to_sys(crate::private::is_class_runtime(is_tool));
+                    sys::conv::bool_to_sys(is_runtime || crate::private::is_class_runtime(is_tool));
             }
         }
 
@@ -554,10 +734,9 @@ fn register_class_raw(mut info: ClassRegistrationInfo) {
         .parent_class_name
         .expect("class defined (parent_class_name)");
 
-    // Register virtual functions -- if the user provided some via #[godot_api], take those; otherwise, use the
-    // ones generated alongside #[derive(GodotClass)]. The latter can also be null, if no OnReady is provided.
+    // Register virtual functions, unless already chained with a Rust base class.
     if info.godot_params.get_virtual_func.is_none() {
-        info.godot_params.get_virtual_func = info.user_virtual_fn.or(info.default_virtual_fn);
+        info.godot_params.get_virtual_func = info.own_virtual_fn();
     }
 
     // The explicit () type notifies us if Godot API ever adds a return type.
@@ -678,6 +857,7 @@ fn default_registration_info(class_name: ClassName) -> ClassRegistrationInfo {
         user_register_fn: None,
         default_virtual_fn: None,
         user_virtual_fn: None,
+        virtual_chain: None,
         godot_params: default_creation_info(),
         init_level: InitLevel::Scene,
         is_editor_plugin: false,
@@ -0,0 +1,363 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::builtin::{GString, StringName, Variant};
+use crate::global::{PropertyHint, PropertyUsageFlags};
+use crate::meta::{FromGodot, PropertyHintInfo, PropertyInfo, ToGodot};
+use crate::registry::property::Var;
+
+/// Schema and values of properties defined at runtime.
+///
+/// Classes whose properties are not known at compile time (e.g. component bags, data-driven configs) implement `get_property_list()`,
+/// `get_property()`, `set_property()` and `property_get_revert()` from their `I*` interface trait. `DynamicProperties` stores the
+/// schema (type, hint, usage, default value) together with the current values, so that each of these methods becomes a one-line delegation.
+///
+/// Values assigned through [`set_property()`][Self::set_property] are converted to the declared type and checked against an optional
+/// validator; invalid values are rejected. [`property_get_revert()`][Self::property_get_revert] returns the default value, so the editor
+/// shows a revert button once a property deviates from it.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::meta::PropertyInfo;
+/// use godot::register::property::DynamicProperties;
+///
+/// #[derive(GodotClass)]
+/// #[class(base=Node)]
+/// struct ComponentBag {
+///     props: DynamicProperties,
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl INode for ComponentBag {
+///     fn init(base: Base<Node>) -> Self {
+///         let mut props = DynamicProperties::new();
+///         props.add_group("Stats", "stats/");
+///         props.add("stats/health", 100i64).range(0.0, 200.0).validate_value(|hp| *hp >= 0);
+///         props.add("stats/name", GString::from("Orc"));
+///
+///         Self { props, base }
+///     }
+///
+///     fn get_property_list(&mut self) -> Vec<PropertyInfo> {
+///         self.props.property_list()
+///     }
+///
+///     fn get_property(&self, property: StringName) -> Option<Variant> {
+///         self.props.get_property(&property)
+///     }
+///
+///     fn set_property(&mut self, property: StringName, value: Variant) -> bool {
+///         self.props.set_property(&property, &value)
+///     }
+///
+///     fn property_get_revert(&self, property: StringName) -> Option<Variant> {
+///         self.props.property_get_revert(&property)
+///     }
+///
+///     fn validate_property(&self, property: &mut PropertyInfo) {
+///         self.props.validate_property(property);
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct DynamicProperties {
+    entries: Vec<Entry>,
+}
+
+enum Entry {
+    Property(DynamicProperty),
+    Group(PropertyInfo),
+}
+
+impl DynamicProperties {
+    /// Creates an empty schema.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a property of type `T`, initialized to `default`, and returns a builder for further configuration.
+    ///
+    /// The property is visible in the editor and stored in scenes; use [`DynamicPropertyBuilder::usage()`] to change that.
+    /// If a property with the same name exists, it is replaced.
+    pub fn add<T>(&mut self, name: &str, default: T) -> DynamicPropertyBuilder<'_, T>
+    where
+        T: Var + ToGodot + FromGodot,
+    {
+        let name = StringName::from(name);
+        let default = default.to_variant();
+
+        let property = DynamicProperty {
+            info: PropertyInfo {
+                usage: PropertyUsageFlags::DEFAULT,
+                ..PropertyInfo::new_var::<T>(&name.to_string())
+            },
+            value: default.clone(),
+            default,
+            convert: |value| {
+                T::try_from_variant(value)
+                    .ok()
+                    .map(|value| value.to_variant())
+            },
+            value_validator: None,
+            info_validator: None,
+        };
+
+        let index = match self.position(&name) {
+            Some(index) => {
+                self.entries[index] = Entry::Property(property);
+                index
+            }
+            None => {
+                self.entries.push(Entry::Property(property));
+                self.entries.len() - 1
+            }
+        };
+
+        match &mut self.entries[index] {
+            Entry::Property(property) => DynamicPropertyBuilder {
+                property,
+                _type: PhantomData,
+            },
+            Entry::Group(_) => unreachable!(),
+        }
+    }
+
+    /// Starts an inspector group; all following properties whose names begin with `prefix` are shown inside it.
+    pub fn add_group(&mut self, name: &str, prefix: &str) {
+        self.entries
+            .push(Entry::Group(PropertyInfo::new_group(name, prefix)));
+    }
+
+    /// Starts an inspector subgroup inside the current group.
+    pub fn add_subgroup(&mut self, name: &str, prefix: &str) {
+        self.entries
+            .push(Entry::Group(PropertyInfo::new_subgroup(name, prefix)));
+    }
+
+    /// Removes a property. Returns whether it existed.
+    ///
+    /// Call [`Object::notify_property_list_changed()`](crate::classes::Object::notify_property_list_changed) afterward, so the editor
+    /// picks up the change. The same applies to adding properties after the object is shown in the inspector.
+    pub fn remove(&mut self, name: &str) -> bool {
+        match self.position(&StringName::from(name)) {
+            Some(index) => {
+                self.entries.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the property named `name`, if it exists.
+    pub fn property(&self, name: &str) -> Option<&DynamicProperty> {
+        let name = StringName::from(name);
+        self.properties()
+            .find(|property| property.info.property_name == name)
+    }
+
+    /// Returns the current value of property `name` converted to `T`, or `None` if it doesn't exist or has a different type.
+    pub fn get<T: FromGodot>(&self, name: &str) -> Option<T> {
+        self.property(name)
+            .and_then(|property| property.value.try_to::<T>().ok())
+    }
+
+    /// Assigns a new value to property `name`, subject to conversion and validation. Returns whether the value was accepted.
+    pub fn set<T: ToGodot>(&mut self, name: &str, value: T) -> bool {
+        self.set_property(&StringName::from(name), &value.to_variant())
+    }
+
+    /// Iterates over all properties (not groups), in insertion order.
+    pub fn properties(&self) -> impl Iterator<Item = &DynamicProperty> {
+        self.entries.iter().filter_map(|entry| match entry {
+            Entry::Property(property) => Some(property),
+            Entry::Group(_) => None,
+        })
+    }
+
+    // ------------------------------------------------------------------------------------------------------------------------------------------
+    // Delegation targets for I* interface methods
+
+    /// Implementation of `get_property_list()`: returns property infos and groups in insertion order.
+    pub fn property_list(&self) -> Vec<PropertyInfo> {
+        self.entries
+            .iter()
+            .map(|entry| match entry {
+                Entry::Property(property) => property.info.clone(),
+                Entry::Group(group) => group.clone(),
+            })
+            .collect()
+    }
+
+    /// Implementation of `get_property()`: returns the current value, or `None` for unknown properties.
+    pub fn get_property(&self, name: &StringName) -> Option<Variant> {
+        self.find(name).map(|property| property.value.clone())
+    }
+
+    /// Implementation of `set_property()`: converts and validates the value. Returns `false` for unknown properties and rejected values.
+    ///
+    /// Returning `false` for unknown properties lets Godot fall back to the class' regular properties.
+    pub fn set_property(&mut self, name: &StringName, value: &Variant) -> bool {
+        let Some(property) = self.find_mut(name) else {
+            return false;
+        };
+
+        let Some(value) = (property.convert)(value) else {
+            return false;
+        };
+
+        if let Some(validator) = &property.value_validator {
+            if !validator(&value) {
+                return false;
+            }
+        }
+
+        property.value = value;
+        true
+    }
+
+    /// Implementation of `property_get_revert()`: returns the default value, or `None` for unknown properties.
+    pub fn property_get_revert(&self, name: &StringName) -> Option<Variant> {
+        self.find(name).map(|property| property.default.clone())
+    }
+
+    /// Implementation of `validate_property()`: applies the hook registered with [`DynamicPropertyBuilder::validate_info()`], if any.
+    ///
+    /// Godot calls this for every property of the object, including regular `#[var]` ones; those are left unchanged.
+    pub fn validate_property(&self, property: &mut PropertyInfo) {
+        if let Some(validator) = self
+            .find(&property.property_name)
+            .and_then(|dynamic| dynamic.info_validator.as_ref())
+        {
+            validator(self, property);
+        }
+    }
+
+    // ------------------------------------------------------------------------------------------------------------------------------------------
+
+    fn position(&self, name: &StringName) -> Option<usize> {
+        self.entries.iter().position(
+            |entry| matches!(entry, Entry::Property(property) if property.info.property_name == *name),
+        )
+    }
+
+    fn find(&self, name: &StringName) -> Option<&DynamicProperty> {
+        self.properties()
+            .find(|property| property.info.property_name == *name)
+    }
+
+    fn find_mut(&mut self, name: &StringName) -> Option<&mut DynamicProperty> {
+        self.entries.iter_mut().find_map(|entry| match entry {
+            Entry::Property(property) if property.info.property_name == *name => Some(property),
+            _ => None,
+        })
+    }
+}
+
+impl fmt::Debug for DynamicProperties {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.properties()
+                    .map(|property| (&property.info.property_name, &property.value)),
+            )
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// A single property in [`DynamicProperties`].
+pub struct DynamicProperty {
+    info: PropertyInfo,
+    value: Variant,
+    default: Variant,
+    convert: fn(&Variant) -> Option<Variant>,
+    value_validator: Option<Box<dyn Fn(&Variant) -> bool>>,
+    info_validator: Option<Box<dyn Fn(&DynamicProperties, &mut PropertyInfo)>>,
+}
+
+impl DynamicProperty {
+    /// Property info as reported to Godot.
+    pub fn info(&self) -> &PropertyInfo {
+        &self.info
+    }
+
+    /// Current value.
+    pub fn value(&self) -> &Variant {
+        &self.value
+    }
+
+    /// Default value, used for reverting.
+    pub fn default_value(&self) -> &Variant {
+        &self.default
+    }
+}
+
+impl fmt::Debug for DynamicProperty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynamicProperty")
+            .field("info", &self.info)
+            .field("value", &self.value)
+            .field("default", &self.default)
+            .finish_non_exhaustive()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Configures a property of type `T`, as returned by [`DynamicProperties::add()`].
+pub struct DynamicPropertyBuilder<'a, T> {
+    property: &'a mut DynamicProperty,
+    _type: PhantomData<T>,
+}
+
+impl<T: FromGodot + 'static> DynamicPropertyBuilder<'_, T> {
+    /// Sets the property hint, e.g. from [`export_info_functions`](crate::registry::property::export_info_functions).
+    pub fn hint(self, hint_info: PropertyHintInfo) -> Self {
+        self.property.info.hint_info = hint_info;
+        self
+    }
+
+    /// Shorthand for a `RANGE` hint, equivalent to `@export_range(min, max)`.
+    pub fn range(self, min: f64, max: f64) -> Self {
+        self.hint(PropertyHintInfo {
+            hint: PropertyHint::RANGE,
+            hint_string: GString::from(format!("{min},{max}")),
+        })
+    }
+
+    /// Sets the usage flags, replacing the default `PropertyUsageFlags::DEFAULT`.
+    pub fn usage(self, usage: PropertyUsageFlags) -> Self {
+        self.property.info.usage = usage;
+        self
+    }
+
+    /// Rejects values for which `is_valid` returns `false`.
+    pub fn validate_value(self, is_valid: impl Fn(&T) -> bool + 'static) -> Self {
+        // Values are converted to T before validation, so try_to() only fails if T's conversion is not round-trip.
+        self.property.value_validator = Some(Box::new(move |value| {
+            value.try_to::<T>().is_ok_and(|value| is_valid(&value))
+        }));
+        self
+    }
+
+    /// Adjusts the property info each time Godot queries it, e.g. to make it read-only depending on another property's value.
+    ///
+    /// Invoked from [`DynamicProperties::validate_property()`].
+    pub fn validate_info(
+        self,
+        validate: impl Fn(&DynamicProperties, &mut PropertyInfo) + 'static,
+    ) -> Self {
+        self.property.info_validator = Some(Box::new(validate));
+        self
+    }
+}
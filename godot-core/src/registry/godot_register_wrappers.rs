@@ -58,6 +58,49 @@ pub fn register_var<C: GodotClass, T: Var>(
     register_var_or_export_inner(info, class_name, getter_name, setter_name);
 }
 
+/// Registers all properties of a `#[var(flatten)]` or `#[export(flatten)]` field, as an inspector group.
+///
+/// Each property is registered as _indexed_, so a single getter/setter pair on the class serves the whole group.
+#[cfg(since_api = "4.2")]
+pub fn register_flattened<C: GodotClass, G: crate::registry::property::PropertyGroup>(
+    field_name: &str,
+    getter_name: &str,
+    setter_name: &str,
+    exported: bool,
+) {
+    let class_name = C::class_name();
+    let getter_name = StringName::from(getter_name);
+    let setter_name = StringName::from(setter_name);
+
+    let group_name = crate::builtin::GString::from(field_name);
+    let prefix = crate::builtin::GString::from(format!("{field_name}/"));
+
+    unsafe {
+        sys::interface_fn!(classdb_register_extension_class_property_group)(
+            sys::get_library(),
+            class_name.string_sys(),
+            group_name.string_sys(),
+            prefix.string_sys(),
+        );
+    }
+
+    for (index, mut info) in G::__group_properties(exported).into_iter().enumerate() {
+        info.property_name = StringName::from(format!("{field_name}/{}", info.property_name));
+        let property_info_sys = info.property_sys();
+
+        unsafe {
+            sys::interface_fn!(classdb_register_extension_class_property_indexed)(
+                sys::get_library(),
+                class_name.string_sys(),
+                std::ptr::addr_of!(property_info_sys),
+                setter_name.string_sys(),
+                getter_name.string_sys(),
+                index as i64,
+            );
+        }
+    }
+}
+
 fn register_var_or_export_inner(
     info: PropertyInfo,
     class_name: ClassName,
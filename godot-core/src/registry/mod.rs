@@ -26,9 +26,15 @@ pub mod signal {
 
 // RpcConfig uses MultiplayerPeer::TransferMode and MultiplayerApi::RpcMode, which are only enabled in `codegen-full` feature.
 #[cfg(feature = "codegen-full")]
+mod rpc_call;
+#[cfg(feature = "codegen-full")]
 mod rpc_config;
 #[cfg(feature = "codegen-full")]
+pub use rpc_call::RpcCall;
+#[cfg(feature = "codegen-full")]
 pub use rpc_config::RpcConfig;
 
 #[doc(hidden)]
 pub mod godot_register_wrappers;
+
+mod dynamic_properties;
@@ -187,6 +187,12 @@ pub struct Struct {
     /// Whether the class has a default constructor.
     pub(crate) is_instantiable: bool,
 
+    /// Whether `#[class(abstract)]` was used.
+    pub(crate) is_abstract: bool,
+
+    /// Whether `#[class(runtime)]` was used.
+    pub(crate) is_runtime: bool,
+
     /// Documentation extracted from the struct's RustDoc.
     #[cfg(all(since_api = "4.3", feature = "register-docs"))]
     pub(crate) docs: StructDocs,
@@ -209,6 +215,8 @@ impl Struct {
             is_editor_plugin: false,
             is_internal: false,
             is_instantiable: false,
+            is_abstract: false,
+            is_runtime: false,
             #[cfg(all(since_api = "4.3", feature = "register-docs"))]
             docs,
         }
@@ -249,6 +257,16 @@ impl Struct {
         self.is_instantiable = true;
         self
     }
+
+    pub fn with_abstract(mut self) -> Self {
+        self.is_abstract = true;
+        self
+    }
+
+    pub fn with_runtime(mut self) -> Self {
+        self.is_runtime = true;
+        self
+    }
 }
 
 /// Stores registration functions for methods, constants, and documentation from inherent `#[godot_api]` impl blocks.
@@ -16,6 +16,10 @@ use std::fmt::Display;
 use crate::meta::{ClassName, FromGodot, GodotConvert, GodotType, PropertyHintInfo, ToGodot};
 use crate::obj::{EngineEnum, GodotClass};
 
+pub use crate::registry::dynamic_properties::{
+    DynamicProperties, DynamicProperty, DynamicPropertyBuilder,
+};
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Trait definitions
 
@@ -106,6 +110,32 @@ pub trait Export: Var {
 // this `MarkerTrait` serves as the intended solution to recognize aforementioned types.
 pub trait BuiltinExport {}
 
+/// Trait for plain structs whose fields are exposed as properties of an enclosing class.
+///
+/// A field of such a type can be marked with `#[var(flatten)]` or `#[export(flatten)]` inside a `#[derive(GodotClass)]` struct. Each field
+/// of the group then becomes its own property, prefixed by the outer field name (e.g. `movement/speed`), and is shown in an inspector
+/// group. Godot API 4.2 or later is required.
+///
+/// This trait should be derived using [`#[derive(PropertyGroup)]`](../derive.PropertyGroup.html).
+#[diagnostic::on_unimplemented(
+    message = "`#[var(flatten)]` and `#[export(flatten)]` require `PropertyGroup` trait",
+    label = "type cannot be flattened into properties",
+    note = "use #[derive(PropertyGroup)] on the struct"
+)]
+pub trait PropertyGroup {
+    /// Properties in declaration order, with names relative to the group.
+    ///
+    /// Fields without explicit usage flags are editor-visible if `exported` is true.
+    #[doc(hidden)]
+    fn __group_properties(exported: bool) -> Vec<crate::meta::PropertyInfo>;
+
+    #[doc(hidden)]
+    fn __get_group_property(&self, index: usize) -> crate::builtin::Variant;
+
+    #[doc(hidden)]
+    fn __set_group_property(&mut self, index: usize, value: &crate::builtin::Variant);
+}
+
 /// This function only exists as a place to add doc-tests for the `Export` trait.
 ///
 /// Test with export of exportable type should succeed:
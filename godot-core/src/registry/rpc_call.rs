@@ -0,0 +1,58 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{StringName, Variant};
+use crate::classes::Node;
+use crate::global::Error;
+use crate::obj::Gd;
+
+/// Remote procedure call with bound arguments, ready to be sent.
+///
+/// Obtained from the typed RPC collection of a user class, e.g. `self.rpcs().take_damage(5)`. Argument types are checked at compile time
+/// against the `#[rpc]` method's signature. The call is only sent once a recipient is chosen, via [`to_all()`][Self::to_all] or
+/// [`to_peer()`][Self::to_peer].
+///
+/// Check documentation of the [`#[rpc]` attribute](attr.godot_api.html#rpc-attributes) for usage.
+#[must_use = "RPC is only sent after calling to_all() or to_peer()"]
+pub struct RpcCall {
+    node: Gd<Node>,
+    method_name: StringName,
+    args: Vec<Variant>,
+}
+
+impl RpcCall {
+    #[doc(hidden)]
+    pub fn new(node: Gd<Node>, method_name: &str, args: Vec<Variant>) -> Self {
+        Self {
+            node,
+            method_name: StringName::from(method_name),
+            args,
+        }
+    }
+
+    /// Sends the RPC to all peers, like [`Node::rpc()`].
+    pub fn to_all(mut self) -> Error {
+        self.node.rpc(&self.method_name, &self.args)
+    }
+
+    /// Sends the RPC to a single peer, like [`Node::rpc_id()`].
+    ///
+    /// `peer_id` can also be `0` (all peers) or negative (all peers except the one with the absolute value).
+    pub fn to_peer(mut self, peer_id: i64) -> Error {
+        self.node.rpc_id(peer_id, &self.method_name, &self.args)
+    }
+
+    /// Name of the method that is invoked remotely.
+    pub fn method_name(&self) -> &StringName {
+        &self.method_name
+    }
+
+    /// Arguments passed to the method.
+    pub fn args(&self) -> &[Variant] {
+        &self.args
+    }
+}
@@ -13,6 +13,7 @@
 
 use crate::builtin::Variant;
 use crate::meta;
+use godot_ffi as sys;
 
 /// Trait that is implemented for functions that can be connected to signals.
 ///
@@ -35,6 +36,17 @@ pub trait SignalReceiver<I, Ps>: 'static {
 pub trait ParamTuple: 'static {
     fn to_variant_array(&self) -> Vec<Variant>;
     fn from_variant_array(array: &[&Variant]) -> Self;
+
+    /// Converts the parameters and passes them to `f` as variants and variant pointers, without heap allocation.
+    #[doc(hidden)]
+    fn with_variant_ptrs<R>(
+        &self,
+        f: impl FnOnce(&[Variant], &[sys::GDExtensionConstVariantPtr]) -> R,
+    ) -> R;
+}
+
+fn variant_ptrs<const N: usize>(variants: &[Variant; N]) -> [sys::GDExtensionConstVariantPtr; N] {
+    variants.each_ref().map(Variant::var_sys)
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -66,6 +78,20 @@ macro_rules! impl_signal_recipient {
                   ),
                )* )
             }
+
+            fn with_variant_ptrs<R>(
+                &self,
+                f: impl FnOnce(&[Variant], &[sys::GDExtensionConstVariantPtr]) -> R,
+            ) -> R {
+                let ($($args,)*) = self;
+
+                let variants = [
+                    $( $args.to_variant(), )*
+                ];
+                let variant_ptrs = variant_ptrs(&variants);
+
+                f(&variants, &variant_ptrs)
+            }
         }
 
         // --------------------------------------------------------------------------------------------------------------------------------------
@@ -6,6 +6,7 @@
  */
 
 use godot_ffi as sys;
+use std::any::{type_name, TypeId};
 use std::cell::Cell;
 use std::ptr;
 
@@ -174,6 +175,66 @@ pub type InstanceStorage<T> = crate::storage::single_threaded::InstanceStorage<T
 #[cfg(feature = "experimental-threads")]
 pub type InstanceStorage<T> = crate::storage::multi_threaded::InstanceStorage<T>;
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Storage of Rust base classes
+
+/// Data located at the start of every `InstanceStorage<T>`, independent of `T`.
+///
+/// When a user class inherits another user class, each level of the hierarchy has its own storage. Only the storage of the most-derived
+/// class is registered with Godot; it owns the storage of its Rust base class, which in turn owns the next one, and so on. The header
+/// allows [`as_storage()`] to find the storage of any class in that chain, starting from the Godot instance pointer.
+pub struct StorageHeader {
+    type_id: TypeId,
+    pub(super) inherited: Option<InheritedStorage>,
+}
+
+impl StorageHeader {
+    pub(super) fn new<T: GodotClass>() -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            inherited: None,
+        }
+    }
+
+    pub(super) fn is_inherited_bound(&self) -> bool {
+        self.inherited
+            .as_ref()
+            .is_some_and(|inherited| inherited.storage.is_bound())
+    }
+}
+
+/// Type-erased storage of a user class that acts as the base of another user class.
+#[doc(hidden)]
+pub struct InheritedStorage {
+    storage: Box<dyn ErasedStorage>,
+}
+
+impl InheritedStorage {
+    pub(crate) fn new<T: GodotClass>(storage: InstanceStorage<T>) -> Self {
+        Self {
+            storage: Box::new(storage),
+        }
+    }
+}
+
+trait ErasedStorage {
+    fn header(&self) -> &StorageHeader;
+
+    fn is_bound(&self) -> bool;
+}
+
+impl<T: GodotClass> ErasedStorage for InstanceStorage<T> {
+    fn header(&self) -> &StorageHeader {
+        &self.header
+    }
+
+    fn is_bound(&self) -> bool {
+        Storage::is_bound(self)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 const fn _assert_implements_storage<T: Storage + StorageRefCounted>() {}
 
 const _INSTANCE_STORAGE_IMPLEMENTS_STORAGE: () =
@@ -181,6 +242,8 @@ const _INSTANCE_STORAGE_IMPLEMENTS_STORAGE: () =
 
 /// Interprets the opaque pointer as pointing to `InstanceStorage<T>`.
 ///
+/// If the instance belongs to a class deriving from `T`, the storage of the Rust base class `T` is looked up in the inheritance chain.
+///
 /// Note: returns reference with unbounded lifetime; intended for local usage
 ///
 /// # Safety
@@ -190,7 +253,22 @@ const _INSTANCE_STORAGE_IMPLEMENTS_STORAGE: () =
 pub unsafe fn as_storage<'u, T: GodotClass>(
     instance_ptr: sys::GDExtensionClassInstancePtr,
 ) -> &'u InstanceStorage<T> {
-    &*(instance_ptr as *mut InstanceStorage<T>)
+    // SAFETY: every `InstanceStorage<U>` is `#[repr(C)]` and starts with a `StorageHeader`.
+    let mut header = &*(instance_ptr as *const StorageHeader);
+
+    let type_id = TypeId::of::<T>();
+    while header.type_id != type_id {
+        header = match &header.inherited {
+            Some(inherited) => inherited.storage.header(),
+            None => panic!(
+                "instance storage does not contain class {}; this is a bug, please report it",
+                type_name::<T>()
+            ),
+        };
+    }
+
+    // SAFETY: the header with the matching type ID is the start of an `InstanceStorage<T>`.
+    &*(header as *const StorageHeader as *const InstanceStorage<T>)
 }
 
 /// # Safety
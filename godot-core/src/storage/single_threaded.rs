@@ -14,9 +14,14 @@ use godot_cell::panicking::{GdCell, InaccessibleGuard, MutGuard, RefGuard};
 use godot_cell::blocking::{GdCell, InaccessibleGuard, MutGuard, RefGuard};
 
 use crate::obj::{Base, GodotClass};
-use crate::storage::{DebugBorrowTracker, Lifecycle, Storage, StorageRefCounted};
+use crate::storage::{
+    DebugBorrowTracker, InheritedStorage, Lifecycle, Storage, StorageHeader, StorageRefCounted,
+};
 
+// Layout is fixed, so that `StorageHeader` is always located at the start (see `as_storage()`).
+#[repr(C)]
 pub struct InstanceStorage<T: GodotClass> {
+    pub(super) header: StorageHeader,
     user_instance: GdCell<T>,
     pub(super) base: Base<T::Base>,
 
@@ -46,6 +51,7 @@ unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
         super::log_construct::<T>();
 
         Self {
+            header: StorageHeader::new::<T>(),
             user_instance: GdCell::new(user_instance),
             base,
             lifecycle: cell::Cell::new(Lifecycle::Alive),
@@ -55,7 +61,8 @@ unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
     }
 
     fn is_bound(&self) -> bool {
-        self.user_instance.is_currently_bound()
+        // A bound Rust base class keeps references into the same object alive, so it counts as well.
+        self.user_instance.is_currently_bound() || self.header.is_inherited_bound()
     }
 
     fn base(&self) -> &Base<<Self::Instance as GodotClass>::Base> {
@@ -100,6 +107,13 @@ unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
     }
 }
 
+impl<T: GodotClass> InstanceStorage<T> {
+    /// Attaches the storage of the Rust base class, if `T` inherits another user class.
+    pub(crate) fn set_inherited(&mut self, inherited: InheritedStorage) {
+        self.header.inherited = Some(inherited);
+    }
+}
+
 impl<T: GodotClass> StorageRefCounted for InstanceStorage<T> {
     fn godot_ref_count(&self) -> u32 {
         self.godot_ref_count.get()
@@ -0,0 +1,138 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Support for `async fn` methods registered with `#[func]`.
+
+use std::cell::RefCell;
+use std::future::Future;
+use std::mem::ManuallyDrop;
+
+use crate::builtin::{Callable, Signal, Variant};
+use crate::classes::{self, RefCounted};
+use crate::meta::ToGodot;
+use crate::obj::{Gd, InstanceId, NewGd};
+use crate::task::{spawn, TaskHandle};
+
+/// Name of the signal which is emitted once an async `#[func]` completes.
+const COMPLETED_SIGNAL: &str = "completed";
+
+thread_local! {
+    /// Tasks spawned by async `#[func]` methods, together with the object they belong to.
+    static OWNED_TASKS: RefCell<Vec<(InstanceId, TaskHandle)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Spawns the future returned by an async `#[func]` and returns a signal that GDScript can `await`.
+///
+/// The signal is emitted once with the result of the future. If `owner` is provided, the task is canceled as soon as that object is freed,
+/// or -- for a `RefCounted` owner -- once the only remaining reference is the one held by the future itself; see [`release_owned_tasks()`].
+/// In that case, the signal is never emitted.
+pub fn spawn_async_func<F>(owner: Option<InstanceId>, future: F) -> Signal
+where
+    F: Future + 'static,
+    F::Output: ToGodot,
+{
+    let mut completion = RefCounted::new_gd();
+    completion.add_user_signal(COMPLETED_SIGNAL);
+
+    let signal = Signal::from_object_signal(&completion, COMPLETED_SIGNAL);
+
+    let task = spawn(async move {
+        let result = future.await.to_variant();
+
+        // Emit deferred: if the future completes immediately, the caller has not started awaiting the signal yet.
+        // The callable keeps the completion object alive until then.
+        let callable = Callable::from_local_fn("async_func::completed", move |_args| {
+            completion.emit_signal(COMPLETED_SIGNAL, &[result.clone()]);
+            Ok(Variant::nil())
+        });
+        callable.call_deferred(&[]);
+    });
+
+    if let Some(owner) = owner {
+        OWNED_TASKS.with_borrow_mut(|tasks| {
+            tasks.retain(|(_, task)| task.is_pending());
+
+            if task.is_pending() {
+                tasks.push((owner, task));
+            }
+        });
+    }
+
+    signal
+}
+
+/// Cancels all async `#[func]` tasks belonging to the given object; called when it is freed.
+pub(crate) fn cancel_owned_tasks(owner: InstanceId) {
+    // Extract first, so that destructors of canceled futures can spawn or cancel tasks themselves.
+    let canceled = OWNED_TASKS
+        .try_with(|tasks| {
+            let Ok(mut tasks) = tasks.try_borrow_mut() else {
+                return Vec::new();
+            };
+
+            if tasks.is_empty() {
+                return Vec::new();
+            }
+
+            let (canceled, remaining): (Vec<_>, Vec<_>) = std::mem::take(&mut *tasks)
+                .into_iter()
+                .partition(|(id, _)| *id == owner);
+
+            *tasks = remaining;
+            canceled
+        })
+        .unwrap_or_default();
+
+    for (_, task) in canceled {
+        task.try_cancel();
+    }
+}
+
+/// Called whenever the reference count of a user-defined `RefCounted` object drops to 1 or 0.
+///
+/// The future of an async `#[func(gd_self)]` holds a strong reference to its object. If that is the last one, nobody else can use the object
+/// anymore, and the task would keep it alive forever if it never completes (e.g. waiting for one of the object's own signals). Thus, such
+/// tasks are canceled, which releases the object.
+///
+/// This must not happen inside Godot's `unreference()`, which still accesses the object afterward -- so the check is deferred. Godot does not
+/// notify about higher reference counts, so an object with several pending tasks is only released once all but one of them have completed.
+pub(crate) fn release_owned_tasks(owner: InstanceId) {
+    let has_tasks = OWNED_TASKS
+        .try_with(|tasks| {
+            tasks
+                .try_borrow()
+                .is_ok_and(|tasks| tasks.iter().any(|(id, _)| *id == owner))
+        })
+        .unwrap_or(false);
+
+    if !has_tasks {
+        return;
+    }
+
+    let callable = Callable::from_local_fn("async_func::release", move |_args| {
+        let obj_ptr = classes::object_ptr_from_id(owner);
+        if obj_ptr.is_null() {
+            return Ok(Variant::nil());
+        }
+
+        // Weak pointer: must not be dropped, as it does not own a reference. The object is alive, as checked above.
+        let obj = ManuallyDrop::new(unsafe { Gd::<RefCounted>::from_obj_sys_weak(obj_ptr) });
+
+        // Re-check, since other references may have been acquired in the meantime.
+        if obj.get_reference_count() <= 1 {
+            cancel_owned_tasks(owner);
+        }
+
+        Ok(Variant::nil())
+    });
+    callable.call_deferred(&[]);
+}
+
+/// Will be called during engine shutdown, together with the async runtime cleanup.
+pub(crate) fn cleanup() {
+    OWNED_TASKS.with_borrow_mut(Vec::clear);
+}
@@ -122,6 +122,25 @@ impl TaskHandle {
         })
     }
 
+    /// Cancels the task like [`cancel()`][Self::cancel], but does nothing if the runtime is unavailable or busy, e.g. while a task is polled.
+    ///
+    /// The future is dropped after the runtime has been released, so its destructor may interact with the runtime again.
+    pub(crate) fn try_cancel(self) {
+        let future = ASYNC_RUNTIME.try_with(|rt| {
+            let mut rt = rt.try_borrow_mut().ok()?;
+            let slot = rt.as_mut()?.tasks.get_mut(self.index)?;
+
+            match slot.value {
+                FutureSlotState::Pending(_) if slot.id == self.id => {
+                    Some(std::mem::replace(&mut slot.value, FutureSlotState::Gone))
+                }
+                _ => None,
+            }
+        });
+
+        drop(future);
+    }
+
     /// Synchronously checks if the task is still pending or has already completed.
     pub fn is_pending(&self) -> bool {
         ASYNC_RUNTIME.with_runtime(|rt| {
@@ -10,11 +10,13 @@
 //! This module contains:
 //! - Implementations of [`Future`](std::future::Future) for [`Signal`](crate::builtin::Signal) and [`TypedSignal`](crate::registry::signal::TypedSignal).
 //! - A way to [`spawn`] new async tasks by using the engine as the async runtime.
+//! - Support for `async fn` methods registered with `#[func]`.
 
+pub(crate) mod async_func;
 mod async_runtime;
 mod futures;
 
-pub(crate) use async_runtime::cleanup;
+pub(crate) use async_func::{cancel_owned_tasks, release_owned_tasks};
 pub(crate) use futures::{impl_dynamic_send, ThreadConfined};
 
 pub use async_runtime::{spawn, TaskHandle};
@@ -27,3 +29,9 @@ pub use futures::{
 pub use async_runtime::has_godot_task_panicked;
 #[cfg(feature = "trace")]
 pub use futures::{create_test_signal_future_resolver, SignalFutureResolver};
+
+/// Will be called during engine shutdown.
+pub(crate) fn cleanup() {
+    async_func::cleanup();
+    async_runtime::cleanup();
+}
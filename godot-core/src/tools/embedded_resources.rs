@@ -0,0 +1,359 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+
+use godot_macros::{godot_api, GodotClass};
+
+use crate::builtin::{GString, PackedByteArray, PackedStringArray, StringName, Variant};
+use crate::classes::{
+    FontFile, IResourceFormatLoader, Image, ImageTexture, Json, ResourceFormatLoader,
+    ResourceLoader, Shader,
+};
+use crate::global::{godot_error, Error};
+use crate::meta::ToGodot;
+use crate::obj::{Base, Gd, NewGd};
+use crate::sys;
+
+/// URI scheme under which embedded resources are served.
+const SCHEME: &str = "rust://";
+
+/// Supported file extensions (lowercase), with the class of the loaded resource.
+const RESOURCE_TYPES: &[(&str, &str)] = &[
+    ("png", "ImageTexture"),
+    ("jpg", "ImageTexture"),
+    ("jpeg", "ImageTexture"),
+    ("webp", "ImageTexture"),
+    ("svg", "ImageTexture"),
+    ("bmp", "ImageTexture"),
+    ("tga", "ImageTexture"),
+    ("gdshader", "Shader"),
+    ("ttf", "FontFile"),
+    ("otf", "FontFile"),
+    ("woff", "FontFile"),
+    ("woff2", "FontFile"),
+    ("json", "JSON"),
+];
+
+// All resources embedded with include_resource!, across all crates linked into the library.
+sys::plugin_registry!(pub __GODOT_EMBEDDED_RESOURCES: EmbeddedResource);
+
+/// Embeds a file in the extension library, and serves it as resource under `rust://<crate>/<path>`.
+///
+/// The path is relative to the directory containing the crate's `Cargo.toml`. The file's bytes are included at compile time, so no
+/// additional files are needed in the exported game. The macro is used at item level, e.g. at the top of a module:
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::{Shader, Texture2D};
+/// use godot::tools::{include_resource, EmbeddedResourceLoader};
+///
+/// // In crate `my_extension`:
+/// include_resource!("assets/outline.gdshader");
+/// include_resource!("assets/icon.png");
+///
+/// fn load_assets() {
+///     // EmbeddedResourceLoader::register() must have been called before, see its docs.
+///     let shader = load::<Shader>("rust://my_extension/assets/outline.gdshader");
+///     let icon = load::<Texture2D>("rust://my_extension/assets/icon.png");
+/// }
+/// ```
+///
+/// Crate names are used as-is from `Cargo.toml`, i.e. they may contain `-`.
+///
+/// See [`EmbeddedResourceLoader`] for the supported file types. Other files, such as `.tres` or `.res`, cause a compile error.
+#[macro_export]
+macro_rules! include_resource {
+    ($path:literal) => {
+        const _: () = $crate::tools::EmbeddedResource::__check_path($path);
+
+        $crate::sys::plugin_add!(
+            $crate::tools::__GODOT_EMBEDDED_RESOURCES;
+            $crate::tools::EmbeddedResource::__new(
+                env!("CARGO_PKG_NAME"),
+                $path,
+                include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/", $path)),
+            )
+        );
+    };
+}
+
+pub use crate::include_resource;
+
+/// A file embedded with [`include_resource!`].
+#[derive(Copy, Clone, Debug)]
+pub struct EmbeddedResource {
+    crate_name: &'static str,
+    path: &'static str,
+    bytes: &'static [u8],
+}
+
+impl EmbeddedResource {
+    #[doc(hidden)]
+    pub const fn __new(crate_name: &'static str, path: &'static str, bytes: &'static [u8]) -> Self {
+        Self {
+            crate_name,
+            path,
+            bytes,
+        }
+    }
+
+    #[doc(hidden)]
+    pub const fn __check_path(path: &str) {
+        if resource_type_of(path).is_none() {
+            panic!("include_resource!: unsupported file type, see EmbeddedResourceLoader for supported types");
+        }
+    }
+
+    /// Finds an embedded resource by its full path, e.g. `rust://my_extension/assets/icon.png`.
+    ///
+    /// This does not require the loader to be registered, and can be used to access the raw bytes.
+    pub fn find(path: &str) -> Option<Self> {
+        let mut found = None;
+        sys::plugin_foreach!(__GODOT_EMBEDDED_RESOURCES; |resource: &EmbeddedResource| {
+            if found.is_none() && resource.resource_path() == path {
+                found = Some(*resource);
+            }
+        });
+
+        found
+    }
+
+    /// Full path under which the resource is served, e.g. `rust://my_extension/assets/icon.png`.
+    pub fn resource_path(&self) -> String {
+        let path = self.path.trim_start_matches("./");
+        format!("{SCHEME}{}/{path}", self.crate_name)
+    }
+
+    /// Contents of the embedded file.
+    pub fn bytes(&self) -> &'static [u8] {
+        self.bytes
+    }
+
+    fn extension(&self) -> String {
+        match self.path.rsplit_once('.') {
+            Some((_, ext)) => ext.to_ascii_lowercase(),
+            None => String::new(),
+        }
+    }
+
+    /// Class of the loaded resource, e.g. `"ImageTexture"`.
+    fn resource_type(&self) -> &'static str {
+        // Checked by include_resource!.
+        resource_type_of(self.path).unwrap_or_default()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// `ResourceFormatLoader` that serves files embedded with [`include_resource!`].
+///
+/// The loader must be registered once, typically when the `Scene` init level is loaded:
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::EmbeddedResourceLoader;
+///
+/// struct MyExtension;
+///
+/// #[gdextension]
+/// unsafe impl ExtensionLibrary for MyExtension {
+///     fn on_level_init(level: InitLevel) {
+///         if level == InitLevel::Scene {
+///             EmbeddedResourceLoader::register();
+///         }
+///     }
+/// }
+/// ```
+///
+/// Afterwards, embedded resources can be loaded with [`load()`][crate::tools::load], [`try_load()`][crate::tools::try_load] or
+/// [`ResourceLoader`], and referenced from scenes and other resources by their `rust://` path.
+///
+/// # Supported file types
+/// - Images (`png`, `jpg`, `jpeg`, `webp`, `svg`, `bmp`, `tga`) are loaded as [`ImageTexture`].
+/// - Shaders (`gdshader`) are loaded as [`Shader`].
+/// - Fonts (`ttf`, `otf`, `woff`, `woff2`) are loaded as [`FontFile`].
+/// - JSON files (`json`) are loaded as [`Json`].
+///
+/// Godot's own formats `tres` and `res` are not supported: their loaders can only read from the file system, which would require extra
+/// files and break relative `ext_resource` paths.
+#[derive(GodotClass)]
+#[class(no_init, tool, base = ResourceFormatLoader)]
+pub struct EmbeddedResourceLoader {
+    resources: HashMap<String, EmbeddedResource>,
+    base: Base<ResourceFormatLoader>,
+}
+
+impl EmbeddedResourceLoader {
+    /// Creates the loader and registers it with [`ResourceLoader`].
+    pub fn register() -> Gd<Self> {
+        let mut resources = HashMap::new();
+        sys::plugin_foreach!(__GODOT_EMBEDDED_RESOURCES; |resource: &EmbeddedResource| {
+            resources.insert(resource.resource_path(), *resource);
+        });
+
+        let loader = Gd::from_init_fn(|base| Self { resources, base });
+        ResourceLoader::singleton()
+            .add_resource_format_loader_ex(&loader)
+            .at_front(true)
+            .done();
+
+        loader
+    }
+
+    /// Unregisters a loader previously registered with [`register()`][Self::register].
+    pub fn unregister(loader: Gd<Self>) {
+        ResourceLoader::singleton().remove_resource_format_loader(&loader);
+    }
+
+    /// Paths of all embedded resources, e.g. `rust://my_extension/assets/icon.png`.
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        self.resources.keys().map(String::as_str)
+    }
+}
+
+#[godot_api]
+impl IResourceFormatLoader for EmbeddedResourceLoader {
+    fn get_recognized_extensions(&self) -> PackedStringArray {
+        let mut extensions: Vec<String> =
+            self.resources.values().map(|res| res.extension()).collect();
+        extensions.sort();
+        extensions.dedup();
+
+        extensions.iter().map(GString::from).collect()
+    }
+
+    fn recognize_path(&self, path: GString, _type: StringName) -> bool {
+        path.to_string().starts_with(SCHEME)
+    }
+
+    fn handles_type(&self, type_: StringName) -> bool {
+        let type_ = type_.to_string();
+        self.resources
+            .values()
+            .any(|res| res.resource_type() == type_)
+    }
+
+    fn get_resource_type(&self, path: GString) -> GString {
+        match self.resources.get(&path.to_string()) {
+            Some(resource) => GString::from(resource.resource_type()),
+            None => GString::new(),
+        }
+    }
+
+    fn exists(&self, path: GString) -> bool {
+        self.resources.contains_key(&path.to_string())
+    }
+
+    fn load(
+        &self,
+        path: GString,
+        _original_path: GString,
+        _use_sub_threads: bool,
+        _cache_mode: i32,
+    ) -> Variant {
+        let Some(resource) = self.resources.get(&path.to_string()) else {
+            return Error::ERR_FILE_NOT_FOUND.to_variant();
+        };
+
+        match load_embedded(resource) {
+            Ok(loaded) => loaded,
+            Err(err) => {
+                godot_error!("failed to load embedded resource '{path}': {err:?}");
+                err.to_variant()
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Creates a resource from the embedded bytes, depending on the file extension.
+fn load_embedded(resource: &EmbeddedResource) -> Result<Variant, Error> {
+    let bytes = PackedByteArray::from(resource.bytes());
+
+    let loaded = match resource.extension().as_str() {
+        ext @ ("png" | "jpg" | "jpeg" | "webp" | "svg" | "bmp" | "tga") => {
+            let mut image = Image::new_gd();
+            let err = match ext {
+                "png" => image.load_png_from_buffer(&bytes),
+                "jpg" | "jpeg" => image.load_jpg_from_buffer(&bytes),
+                "webp" => image.load_webp_from_buffer(&bytes),
+                "svg" => image.load_svg_from_buffer(&bytes),
+                "bmp" => image.load_bmp_from_buffer(&bytes),
+                _ => image.load_tga_from_buffer(&bytes),
+            };
+            check(err)?;
+
+            let texture = ImageTexture::create_from_image(&image).ok_or(Error::ERR_INVALID_DATA)?;
+            texture.to_variant()
+        }
+        "gdshader" => {
+            let code = String::from_utf8_lossy(resource.bytes());
+
+            let mut shader = Shader::new_gd();
+            shader.set_code(code.as_ref());
+            shader.to_variant()
+        }
+        "ttf" | "otf" | "woff" | "woff2" => {
+            let mut font = FontFile::new_gd();
+            font.set_data(&bytes);
+            font.to_variant()
+        }
+        "json" => {
+            let text = String::from_utf8_lossy(resource.bytes());
+
+            let mut json = Json::new_gd();
+            check(json.parse(text.as_ref()))?;
+            json.to_variant()
+        }
+        _ => return Err(Error::ERR_FILE_UNRECOGNIZED),
+    };
+
+    Ok(loaded)
+}
+
+fn check(err: Error) -> Result<(), Error> {
+    match err {
+        Error::OK => Ok(()),
+        err => Err(err),
+    }
+}
+
+/// Returns the class of resources loaded from `path`, or `None` if the file type is not supported.
+const fn resource_type_of(path: &str) -> Option<&'static str> {
+    let path = path.as_bytes();
+
+    let mut i = 0;
+    while i < RESOURCE_TYPES.len() {
+        let (ext, class_name) = RESOURCE_TYPES[i];
+        if has_extension(path, ext.as_bytes()) {
+            return Some(class_name);
+        }
+        i += 1;
+    }
+
+    None
+}
+
+/// Whether `path` ends with `.ext`, ignoring ASCII case. Usable in const context.
+const fn has_extension(path: &[u8], ext: &[u8]) -> bool {
+    if path.len() <= ext.len() || path[path.len() - ext.len() - 1] != b'.' {
+        return false;
+    }
+
+    let offset = path.len() - ext.len();
+    let mut i = 0;
+    while i < ext.len() {
+        if path[offset + i].to_ascii_lowercase() != ext[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
@@ -0,0 +1,344 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::io::ErrorKind;
+
+use crate::builtin::GString;
+use crate::classes::DirAccess;
+use crate::global::Error;
+use crate::meta::{arg_into_ref, AsArg};
+use crate::obj::Gd;
+
+/// Directory operations on Godot paths, such as `res://` and `user://`.
+///
+/// This is the directory counterpart to [`GFile`](crate::tools::GFile): it wraps [`DirAccess`] and replaces its
+/// `list_dir_begin()`/`get_next()` loop and [`Error`] codes with iterators and [`std::io::Result`]. The functions mirror those of
+/// [`std::fs`], but go through Godot's file system, so they work in exported games as well (where `res://` is inside a PCK file).
+///
+/// ## Examples
+///
+/// ```no_run
+/// use godot::tools::GDir;
+///
+/// fn list_saves() -> std::io::Result<Vec<String>> {
+///     GDir::create_dir_all("user://saves")?;
+///
+///     let names = GDir::read_dir("user://saves")?
+///         .filter(|entry| entry.is_file())
+///         .map(|entry| entry.name().to_string())
+///         .collect();
+///
+///     Ok(names)
+/// }
+///
+/// fn list_levels() -> std::io::Result<()> {
+///     for entry in GDir::walk("res://levels")? {
+///         let entry = entry?;
+///         if entry.path().to_string().ends_with(".tscn") {
+///             println!("level: {}", entry.path());
+///         }
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// In exported games, resources in `res://` are listed with their import suffixes, e.g. `icon.png.import` instead of `icon.png`.
+///
+/// ## See also
+///
+/// - [`DirAccess`] class in Rust.
+/// - [Godot documentation](https://docs.godotengine.org/en/stable/classes/class_diraccess.html) for `DirAccess`.
+pub struct GDir {
+    _private: (),
+}
+
+impl GDir {
+    /// Returns an iterator over the entries of directory `path`.
+    ///
+    /// Hidden files are included; `.` and `..` are not. The order of entries is unspecified and depends on the file system.
+    ///
+    /// Only opening the directory can fail, so the iterator yields entries directly.
+    pub fn read_dir(path: impl AsArg<GString>) -> std::io::Result<ReadDir> {
+        arg_into_ref!(path);
+
+        let mut da = DirAccess::open(path).ok_or_else(|| {
+            let error = DirAccess::get_open_error();
+            io_error(
+                error,
+                format!("can't open directory {path}; GodotError: {error:?}"),
+            )
+        })?;
+
+        da.set_include_hidden(true);
+        da.set_include_navigational(false);
+        check(da.list_dir_begin(), || {
+            format!("can't list directory {path}")
+        })?;
+
+        Ok(ReadDir {
+            da,
+            dir: path.clone(),
+            finished: false,
+        })
+    }
+
+    /// Returns an iterator over all entries in directory `path` and its subdirectories, recursively.
+    ///
+    /// Directories are yielded before their contents. An error is yielded if a subdirectory cannot be opened; iteration can continue
+    /// afterwards, skipping that subdirectory.
+    pub fn walk(path: impl AsArg<GString>) -> std::io::Result<Walk> {
+        let root = Self::read_dir(path)?;
+
+        Ok(Walk {
+            stack: vec![root],
+            pending_dir: None,
+        })
+    }
+
+    /// Whether `path` is an existing directory.
+    #[doc(alias = "dir_exists_absolute")]
+    pub fn exists(path: impl AsArg<GString>) -> bool {
+        arg_into_ref!(path);
+        DirAccess::dir_exists_absolute(path)
+    }
+
+    /// Creates directory `path`. Its parent must exist.
+    ///
+    /// Fails with [`ErrorKind::AlreadyExists`] if the directory already exists.
+    #[doc(alias = "make_dir_absolute")]
+    pub fn create_dir(path: impl AsArg<GString>) -> std::io::Result<()> {
+        arg_into_ref!(path);
+
+        check(DirAccess::make_dir_absolute(path), || {
+            format!("can't create directory {path}")
+        })
+    }
+
+    /// Creates directory `path` including all missing parents. Succeeds if the directory already exists.
+    #[doc(alias = "make_dir_recursive_absolute")]
+    pub fn create_dir_all(path: impl AsArg<GString>) -> std::io::Result<()> {
+        arg_into_ref!(path);
+
+        check(DirAccess::make_dir_recursive_absolute(path), || {
+            format!("can't create directory {path}")
+        })
+    }
+
+    /// Removes a file or an empty directory.
+    ///
+    /// To remove a directory with its contents, use [`remove_dir_all()`](Self::remove_dir_all).
+    #[doc(alias = "remove_absolute")]
+    pub fn remove(path: impl AsArg<GString>) -> std::io::Result<()> {
+        arg_into_ref!(path);
+
+        check(DirAccess::remove_absolute(path), || {
+            format!("can't remove {path}")
+        })
+    }
+
+    /// Removes directory `path` after removing all its contents, recursively.
+    ///
+    /// Like [`std::fs::remove_dir_all()`], symbolic links are not followed: a link is removed itself, not the contents of its target.
+    pub fn remove_dir_all(path: impl AsArg<GString>) -> std::io::Result<()> {
+        arg_into_ref!(path);
+
+        let mut read_dir = Self::read_dir(path)?;
+        if read_dir.da.is_link(path) {
+            drop(read_dir);
+            return Self::remove(path);
+        }
+
+        // Collect first, so entries are not removed while the directory is being listed.
+        let entries: Vec<DirEntry> = read_dir.collect();
+        for entry in entries {
+            if entry.is_dir() && !entry.is_symlink() {
+                Self::remove_dir_all(entry.path())?;
+            } else {
+                Self::remove(entry.path())?;
+            }
+        }
+
+        Self::remove(path)
+    }
+
+    /// Copies file `from` to `to`, overwriting `to` if it exists.
+    ///
+    /// Directories cannot be copied with this function.
+    #[doc(alias = "copy_absolute")]
+    pub fn copy(from: impl AsArg<GString>, to: impl AsArg<GString>) -> std::io::Result<()> {
+        arg_into_ref!(from);
+        arg_into_ref!(to);
+
+        check(DirAccess::copy_absolute(from, to), || {
+            format!("can't copy {from} to {to}")
+        })
+    }
+
+    /// Renames or moves a file or directory from `from` to `to`, overwriting `to` if it is an existing file.
+    #[doc(alias = "rename_absolute")]
+    pub fn rename(from: impl AsArg<GString>, to: impl AsArg<GString>) -> std::io::Result<()> {
+        arg_into_ref!(from);
+        arg_into_ref!(to);
+
+        check(DirAccess::rename_absolute(from, to), || {
+            format!("can't rename {from} to {to}")
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Entry of a directory, returned by [`GDir::read_dir()`] and [`GDir::walk()`].
+#[derive(Clone, Debug)]
+pub struct DirEntry {
+    name: GString,
+    path: GString,
+    is_dir: bool,
+    is_symlink: bool,
+}
+
+impl DirEntry {
+    /// File or directory name, without parent directories, e.g. `icon.png`.
+    pub fn name(&self) -> &GString {
+        &self.name
+    }
+
+    /// Full path, e.g. `res://assets/icon.png`.
+    pub fn path(&self) -> &GString {
+        &self.path
+    }
+
+    /// Whether the entry is a directory, or a symbolic link to one.
+    pub fn is_dir(&self) -> bool {
+        self.is_dir
+    }
+
+    /// Whether the entry is a symbolic link.
+    #[doc(alias = "is_link")]
+    pub fn is_symlink(&self) -> bool {
+        self.is_symlink
+    }
+
+    /// Whether the entry is a file, i.e. not a directory.
+    pub fn is_file(&self) -> bool {
+        !self.is_dir
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Iterator over the entries of a directory, returned by [`GDir::read_dir()`].
+pub struct ReadDir {
+    da: Gd<DirAccess>,
+    dir: GString,
+    finished: bool,
+}
+
+impl Iterator for ReadDir {
+    type Item = DirEntry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let name = self.da.get_next();
+        if name.is_empty() {
+            self.finished = true;
+            self.da.list_dir_end();
+            return None;
+        }
+
+        Some(DirEntry {
+            path: join_path(&self.dir, &name),
+            is_dir: self.da.current_is_dir(),
+            is_symlink: self.da.is_link(&name),
+            name,
+        })
+    }
+}
+
+impl Drop for ReadDir {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.da.list_dir_end();
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Recursive iterator over a directory tree, returned by [`GDir::walk()`].
+pub struct Walk {
+    stack: Vec<ReadDir>,
+
+    /// Directory yielded last, to be descended into on the next call.
+    pending_dir: Option<GString>,
+}
+
+impl Iterator for Walk {
+    type Item = std::io::Result<DirEntry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(dir) = self.pending_dir.take() {
+            match GDir::read_dir(&dir) {
+                Ok(read_dir) => self.stack.push(read_dir),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+
+        loop {
+            let current = self.stack.last_mut()?;
+
+            match current.next() {
+                Some(entry) => {
+                    if entry.is_dir() {
+                        self.pending_dir = Some(entry.path().clone());
+                    }
+                    return Some(Ok(entry));
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+fn join_path(dir: &GString, name: &GString) -> GString {
+    let dir = dir.to_string();
+
+    // Roots such as `res://` and `user://` already end in a slash.
+    if dir.ends_with('/') {
+        GString::from(format!("{dir}{name}"))
+    } else {
+        GString::from(format!("{dir}/{name}"))
+    }
+}
+
+fn check(error: Error, message: impl FnOnce() -> String) -> std::io::Result<()> {
+    if error == Error::OK {
+        return Ok(());
+    }
+
+    let message = format!("{}; GodotError: {error:?}", message());
+    Err(io_error(error, message))
+}
+
+fn io_error(error: Error, message: String) -> std::io::Error {
+    let kind = match error {
+        Error::ERR_FILE_NOT_FOUND | Error::ERR_DOES_NOT_EXIST => ErrorKind::NotFound,
+        Error::ERR_ALREADY_EXISTS => ErrorKind::AlreadyExists,
+        Error::ERR_FILE_NO_PERMISSION | Error::ERR_UNAUTHORIZED => ErrorKind::PermissionDenied,
+        _ => ErrorKind::Other,
+    };
+
+    std::io::Error::new(kind, message)
+}
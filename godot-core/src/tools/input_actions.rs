@@ -0,0 +1,192 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::any::TypeId;
+use std::collections::HashMap;
+
+use godot_ffi as sys;
+use sys::Global;
+
+use crate::builtin::StringName;
+use crate::classes::{
+    Input, InputEvent, InputEventJoypadButton, InputEventKey, InputEventMouseButton, InputMap,
+};
+use crate::global::{JoyButton, Key, MouseButton};
+use crate::meta::error::InputActionsError;
+use crate::obj::{Gd, NewGd};
+
+/// Interned action names, per enum type and in variant order.
+///
+/// `StringName` needs to be lazy-initialized because the Godot binding may not be initialized yet.
+static ACTION_NAMES: Global<HashMap<TypeId, Vec<StringName>>> = Global::default();
+
+/// Enum whose variants correspond to input actions in the [`InputMap`].
+///
+/// This trait is implemented via [`#[derive(InputActions)]`](../register/derive.InputActions.html). Instead of passing action names as
+/// strings to [`Input`], which only fail at runtime when misspelled, you can call typed methods on the enum:
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::InputActions;
+///
+/// #[derive(InputActions, Copy, Clone, Eq, PartialEq, Debug)]
+/// enum Action {
+///     Jump,     // "jump"
+///     MoveLeft, // "move_left"
+///     #[action(name = "ui_accept")]
+///     Confirm,
+/// }
+///
+/// fn physics_process() {
+///     if Action::Jump.is_just_pressed() {
+///         // ...
+///     }
+///     let left = Action::MoveLeft.strength();
+/// }
+/// ```
+///
+/// Action names are cached as interned [`StringName`]s, so repeated queries do not allocate.
+///
+/// # Verifying the input map
+/// Actions are usually defined in the project settings (_Project > Project Settings > Input Map_), and thus stored in `project.godot`.
+/// To detect mismatches early, call [`verify_input_map()`][Self::verify_input_map] on startup, e.g. in `ready()` of the main scene.
+/// It reports all actions of the enum that are not registered.
+///
+/// # Default bindings
+/// Variants can declare default bindings, e.g. `#[action(keys = [SPACE], joy_buttons = [A])]`. They are only registered when calling
+/// [`register_default_bindings()`][Self::register_default_bindings], and only for actions that do not yet exist in the `InputMap`. This
+/// allows prototyping without editing the project settings, while keeping user-defined bindings intact.
+pub trait InputActions: Copy + 'static {
+    /// All variants, in declaration order.
+    const ALL: &'static [Self];
+
+    #[doc(hidden)]
+    const __ENUM_NAME: &'static str;
+
+    /// Name of the action in the `InputMap`, e.g. `"move_left"`.
+    fn action_name(self) -> &'static str;
+
+    /// Position of this variant in [`ALL`][Self::ALL].
+    #[doc(hidden)]
+    fn __index(self) -> usize;
+
+    /// Events that are registered by [`register_default_bindings()`][Self::register_default_bindings].
+    #[doc(hidden)]
+    fn __default_bindings(self) -> Vec<Gd<InputEvent>>;
+
+    /// Name of the action as interned `StringName`.
+    fn string_name(self) -> StringName {
+        let mut cache = ACTION_NAMES.lock();
+        let names = cache.entry(TypeId::of::<Self>()).or_insert_with(|| {
+            Self::ALL
+                .iter()
+                .map(|action| StringName::from(action.action_name()))
+                .collect()
+        });
+
+        names[self.__index()].clone()
+    }
+
+    /// Whether the action is currently pressed. See [`Input::is_action_pressed()`].
+    fn is_pressed(self) -> bool {
+        Input::singleton().is_action_pressed(&self.string_name())
+    }
+
+    /// Whether the action was pressed in the current frame. See [`Input::is_action_just_pressed()`].
+    fn is_just_pressed(self) -> bool {
+        Input::singleton().is_action_just_pressed(&self.string_name())
+    }
+
+    /// Whether the action was released in the current frame. See [`Input::is_action_just_released()`].
+    fn is_just_released(self) -> bool {
+        Input::singleton().is_action_just_released(&self.string_name())
+    }
+
+    /// Strength of the action between 0 and 1, for analog input. See [`Input::get_action_strength()`].
+    fn strength(self) -> f32 {
+        Input::singleton().get_action_strength(&self.string_name())
+    }
+
+    /// Whether `event` belongs to this action, e.g. inside `unhandled_input()`. See [`InputEvent::is_action()`].
+    fn matches_event(self, event: &Gd<InputEvent>) -> bool {
+        event.is_action(&self.string_name())
+    }
+
+    /// Returns the first action that `event` belongs to, in declaration order.
+    fn from_event(event: &Gd<InputEvent>) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|action| action.matches_event(event))
+    }
+
+    /// Checks that all actions are registered in the [`InputMap`], returning an error that lists all missing ones.
+    fn verify_input_map() -> Result<(), InputActionsError> {
+        let input_map = InputMap::singleton();
+        let mut error = InputActionsError::new(Self::__ENUM_NAME);
+
+        for action in Self::ALL {
+            if !input_map.has_action(&action.string_name()) {
+                error.add_missing(action.action_name());
+            }
+        }
+
+        if error.has_problems() {
+            Err(error)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Adds actions that are missing from the [`InputMap`], together with their default bindings.
+    ///
+    /// Actions that already exist, e.g. because they are defined in the project settings, are left unchanged.
+    fn register_default_bindings() {
+        let mut input_map = InputMap::singleton();
+
+        for &action in Self::ALL {
+            let name = action.string_name();
+            if input_map.has_action(&name) {
+                continue;
+            }
+
+            input_map.add_action(&name);
+            for event in action.__default_bindings() {
+                input_map.action_add_event(&name, &event);
+            }
+        }
+    }
+}
+
+/// Will be called during engine shutdown.
+pub(crate) fn cleanup() {
+    ACTION_NAMES.lock().clear();
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Default bindings, used by generated code.
+
+#[doc(hidden)]
+pub fn __key_binding(key: Key) -> Gd<InputEvent> {
+    let mut event = InputEventKey::new_gd();
+    event.set_physical_keycode(key);
+    event.upcast()
+}
+
+#[doc(hidden)]
+pub fn __mouse_button_binding(button: MouseButton) -> Gd<InputEvent> {
+    let mut event = InputEventMouseButton::new_gd();
+    event.set_button_index(button);
+    event.upcast()
+}
+
+#[doc(hidden)]
+pub fn __joy_button_binding(button: JoyButton) -> Gd<InputEvent> {
+    let mut event = InputEventJoypadButton::new_gd();
+    event.set_button_index(button);
+    event.upcast()
+}
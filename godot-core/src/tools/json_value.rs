@@ -0,0 +1,383 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Conversion between `Variant` and `serde_json::Value`.
+
+use serde_json::{Map, Number, Value};
+
+use crate::builtin::{Dictionary, GString, Variant, VariantArray, VariantType};
+#[cfg(since_api = "4.4")]
+use crate::classes::Json;
+use crate::meta::error::JsonValueError;
+use crate::meta::ToGodot;
+
+/// How JSON numbers are converted to `Variant`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum JsonNumbers {
+    /// Integers become `int`, all other numbers `float`. Integers outside the `i64` range are rejected.
+    ///
+    /// Together with `serde_json`, which keeps `1.0` and `1` apart, this converts Godot values losslessly in both directions.
+    #[default]
+    Preserve,
+
+    /// All numbers become `float`, like in Godot's `JSON.parse()`.
+    Float,
+}
+
+/// How `Dictionary` keys that are not strings are converted to JSON object keys.
+///
+/// Keys of type `String` and `StringName` are always accepted.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum JsonKeys {
+    /// Other keys are rejected with an error.
+    #[default]
+    Reject,
+
+    /// Other keys are converted with Godot's `str()`, e.g. `1` becomes `"1"`, like in Godot's `JSON.stringify()`.
+    ///
+    /// Two keys that are stringified to the same string (e.g. `1` and `"1"`) are rejected.
+    Stringify,
+}
+
+/// How `Variant` types without JSON equivalent, such as `Vector2` or `PackedByteArray`, are converted.
+///
+/// `Object`, `Callable`, `Signal` and `RID` are always rejected.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum JsonNativeTypes {
+    /// Such values are rejected with an error.
+    #[default]
+    Reject,
+
+    /// Such values are encoded like Godot's `JSON.from_native()` does, e.g. `{"type": "Vector2", "args": [1.0, 2.0]}`.
+    ///
+    /// When converting back to `Variant`, JSON objects in this format are decoded with `JSON.to_native()`. All other values keep their
+    /// plain JSON representation, so the result stays readable for non-Godot consumers.
+    #[cfg(since_api = "4.4")]
+    Encode,
+}
+
+/// Configurable conversion between `Variant` trees and [`serde_json::Value`].
+///
+/// JSON `null`, booleans, numbers, strings, arrays and objects map to `Variant` nil, `bool`, `int`/`float`, `String`, `Array` and
+/// `Dictionary`, respectively. The handling of numbers, non-string dictionary keys and other types can be configured; by default,
+/// everything that cannot be converted losslessly is rejected.
+///
+/// The only exception are `StringName` and `NodePath`, which are always converted to JSON strings, also with
+/// [`JsonNativeTypes::Encode`]. Converting back yields a `String`, so the original type is lost.
+///
+/// Errors report the location of the offending value as JSON pointer, see [`JsonValueError`].
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::{json_to_variant, JsonConversion, JsonKeys};
+///
+/// let json: serde_json::Value = serde_json::from_str(r#"{"name": "Ferris", "level": 3}"#).unwrap();
+///
+/// // Default conversion.
+/// let dict: Dictionary = json_to_variant(&json).unwrap().to();
+/// assert_eq!(dict.get("level"), Some(3.to_variant()));
+///
+/// // Custom conversion.
+/// let conversion = JsonConversion::new().keys(JsonKeys::Stringify);
+/// let scores = dict! { 1: 100, 2: 80 };
+/// let json = conversion.to_json(&scores.to_variant()).unwrap();
+/// assert_eq!(json.to_string(), r#"{"1":100,"2":80}"#);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct JsonConversion {
+    numbers: JsonNumbers,
+    keys: JsonKeys,
+    native_types: JsonNativeTypes,
+}
+
+impl JsonConversion {
+    /// Creates a conversion with default settings, which rejects all values that cannot be converted losslessly.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how JSON numbers are converted to `Variant`.
+    pub fn numbers(self, numbers: JsonNumbers) -> Self {
+        Self { numbers, ..self }
+    }
+
+    /// Sets how non-string `Dictionary` keys are converted to JSON.
+    pub fn keys(self, keys: JsonKeys) -> Self {
+        Self { keys, ..self }
+    }
+
+    /// Sets how `Variant` types without JSON equivalent are converted.
+    pub fn native_types(self, native_types: JsonNativeTypes) -> Self {
+        Self {
+            native_types,
+            ..self
+        }
+    }
+
+    /// Converts a `Variant` tree to a JSON value.
+    pub fn to_json(&self, variant: &Variant) -> Result<Value, JsonValueError> {
+        self.variant_to_json(variant, "")
+    }
+
+    /// Converts a JSON value to a `Variant` tree.
+    pub fn to_variant(&self, json: &Value) -> Result<Variant, JsonValueError> {
+        self.json_to_variant(json, "")
+    }
+
+    fn variant_to_json(&self, variant: &Variant, pointer: &str) -> Result<Value, JsonValueError> {
+        let json = match variant.get_type() {
+            VariantType::NIL => Value::Null,
+            VariantType::BOOL => Value::Bool(variant.to::<bool>()),
+            VariantType::INT => Value::from(variant.to::<i64>()),
+            VariantType::FLOAT => {
+                let float = variant.to::<f64>();
+                let number = Number::from_f64(float).ok_or_else(|| {
+                    JsonValueError::new(format!("{float} cannot be represented in JSON"), pointer)
+                })?;
+
+                Value::Number(number)
+            }
+            VariantType::STRING | VariantType::STRING_NAME | VariantType::NODE_PATH => {
+                Value::String(variant.to_string())
+            }
+            VariantType::ARRAY => {
+                // SAFETY: type is ARRAY. Elements are only read, so typed arrays (which cannot be converted to VariantArray) are fine.
+                let array = unsafe { VariantArray::from_variant_unchecked(variant) };
+
+                let elements = array
+                    .iter_shared()
+                    .enumerate()
+                    .map(|(i, element)| self.variant_to_json(&element, &format!("{pointer}/{i}")))
+                    .collect::<Result<_, _>>()?;
+
+                Value::Array(elements)
+            }
+            VariantType::DICTIONARY => {
+                let mut map = Map::new();
+                for (key, value) in variant.to::<Dictionary>().iter_shared() {
+                    let key = self.key_to_json(&key, pointer)?;
+                    let value = self.variant_to_json(&value, &child_pointer(pointer, &key))?;
+
+                    if map.insert(key, value).is_some() {
+                        return Err(JsonValueError::new(
+                            "duplicate key after converting keys to strings",
+                            pointer,
+                        ));
+                    }
+                }
+
+                Value::Object(map)
+            }
+            ty @ (VariantType::OBJECT
+            | VariantType::CALLABLE
+            | VariantType::SIGNAL
+            | VariantType::RID) => {
+                return Err(JsonValueError::new(
+                    format!("{ty:?} cannot be converted to JSON"),
+                    pointer,
+                ))
+            }
+            ty => self.native_to_json(variant, ty, pointer)?,
+        };
+
+        Ok(json)
+    }
+
+    fn key_to_json(&self, key: &Variant, pointer: &str) -> Result<String, JsonValueError> {
+        match (key.get_type(), self.keys) {
+            (VariantType::STRING | VariantType::STRING_NAME, _) => Ok(key.to_string()),
+            (_, JsonKeys::Stringify) => Ok(key.stringify().to_string()),
+            (ty, JsonKeys::Reject) => Err(JsonValueError::new(
+                format!("dictionary key `{key}` of type {ty:?} is not a string"),
+                pointer,
+            )),
+        }
+    }
+
+    #[allow(unused_variables)] // Without Encode variant.
+    fn native_to_json(
+        &self,
+        variant: &Variant,
+        ty: VariantType,
+        pointer: &str,
+    ) -> Result<Value, JsonValueError> {
+        match self.native_types {
+            JsonNativeTypes::Reject => Err(JsonValueError::new(
+                format!("{ty:?} has no JSON equivalent"),
+                pointer,
+            )),
+
+            #[cfg(since_api = "4.4")]
+            JsonNativeTypes::Encode => {
+                // The encoding only consists of JSON types, so this does not recurse further.
+                let encoded = Json::from_native(variant);
+                self.variant_to_json(&encoded, pointer)
+            }
+        }
+    }
+
+    fn json_to_variant(&self, json: &Value, pointer: &str) -> Result<Variant, JsonValueError> {
+        let variant = match json {
+            Value::Null => Variant::nil(),
+            Value::Bool(value) => value.to_variant(),
+            Value::Number(number) => self.number_to_variant(number, pointer)?,
+            Value::String(string) => GString::from(string.as_str()).to_variant(),
+            Value::Array(elements) => {
+                let mut array = VariantArray::new();
+                for (i, element) in elements.iter().enumerate() {
+                    let element = self.json_to_variant(element, &format!("{pointer}/{i}"))?;
+                    array.push(&element);
+                }
+
+                array.to_variant()
+            }
+            Value::Object(map) => {
+                if let Some(type_name) = self.native_type_name(map) {
+                    return self.native_to_variant(map, type_name, pointer);
+                }
+
+                let mut dict = Dictionary::new();
+                for (key, value) in map {
+                    let value = self.json_to_variant(value, &child_pointer(pointer, key))?;
+                    dict.set(GString::from(key.as_str()), value);
+                }
+
+                dict.to_variant()
+            }
+        };
+
+        Ok(variant)
+    }
+
+    fn number_to_variant(&self, number: &Number, pointer: &str) -> Result<Variant, JsonValueError> {
+        if self.numbers == JsonNumbers::Preserve {
+            if let Some(int) = number.as_i64() {
+                return Ok(int.to_variant());
+            }
+
+            if number.is_u64() {
+                return Err(JsonValueError::new(
+                    format!("integer {number} does not fit into i64"),
+                    pointer,
+                ));
+            }
+        }
+
+        match number.as_f64() {
+            Some(float) => Ok(float.to_variant()),
+            None => Err(JsonValueError::new(
+                format!("number {number} cannot be represented as float"),
+                pointer,
+            )),
+        }
+    }
+
+    /// If `map` is an encoding of `JSON.from_native()` and decoding is enabled, returns the encoded type.
+    #[allow(unused_variables)] // Without Encode variant.
+    fn native_type_name<'m>(&self, map: &'m Map<String, Value>) -> Option<&'m str> {
+        match self.native_types {
+            JsonNativeTypes::Reject => None,
+
+            #[cfg(since_api = "4.4")]
+            JsonNativeTypes::Encode => {
+                // Exactly the keys produced by `JSON.from_native()`; user data that merely has a "type" key stays a dictionary.
+                if map.len() != 2 || !map.get("args")?.is_array() {
+                    return None;
+                }
+
+                let type_name = map.get("type")?.as_str()?;
+                NATIVE_TYPE_NAMES.contains(&type_name).then_some(type_name)
+            }
+        }
+    }
+
+    #[allow(unused_variables)] // Without Encode variant.
+    fn native_to_variant(
+        &self,
+        map: &Map<String, Value>,
+        type_name: &str,
+        pointer: &str,
+    ) -> Result<Variant, JsonValueError> {
+        #[cfg(since_api = "4.4")]
+        {
+            // Godot's encoding contains ints and floats as-is, so they must be preserved.
+            let plain = JsonConversion::new().to_variant(&Value::Object(map.clone()))?;
+            let decoded = Json::to_native(&plain);
+
+            // Godot returns nil or the unchanged dictionary for invalid encodings.
+            if matches!(
+                decoded.get_type(),
+                VariantType::NIL | VariantType::DICTIONARY
+            ) {
+                return Err(JsonValueError::new(
+                    format!("invalid encoding of {type_name}"),
+                    pointer,
+                ));
+            }
+
+            Ok(decoded)
+        }
+
+        #[cfg(before_api = "4.4")]
+        unreachable!("native types are only decoded with JsonNativeTypes::Encode")
+    }
+}
+
+/// Converts a `Variant` tree to a JSON value, with default settings.
+///
+/// Values that cannot be converted losslessly are rejected, except for `StringName` and `NodePath`, which become strings. See
+/// [`JsonConversion`] for details and options.
+pub fn variant_to_json(variant: &Variant) -> Result<Value, JsonValueError> {
+    JsonConversion::new().to_json(variant)
+}
+
+/// Converts a JSON value to a `Variant` tree, with default settings.
+///
+/// Integers outside the `i64` range are rejected. See [`JsonConversion`] for details and options.
+pub fn json_to_variant(json: &Value) -> Result<Variant, JsonValueError> {
+    JsonConversion::new().to_variant(json)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Types encoded as `{"type": ..., "args": [...]}` by `JSON.from_native()`.
+#[cfg(since_api = "4.4")]
+const NATIVE_TYPE_NAMES: &[&str] = &[
+    "Vector2",
+    "Vector2i",
+    "Rect2",
+    "Rect2i",
+    "Vector3",
+    "Vector3i",
+    "Transform2D",
+    "Vector4",
+    "Vector4i",
+    "Plane",
+    "Quaternion",
+    "AABB",
+    "Basis",
+    "Transform3D",
+    "Projection",
+    "Color",
+    "PackedByteArray",
+    "PackedInt32Array",
+    "PackedInt64Array",
+    "PackedFloat32Array",
+    "PackedFloat64Array",
+    "PackedStringArray",
+    "PackedVector2Array",
+    "PackedVector3Array",
+    "PackedColorArray",
+    "PackedVector4Array",
+];
+
+/// Appends `key` to a JSON pointer, escaping `~` and `/`.
+fn child_pointer(pointer: &str, key: &str) -> String {
+    let key = key.replace('~', "~0").replace('/', "~1");
+    format!("{pointer}/{key}")
+}
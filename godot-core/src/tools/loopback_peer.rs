@@ -0,0 +1,644 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use godot_macros::{godot_api, GodotClass};
+
+use crate::builtin::PackedByteArray;
+use crate::classes::multiplayer_peer::{ConnectionStatus, TransferMode};
+use crate::classes::{IMultiplayerPeerExtension, MultiplayerPeerExtension};
+use crate::global::{godot_error, Error};
+use crate::meta::ToGodot;
+use crate::obj::{Base, Gd, WithBaseField};
+
+/// Peer ID of the server, as defined by Godot's multiplayer API.
+const SERVER_ID: i32 = 1;
+
+/// Same limit as `ENetMultiplayerPeer`.
+const MAX_PACKET_SIZE: i32 = 1 << 24;
+
+/// Simulated network conditions for one [`TransferMode`].
+///
+/// See [`LoopbackConfig`] for defaults.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LoopbackLink {
+    /// Fixed delay until a packet arrives at the receiving peer.
+    pub latency: Duration,
+
+    /// Upper bound of an additional random delay, added on top of `latency`.
+    pub jitter: Duration,
+
+    /// Probability in `0.0..=1.0` that a packet is dropped.
+    ///
+    /// Ignored for [`TransferMode::RELIABLE`], where packets always arrive.
+    pub packet_loss: f32,
+
+    /// Whether packets sent on the same channel arrive in the order they were sent.
+    ///
+    /// If `false`, jitter can cause later packets to overtake earlier ones.
+    pub ordered: bool,
+}
+
+impl LoopbackLink {
+    const fn new(ordered: bool) -> Self {
+        Self {
+            latency: Duration::ZERO,
+            jitter: Duration::ZERO,
+            packet_loss: 0.0,
+            ordered,
+        }
+    }
+}
+
+/// Configuration of a [`LoopbackNetwork`].
+///
+/// By default, packets arrive immediately and are never lost. Ordering follows Godot's semantics of the respective transfer mode:
+/// `RELIABLE` and `UNRELIABLE_ORDERED` are ordered, `UNRELIABLE` is not.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LoopbackConfig {
+    /// Conditions for packets sent with [`TransferMode::RELIABLE`].
+    pub reliable: LoopbackLink,
+
+    /// Conditions for packets sent with [`TransferMode::UNRELIABLE`].
+    pub unreliable: LoopbackLink,
+
+    /// Conditions for packets sent with [`TransferMode::UNRELIABLE_ORDERED`].
+    pub unreliable_ordered: LoopbackLink,
+
+    /// Seed for the random number generator behind jitter and packet loss. The same seed yields the same network behavior.
+    pub seed: u64,
+}
+
+impl LoopbackConfig {
+    /// Returns the simulated conditions for the given transfer mode.
+    pub fn link(&self, mode: TransferMode) -> &LoopbackLink {
+        match mode {
+            TransferMode::UNRELIABLE => &self.unreliable,
+            TransferMode::UNRELIABLE_ORDERED => &self.unreliable_ordered,
+            _ => &self.reliable,
+        }
+    }
+}
+
+impl Default for LoopbackConfig {
+    fn default() -> Self {
+        Self {
+            reliable: LoopbackLink::new(true),
+            unreliable: LoopbackLink::new(false),
+            unreliable_ordered: LoopbackLink::new(true),
+            seed: 0x5eed,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// LoopbackNetwork
+
+/// In-process network that connects several [`LoopbackMultiplayerPeer`] instances, without any sockets.
+///
+/// Useful to test `#[rpc]` methods headlessly: each peer can be assigned to its own `SceneMultiplayer`, and the network delivers packets
+/// between them within the same Godot process.
+///
+/// The topology is the same as with `ENetMultiplayerPeer`: every client is connected to the server, and traffic between clients is
+/// relayed by the server's `SceneMultiplayer`. A client connects during its first `poll()` after the server has been created.
+///
+/// Latency is measured in real time. To test delayed delivery deterministically, use [`advance_time()`][Self::advance_time] instead of waiting.
+///
+/// # Example
+/// ```no_run
+/// use godot::classes::SceneMultiplayer;
+/// use godot::prelude::*;
+/// use godot::tools::LoopbackNetwork;
+///
+/// let network = LoopbackNetwork::new();
+///
+/// let mut server = SceneMultiplayer::new_gd();
+/// server.set_multiplayer_peer(&network.create_server());
+///
+/// let mut client = SceneMultiplayer::new_gd();
+/// client.set_multiplayer_peer(&network.create_client());
+///
+/// // Polling exchanges packets; usually done by the scene tree each frame.
+/// for _ in 0..4 {
+///     server.poll();
+///     client.poll();
+/// }
+/// ```
+///
+/// The network state is shared behind a mutex, so peers of the same network can be used from different threads.
+#[derive(Clone)]
+pub struct LoopbackNetwork {
+    state: Arc<Mutex<NetworkState>>,
+}
+
+impl LoopbackNetwork {
+    /// Creates a network with instant and lossless delivery.
+    pub fn new() -> Self {
+        Self::with_config(LoopbackConfig::default())
+    }
+
+    /// Creates a network that simulates the given conditions.
+    pub fn with_config(config: LoopbackConfig) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(NetworkState::new(config))),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, NetworkState> {
+        // Network state stays consistent even if a panic occurred while it was locked.
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Creates the server peer, with ID 1.
+    ///
+    /// # Panics
+    /// If the network already has a server.
+    pub fn create_server(&self) -> Gd<LoopbackMultiplayerPeer> {
+        {
+            let mut state = self.state();
+            assert!(
+                !state.peers.contains_key(&SERVER_ID),
+                "LoopbackNetwork already has a server"
+            );
+
+            state.peers.insert(
+                SERVER_ID,
+                PeerState::new(ConnectionStatus::CONNECTED, false),
+            );
+        }
+
+        LoopbackMultiplayerPeer::create(self.clone(), SERVER_ID)
+    }
+
+    /// Creates a new client peer, with a unique ID greater than 1.
+    ///
+    /// The client is connecting until its first `poll()` after a server exists.
+    pub fn create_client(&self) -> Gd<LoopbackMultiplayerPeer> {
+        let unique_id = {
+            let mut state = self.state();
+            let unique_id = state.next_client_id;
+            state.next_client_id += 1;
+
+            state.peers.insert(
+                unique_id,
+                PeerState::new(ConnectionStatus::CONNECTING, true),
+            );
+            unique_id
+        };
+
+        LoopbackMultiplayerPeer::create(self.clone(), unique_id)
+    }
+
+    /// Returns the simulated network conditions.
+    pub fn config(&self) -> LoopbackConfig {
+        self.state().config
+    }
+
+    /// Changes the simulated network conditions. Affects only packets sent afterwards.
+    pub fn set_config(&self, config: LoopbackConfig) {
+        self.state().config = config;
+    }
+
+    /// Moves the network clock forward, so that packets become due earlier than in real time.
+    pub fn advance_time(&self, duration: Duration) {
+        self.state().time_offset += duration;
+    }
+
+    /// Number of packets which have been sent, but not yet delivered to the receiving peer.
+    pub fn in_flight_count(&self) -> usize {
+        self.state().in_flight.len()
+    }
+}
+
+impl Default for LoopbackNetwork {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// LoopbackMultiplayerPeer
+
+/// `MultiplayerPeer` that exchanges packets with other peers of the same [`LoopbackNetwork`].
+///
+/// Instances are created via [`LoopbackNetwork::create_server()`] and [`LoopbackNetwork::create_client()`].
+#[derive(GodotClass)]
+#[class(no_init, base = MultiplayerPeerExtension)]
+pub struct LoopbackMultiplayerPeer {
+    network: LoopbackNetwork,
+    unique_id: i32,
+    transfer_channel: i32,
+    transfer_mode: TransferMode,
+    target_peer: i32,
+    incoming: VecDeque<Packet>,
+    base: Base<MultiplayerPeerExtension>,
+}
+
+impl LoopbackMultiplayerPeer {
+    fn create(network: LoopbackNetwork, unique_id: i32) -> Gd<Self> {
+        Gd::from_init_fn(|base| Self {
+            network,
+            unique_id,
+            transfer_channel: 0,
+            transfer_mode: TransferMode::RELIABLE,
+            target_peer: 0,
+            incoming: VecDeque::new(),
+            base,
+        })
+    }
+
+    /// Returns the network this peer belongs to.
+    pub fn network(&self) -> LoopbackNetwork {
+        self.network.clone()
+    }
+
+    fn status(&self) -> ConnectionStatus {
+        self.network
+            .state()
+            .peers
+            .get(&self.unique_id)
+            .map_or(ConnectionStatus::DISCONNECTED, |peer| peer.status)
+    }
+}
+
+#[godot_api]
+impl IMultiplayerPeerExtension for LoopbackMultiplayerPeer {
+    fn get_available_packet_count(&self) -> i32 {
+        self.incoming.len() as i32
+    }
+
+    fn get_max_packet_size(&self) -> i32 {
+        MAX_PACKET_SIZE
+    }
+
+    fn get_packet_script(&mut self) -> PackedByteArray {
+        match self.incoming.pop_front() {
+            Some(packet) => PackedByteArray::from(packet.data.as_slice()),
+            None => {
+                godot_error!("LoopbackMultiplayerPeer: no incoming packets available");
+                PackedByteArray::new()
+            }
+        }
+    }
+
+    fn put_packet_script(&mut self, buffer: PackedByteArray) -> Error {
+        if buffer.len() > MAX_PACKET_SIZE as usize {
+            godot_error!(
+                "LoopbackMultiplayerPeer: packet exceeds maximum size of {MAX_PACKET_SIZE} bytes"
+            );
+            return Error::ERR_INVALID_PARAMETER;
+        }
+
+        let mut state = self.network.state();
+        let Some(peer) = state.peers.get(&self.unique_id) else {
+            return Error::ERR_UNCONFIGURED;
+        };
+
+        if peer.status != ConnectionStatus::CONNECTED {
+            return Error::ERR_UNCONFIGURED;
+        }
+
+        let receivers: Vec<i32> = match self.target_peer {
+            0 => peer.connections.clone(),
+            target if target > 0 => {
+                if !peer.connections.contains(&target) {
+                    godot_error!("LoopbackMultiplayerPeer: invalid target peer {target}");
+                    return Error::ERR_INVALID_PARAMETER;
+                }
+                vec![target]
+            }
+            excluded => peer
+                .connections
+                .iter()
+                .copied()
+                .filter(|&id| id != -excluded)
+                .collect(),
+        };
+
+        for to in receivers {
+            state.send(Packet {
+                from: self.unique_id,
+                to,
+                channel: self.transfer_channel,
+                mode: self.transfer_mode,
+                data: buffer.to_vec(),
+            });
+        }
+
+        Error::OK
+    }
+
+    fn set_transfer_channel(&mut self, channel: i32) {
+        self.transfer_channel = channel;
+    }
+
+    fn get_transfer_channel(&self) -> i32 {
+        self.transfer_channel
+    }
+
+    fn set_transfer_mode(&mut self, mode: TransferMode) {
+        self.transfer_mode = mode;
+    }
+
+    fn get_transfer_mode(&self) -> TransferMode {
+        self.transfer_mode
+    }
+
+    fn set_target_peer(&mut self, peer: i32) {
+        self.target_peer = peer;
+    }
+
+    fn get_packet_peer(&self) -> i32 {
+        self.incoming
+            .front()
+            .map_or(SERVER_ID, |packet| packet.from)
+    }
+
+    fn get_packet_mode(&self) -> TransferMode {
+        self.incoming
+            .front()
+            .map_or(TransferMode::RELIABLE, |packet| packet.mode)
+    }
+
+    fn get_packet_channel(&self) -> i32 {
+        self.incoming.front().map_or(0, |packet| packet.channel)
+    }
+
+    fn is_server(&self) -> bool {
+        self.unique_id == SERVER_ID
+    }
+
+    fn is_server_relay_supported(&self) -> bool {
+        true
+    }
+
+    fn poll(&mut self) {
+        // Do not hold the network lock while emitting signals: handlers (e.g. SceneMultiplayer) send packets in response.
+        let events = {
+            let mut state = self.network.state();
+            state.connect_to_server(self.unique_id);
+            state.deliver(self.unique_id, &mut self.incoming);
+
+            match state.peers.get_mut(&self.unique_id) {
+                Some(peer) => std::mem::take(&mut peer.events),
+                None => Vec::new(),
+            }
+        };
+
+        for event in events {
+            let (signal, peer_id) = match event {
+                PeerEvent::Connected(id) => ("peer_connected", id),
+                PeerEvent::Disconnected(id) => ("peer_disconnected", id),
+            };
+
+            self.base_mut()
+                .emit_signal(signal, &[i64::from(peer_id).to_variant()]);
+        }
+    }
+
+    fn close(&mut self) {
+        self.incoming.clear();
+        self.network.state().disconnect_all(self.unique_id);
+    }
+
+    fn disconnect_peer(&mut self, peer: i32, force: bool) {
+        self.network
+            .state()
+            .disconnect(self.unique_id, peer, !force);
+
+        self.incoming.retain(|packet| packet.from != peer);
+    }
+
+    fn get_unique_id(&self) -> i32 {
+        self.unique_id
+    }
+
+    fn get_connection_status(&self) -> ConnectionStatus {
+        self.status()
+    }
+}
+
+impl Drop for LoopbackMultiplayerPeer {
+    fn drop(&mut self) {
+        // Peers that are freed without being closed should not linger as connections of others.
+        let mut state = self.network.state();
+        state.disconnect_all(self.unique_id);
+        state.peers.remove(&self.unique_id);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Network internals
+
+struct Packet {
+    from: i32,
+    to: i32,
+    channel: i32,
+    mode: TransferMode,
+    // Not `PackedByteArray`, so that the network state can be shared across threads.
+    data: Vec<u8>,
+}
+
+struct InFlightPacket {
+    deliver_at: Instant,
+    sequence: u64,
+    packet: Packet,
+}
+
+enum PeerEvent {
+    Connected(i32),
+    Disconnected(i32),
+}
+
+struct PeerState {
+    status: ConnectionStatus,
+    is_client: bool,
+
+    /// IDs of directly connected peers: the server for a client, all clients for the server.
+    connections: Vec<i32>,
+
+    /// Signals to emit during the next `poll()`.
+    events: Vec<PeerEvent>,
+}
+
+impl PeerState {
+    fn new(status: ConnectionStatus, is_client: bool) -> Self {
+        Self {
+            status,
+            is_client,
+            connections: Vec::new(),
+            events: Vec::new(),
+        }
+    }
+}
+
+struct NetworkState {
+    config: LoopbackConfig,
+    rng_state: u64,
+    time_offset: Duration,
+    next_client_id: i32,
+    next_sequence: u64,
+    peers: HashMap<i32, PeerState>,
+    in_flight: Vec<InFlightPacket>,
+
+    /// Latest delivery time per `(from, to, channel, mode)`, to keep ordered packets in sequence.
+    last_ordered_delivery: HashMap<(i32, i32, i32, i32), Instant>,
+}
+
+impl NetworkState {
+    fn new(config: LoopbackConfig) -> Self {
+        Self {
+            config,
+            // Xorshift must not start at zero.
+            rng_state: config.seed.max(1),
+            time_offset: Duration::ZERO,
+            next_client_id: SERVER_ID + 1,
+            next_sequence: 0,
+            peers: HashMap::new(),
+            in_flight: Vec::new(),
+            last_ordered_delivery: HashMap::new(),
+        }
+    }
+
+    fn now(&self) -> Instant {
+        Instant::now() + self.time_offset
+    }
+
+    /// Returns a pseudo-random number in `0.0..1.0` (xorshift64*).
+    fn next_random(&mut self) -> f32 {
+        let mut x = self.rng_state;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.rng_state = x;
+
+        let bits = x.wrapping_mul(0x2545_f491_4f6c_dd1d) >> 40;
+        bits as f32 / (1u64 << 24) as f32
+    }
+
+    fn connect_to_server(&mut self, client_id: i32) {
+        let is_pending = self
+            .peers
+            .get(&client_id)
+            .is_some_and(|peer| peer.is_client && peer.status == ConnectionStatus::CONNECTING);
+
+        if !is_pending {
+            return;
+        }
+
+        let Some(server) = self.peers.get_mut(&SERVER_ID) else {
+            return;
+        };
+
+        server.connections.push(client_id);
+        server.events.push(PeerEvent::Connected(client_id));
+
+        let client = self.peers.get_mut(&client_id).expect("client exists");
+        client.status = ConnectionStatus::CONNECTED;
+        client.connections.push(SERVER_ID);
+        client.events.push(PeerEvent::Connected(SERVER_ID));
+    }
+
+    fn send(&mut self, packet: Packet) {
+        let link = *self.config.link(packet.mode);
+        let is_reliable = packet.mode == TransferMode::RELIABLE;
+
+        if !is_reliable && link.packet_loss > 0.0 && self.next_random() < link.packet_loss {
+            return;
+        }
+
+        let jitter = if link.jitter.is_zero() {
+            Duration::ZERO
+        } else {
+            link.jitter.mul_f32(self.next_random())
+        };
+
+        let mut deliver_at = self.now() + link.latency + jitter;
+
+        if link.ordered {
+            let key = (packet.from, packet.to, packet.channel, packet.mode.ord());
+            let last = self.last_ordered_delivery.entry(key).or_insert(deliver_at);
+
+            deliver_at = deliver_at.max(*last);
+            *last = deliver_at;
+        }
+
+        let sequence = self.next_sequence;
+        self.next_sequence += 1;
+
+        self.in_flight.push(InFlightPacket {
+            deliver_at,
+            sequence,
+            packet,
+        });
+    }
+
+    /// Moves all due packets addressed to `receiver` into its incoming queue.
+    fn deliver(&mut self, receiver: i32, incoming: &mut VecDeque<Packet>) {
+        let now = self.now();
+
+        let (mut due, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut self.in_flight)
+            .into_iter()
+            .partition(|p| p.packet.to == receiver && p.deliver_at <= now);
+
+        self.in_flight = pending;
+
+        due.sort_by_key(|p| (p.deliver_at, p.sequence));
+        incoming.extend(due.into_iter().map(|p| p.packet));
+    }
+
+    /// Closes the connection between `peer_id` and `other_id`, from the perspective of `peer_id`.
+    fn disconnect(&mut self, peer_id: i32, other_id: i32, notify_self: bool) {
+        let Some(peer) = self.peers.get_mut(&peer_id) else {
+            return;
+        };
+
+        if !peer.connections.contains(&other_id) {
+            return;
+        }
+
+        peer.connections.retain(|&id| id != other_id);
+        if notify_self {
+            peer.events.push(PeerEvent::Disconnected(other_id));
+        }
+        if peer.is_client {
+            peer.status = ConnectionStatus::DISCONNECTED;
+        }
+
+        if let Some(other) = self.peers.get_mut(&other_id) {
+            other.connections.retain(|&id| id != peer_id);
+            other.events.push(PeerEvent::Disconnected(peer_id));
+            if other.is_client {
+                other.status = ConnectionStatus::DISCONNECTED;
+            }
+        }
+
+        self.in_flight.retain(|p| {
+            let Packet { from, to, .. } = p.packet;
+            !((from == peer_id && to == other_id) || (from == other_id && to == peer_id))
+        });
+    }
+
+    /// Disconnects `peer_id` from everyone and marks it as disconnected.
+    fn disconnect_all(&mut self, peer_id: i32) {
+        let connections = match self.peers.get(&peer_id) {
+            Some(peer) => peer.connections.clone(),
+            None => return,
+        };
+
+        for other_id in connections {
+            self.disconnect(peer_id, other_id, false);
+        }
+
+        if let Some(peer) = self.peers.get_mut(&peer_id) {
+            peer.status = ConnectionStatus::DISCONNECTED;
+        }
+    }
+}
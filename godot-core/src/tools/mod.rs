@@ -10,10 +10,46 @@
 //! Contains functionality that extends existing Godot classes and functions, to make them more versatile
 //! or better integrated with Rust.
 
+#[cfg(feature = "embedded-resources")]
+mod embedded_resources;
+mod gdir;
 mod gfile;
+mod input_actions;
+#[cfg(feature = "serde_json")]
+mod json_value;
+#[cfg(feature = "loopback-peer")]
+mod loopback_peer;
+mod project_settings;
 mod save_load;
+#[cfg(feature = "serde")]
+mod settings;
 mod translate;
 
+#[cfg(feature = "script-toolkit")]
+pub mod scripting;
+
+#[cfg(feature = "embedded-resources")]
+pub use embedded_resources::*;
+pub use gdir::*;
 pub use gfile::*;
+pub use input_actions::*;
+#[cfg(feature = "serde_json")]
+pub use json_value::*;
+#[cfg(feature = "loopback-peer")]
+pub use loopback_peer::*;
+pub use project_settings::*;
 pub use save_load::*;
+#[cfg(feature = "serde")]
+pub use settings::*;
 pub use translate::*;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Crate-local utilities
+
+pub(crate) fn register_project_settings() {
+    project_settings::register_all();
+}
+
+pub(crate) fn cleanup() {
+    input_actions::cleanup();
+}
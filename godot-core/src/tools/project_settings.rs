@@ -0,0 +1,133 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+
+use crate::builtin::Variant;
+use crate::classes::ProjectSettings;
+use crate::global::godot_error;
+use crate::meta::{FromGodot, PropertyInfo};
+
+// Registration functions of all types deriving `ProjectSettings`.
+sys::plugin_registry!(pub __GODOT_PROJECT_SETTINGS: fn());
+
+/// Struct whose fields are registered as settings in [`ProjectSettings`].
+///
+/// This trait is implemented via [`#[derive(ProjectSettings)]`](../register/derive.ProjectSettings.html). Each field becomes a setting named
+/// `prefix/section/field`, with default value, editor hint and _Advanced Settings_ visibility declared on the field:
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::TypedProjectSettings;
+///
+/// #[derive(ProjectSettings)]
+/// #[settings(prefix = "my_plugin", section = "general")]
+/// struct MyPluginSettings {
+///     // "my_plugin/general/max_speed"
+///     #[setting(default = 4.5)]
+///     #[export(range = (0.0, 10.0))]
+///     max_speed: f32,
+///
+///     // "my_plugin/general/log_level", only shown with "Advanced Settings" enabled.
+///     #[setting(default = "info".into(), advanced)]
+///     #[export(enum = (error, info, verbose))]
+///     log_level: GString,
+///
+///     // "my_plugin/debug/draw_paths", default value `false`.
+///     #[setting(section = "debug")]
+///     draw_paths: bool,
+/// }
+///
+/// fn ready() {
+///     let settings = MyPluginSettings::load();
+///     godot_print!("max speed: {}", settings.max_speed);
+/// }
+/// ```
+///
+/// # Registration
+/// Settings are registered automatically when the `Scene` [init level][crate::init::InitLevel] is loaded, so they appear in the editor's
+/// project settings dialog. Registering a setting does not overwrite values that are already stored in `project.godot`. Defaults are set
+/// as initial values, so settings are only saved to `project.godot` once they are changed.
+pub trait TypedProjectSettings: Sized + 'static {
+    /// Common prefix of all settings, e.g. `"my_plugin"`.
+    const PREFIX: &'static str;
+
+    /// Full names of all settings, in field order.
+    const SETTING_NAMES: &'static [&'static str];
+
+    /// Reads the current values from [`ProjectSettings`].
+    ///
+    /// Settings that are missing fall back to their default value. Settings with a value of incompatible type also fall back to their
+    /// default, after printing an error.
+    fn load() -> Self;
+
+    /// Registers all settings with their defaults and editor hints.
+    ///
+    /// Called automatically on startup, see [Registration](#registration). Calling it again has no effect.
+    fn register() {
+        for setting in Self::__settings() {
+            register_setting(setting);
+        }
+    }
+
+    #[doc(hidden)]
+    fn __settings() -> Vec<ProjectSettingInfo>;
+}
+
+/// Metadata of a single setting, created by `#[derive(ProjectSettings)]`.
+#[doc(hidden)]
+pub struct ProjectSettingInfo {
+    pub default: Variant,
+    pub property: PropertyInfo,
+    pub basic: bool,
+    pub restart_if_changed: bool,
+}
+
+#[doc(hidden)]
+pub fn __load_setting<T: FromGodot>(name: &str, default: impl FnOnce() -> T) -> T {
+    let project_settings = ProjectSettings::singleton();
+    if !project_settings.has_setting(name) {
+        return default();
+    }
+
+    match project_settings.get_setting(name).try_to::<T>() {
+        Ok(value) => value,
+        Err(err) => {
+            godot_error!("project setting `{name}` has invalid value, using default: {err}");
+            default()
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Crate-local utilities
+
+pub(crate) fn register_all() {
+    // Copy out function pointers, so registration code cannot deadlock on the registry.
+    let mut register_fns = vec![];
+    sys::plugin_foreach!(__GODOT_PROJECT_SETTINGS; |register_fn: &fn()| {
+        register_fns.push(*register_fn);
+    });
+
+    for register_fn in register_fns {
+        register_fn();
+    }
+}
+
+fn register_setting(setting: ProjectSettingInfo) {
+    let mut project_settings = ProjectSettings::singleton();
+    let name = setting.property.property_name.to_string();
+
+    if !project_settings.has_setting(&name) {
+        project_settings.set_setting(&name, &setting.default);
+    }
+
+    project_settings.set_initial_value(&name, &setting.default);
+    project_settings.set_as_basic(&name, setting.basic);
+    project_settings.set_restart_if_changed(&name, setting.restart_if_changed);
+    project_settings.add_property_info(&setting.property.to_dictionary());
+}
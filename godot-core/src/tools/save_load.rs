@@ -50,6 +50,8 @@ where
 /// or by dragging the file from the _FileSystem_ dock into the script.
 ///
 /// The path must be absolute (typically starting with `res://`), a local path will fail.
+/// Other schemes work if a `ResourceFormatLoader` handles them, e.g. `rust://` for files embedded with `include_resource!` (requires the
+/// `embedded-resources` feature of the `godot` crate).
 ///
 /// # Example
 /// Loads a scene called `Main` located in the `path/to` subdirectory of the Godot project and caches it in a variable.
@@ -0,0 +1,190 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::any::Any;
+use std::collections::HashMap;
+
+use crate::builtin::{GString, StringName, Variant, VariantType};
+use crate::classes::{Object, Script as ScriptClass, ScriptLanguage};
+use crate::meta::{MethodInfo, PropertyInfo};
+use crate::obj::script::{ScriptInstance, SiMut};
+use crate::obj::{Gd, InstanceId};
+use crate::sys;
+
+use super::script::{ErasedScript, ScriptHost};
+
+/// Low-level script instance, which forwards to the [`Script`][super::Script] compiled by a [`ScriptHost`].
+pub(super) struct HostedInstance {
+    host: Gd<ScriptHost>,
+    script: Gd<ScriptClass>,
+    language: Gd<ScriptLanguage>,
+    state: Box<dyn Any>,
+
+    /// Values of declared properties, unless the script handles them itself.
+    values: HashMap<StringName, Variant>,
+
+    owner_id: InstanceId,
+    owner_class: GString,
+}
+
+impl HostedInstance {
+    pub fn new(host: Gd<ScriptHost>, compiled: &dyn ErasedScript, owner: &Gd<Object>) -> Self {
+        let values = compiled
+            .properties()
+            .into_iter()
+            .map(|property| {
+                let value = compiled
+                    .property_default(&property.property_name)
+                    .unwrap_or_default();
+
+                (property.property_name, value)
+            })
+            .collect();
+
+        let language = host.bind().language().upcast();
+
+        Self {
+            script: host.clone().upcast(),
+            host,
+            language,
+            state: compiled.instantiate(owner),
+            values,
+            owner_id: owner.instance_id(),
+            owner_class: owner.get_class(),
+        }
+    }
+
+    /// Runs `f` with the currently compiled script, or returns `None` if the script no longer compiles.
+    fn with_compiled<R>(&self, f: impl FnOnce(&dyn ErasedScript) -> R) -> Option<R> {
+        let host = self.host.bind();
+        host.compiled_erased().map(f)
+    }
+
+    fn find_method(&self, method: &StringName) -> Option<MethodInfo> {
+        self.method_list()
+            .into_iter()
+            .find(|info| &info.method_name == method)
+    }
+
+    fn method_list(&self) -> Vec<MethodInfo> {
+        self.with_compiled(|s| s.methods()).unwrap_or_default()
+    }
+}
+
+impl ScriptInstance for HostedInstance {
+    type Base = Object;
+
+    fn class_name(&self) -> GString {
+        self.owner_class.clone()
+    }
+
+    fn set_property(mut this: SiMut<Self>, name: StringName, value: &Variant) -> bool {
+        let host = this.host.clone();
+        let host = host.bind();
+        let Some(compiled) = host.compiled_erased() else {
+            return false;
+        };
+
+        if compiled.set(this.state.as_mut(), &name, value) {
+            return true;
+        }
+
+        match this.values.get_mut(&name) {
+            Some(stored) => {
+                *stored = value.clone();
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn get_property(&self, name: StringName) -> Option<Variant> {
+        self.with_compiled(|s| s.get(self.state.as_ref(), &name))
+            .flatten()
+            .or_else(|| self.values.get(&name).cloned())
+    }
+
+    fn get_property_list(&self) -> Vec<PropertyInfo> {
+        self.with_compiled(|s| s.properties()).unwrap_or_default()
+    }
+
+    fn get_method_list(&self) -> Vec<MethodInfo> {
+        self.method_list()
+    }
+
+    fn call(
+        mut this: SiMut<Self>,
+        method: StringName,
+        args: &[&Variant],
+    ) -> Result<Variant, sys::GDExtensionCallErrorType> {
+        let host = this.host.clone();
+        let host = host.bind();
+        let Some(compiled) = host.compiled_erased() else {
+            return Err(sys::GDEXTENSION_CALL_ERROR_INSTANCE_IS_NULL);
+        };
+
+        compiled.call(this.state.as_mut(), &method, args)
+    }
+
+    fn is_placeholder(&self) -> bool {
+        false
+    }
+
+    fn has_method(&self, method: StringName) -> bool {
+        self.find_method(&method).is_some()
+    }
+
+    fn get_script(&self) -> &Gd<ScriptClass> {
+        &self.script
+    }
+
+    fn get_property_type(&self, name: StringName) -> VariantType {
+        self.get_property_list()
+            .into_iter()
+            .find(|property| property.property_name == name)
+            .map_or(VariantType::NIL, |property| property.variant_type)
+    }
+
+    fn to_string(&self) -> GString {
+        format!("<{}#{}>", self.owner_class, self.owner_id).into()
+    }
+
+    fn get_property_state(&self) -> Vec<(StringName, Variant)> {
+        self.get_property_list()
+            .into_iter()
+            .filter_map(|property| {
+                let name = property.property_name;
+                let value = self.get_property(name.clone())?;
+                Some((name, value))
+            })
+            .collect()
+    }
+
+    fn get_language(&self) -> Gd<ScriptLanguage> {
+        self.language.clone()
+    }
+
+    fn on_refcount_decremented(&self) -> bool {
+        true
+    }
+
+    fn on_refcount_incremented(&self) {}
+
+    fn property_get_fallback(&self, _name: StringName) -> Option<Variant> {
+        None
+    }
+
+    fn property_set_fallback(_this: SiMut<Self>, _name: StringName, _value: &Variant) -> bool {
+        false
+    }
+
+    #[cfg(since_api = "4.3")]
+    fn get_method_argument_count(&self, method: StringName) -> Option<u32> {
+        self.find_method(&method)
+            .map(|info| info.arguments.len() as u32)
+    }
+}
@@ -0,0 +1,578 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::ffi::c_void;
+
+use godot_macros::{godot_api, GodotClass};
+
+use crate::builtin::{
+    dict, Array, Dictionary, GString, PackedStringArray, StringName, Variant, VariantArray,
+};
+use crate::classes::native::ScriptLanguageExtensionProfilingInfo;
+use crate::classes::{
+    Engine, IScriptLanguageExtension, Object, Script as ScriptClass, ScriptLanguageExtension,
+};
+use crate::global::Error;
+use crate::obj::{Base, Gd, GodotClass, WithBaseField};
+
+use super::script::{ErasedScript, Script, ScriptHost};
+
+/// A scripting language implemented in Rust.
+///
+/// Implement this trait and pass an instance to [`ScriptLanguageHost::register()`], which takes care of the Godot side: all the virtual
+/// methods of `ScriptLanguageExtension` are provided with sensible defaults, which only rely on the methods of this trait.
+///
+/// Only [`name()`][Self::name], [`extension()`][Self::extension] and [`compile()`][Self::compile] are required.
+pub trait ScriptLanguage: 'static {
+    /// Compiled form of a script in this language.
+    type Script: Script;
+
+    /// Human-readable name of the language, e.g. `"Lua"`. Must be unique among all registered languages.
+    fn name(&self) -> GString;
+
+    /// File extension of scripts in this language, without the dot, e.g. `"lua"`.
+    fn extension(&self) -> GString;
+
+    /// Compiles the source code of a script.
+    ///
+    /// Called whenever a script is reloaded, and to validate source code in the editor.
+    fn compile(&self, source: &GString) -> Result<Self::Script, ScriptError>;
+
+    /// Called once Godot initializes all script languages.
+    fn init(&mut self) {}
+
+    /// Called when the engine shuts down script languages.
+    fn finish(&mut self) {}
+
+    /// Called once per frame.
+    fn frame(&mut self) {}
+
+    /// Keywords of the language, used for syntax highlighting.
+    fn reserved_words(&self) -> PackedStringArray {
+        PackedStringArray::new()
+    }
+
+    /// Whether `keyword` affects control flow (e.g. `if`, `return`), which the editor highlights differently.
+    fn is_control_flow_keyword(&self, _keyword: &GString) -> bool {
+        false
+    }
+
+    /// Delimiters of comments, e.g. `"#"` for line comments or `"/* */"` for block comments.
+    fn comment_delimiters(&self) -> PackedStringArray {
+        PackedStringArray::from(&[GString::from("#")])
+    }
+
+    /// Delimiters of string literals, e.g. `"\" \""`.
+    fn string_delimiters(&self) -> PackedStringArray {
+        PackedStringArray::from(&[GString::from("\" \""), GString::from("' '")])
+    }
+
+    /// Source code for a new script, as created by the editor's "Attach Script" dialog.
+    fn template(&self, _class_name: &GString, _base_class_name: &GString) -> GString {
+        GString::new()
+    }
+}
+
+/// Error while compiling a script, see [`ScriptLanguage::compile()`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ScriptError {
+    /// 1-based line of the error, or 0 if unknown.
+    pub line: i32,
+
+    /// 1-based column of the error, or 0 if unknown.
+    pub column: i32,
+
+    /// Human-readable description.
+    pub message: String,
+}
+
+impl ScriptError {
+    /// Creates an error at the given position.
+    pub fn new(line: i32, column: i32, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    fn to_dictionary(&self) -> Dictionary {
+        dict! {
+            "line": self.line,
+            "column": self.column,
+            "message": self.message.as_str(),
+        }
+    }
+}
+
+impl std::fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Type erasure
+
+/// Object-safe counterpart of [`ScriptLanguage`], so that the host class does not need to be generic.
+pub(super) trait ErasedLanguage {
+    fn language(&self) -> &dyn LanguageInfo;
+    fn language_mut(&mut self) -> &mut dyn LanguageInfo;
+    fn compile_erased(&self, source: &GString) -> Result<Box<dyn ErasedScript>, ScriptError>;
+}
+
+/// The methods of [`ScriptLanguage`] that do not involve the script type.
+pub(super) trait LanguageInfo {
+    fn name(&self) -> GString;
+    fn extension(&self) -> GString;
+    fn init(&mut self);
+    fn finish(&mut self);
+    fn frame(&mut self);
+    fn reserved_words(&self) -> PackedStringArray;
+    fn is_control_flow_keyword(&self, keyword: &GString) -> bool;
+    fn comment_delimiters(&self) -> PackedStringArray;
+    fn string_delimiters(&self) -> PackedStringArray;
+    fn template(&self, class_name: &GString, base_class_name: &GString) -> GString;
+}
+
+impl<L: ScriptLanguage> LanguageInfo for L {
+    fn name(&self) -> GString {
+        ScriptLanguage::name(self)
+    }
+
+    fn extension(&self) -> GString {
+        ScriptLanguage::extension(self)
+    }
+
+    fn init(&mut self) {
+        ScriptLanguage::init(self)
+    }
+
+    fn finish(&mut self) {
+        ScriptLanguage::finish(self)
+    }
+
+    fn frame(&mut self) {
+        ScriptLanguage::frame(self)
+    }
+
+    fn reserved_words(&self) -> PackedStringArray {
+        ScriptLanguage::reserved_words(self)
+    }
+
+    fn is_control_flow_keyword(&self, keyword: &GString) -> bool {
+        ScriptLanguage::is_control_flow_keyword(self, keyword)
+    }
+
+    fn comment_delimiters(&self) -> PackedStringArray {
+        ScriptLanguage::comment_delimiters(self)
+    }
+
+    fn string_delimiters(&self) -> PackedStringArray {
+        ScriptLanguage::string_delimiters(self)
+    }
+
+    fn template(&self, class_name: &GString, base_class_name: &GString) -> GString {
+        ScriptLanguage::template(self, class_name, base_class_name)
+    }
+}
+
+impl<L: ScriptLanguage> ErasedLanguage for L {
+    fn language(&self) -> &dyn LanguageInfo {
+        self
+    }
+
+    fn language_mut(&mut self) -> &mut dyn LanguageInfo {
+        self
+    }
+
+    fn compile_erased(&self, source: &GString) -> Result<Box<dyn ErasedScript>, ScriptError> {
+        let script = self.compile(source)?;
+        Ok(Box::new(script))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// ScriptLanguageHost
+
+/// Godot-side `ScriptLanguage` object, which hosts a Rust [`ScriptLanguage`] implementation.
+///
+/// Scripts of the language are represented by [`ScriptHost`] objects.
+#[derive(GodotClass)]
+#[class(no_init, tool, base = ScriptLanguageExtension)]
+pub struct ScriptLanguageHost {
+    language: Box<dyn ErasedLanguage>,
+    base: Base<ScriptLanguageExtension>,
+}
+
+impl ScriptLanguageHost {
+    /// Creates a host for `language`, without registering it with the engine.
+    ///
+    /// Such a language can still be used to create scripts via [`new_script()`][Self::new_script], but is unknown to the editor.
+    pub fn new(language: impl ScriptLanguage) -> Gd<Self> {
+        Gd::from_init_fn(|base| Self {
+            language: Box::new(language),
+            base,
+        })
+    }
+
+    /// Creates a host for `language` and registers it with the engine, making it available to the editor and `ResourceLoader`.
+    ///
+    /// # Panics
+    /// If registration fails, e.g. because a language with the same name is already registered.
+    pub fn register(language: impl ScriptLanguage) -> Gd<Self> {
+        let host = Self::new(language);
+
+        let err = Engine::singleton().register_script_language(&host);
+        assert_eq!(
+            err,
+            Error::OK,
+            "failed to register script language '{}'",
+            host.bind().language.language().name()
+        );
+
+        host
+    }
+
+    /// Unregisters a language previously registered with [`register()`][Self::register], and frees it.
+    pub fn unregister(host: Gd<Self>) {
+        Engine::singleton().unregister_script_language(&host);
+        host.free();
+    }
+
+    /// Creates a new script in this language, and compiles the given source code.
+    ///
+    /// Compile errors are not fatal: the script is then invalid, see [`ScriptHost::compile_error()`].
+    pub fn new_script(&self, source: impl Into<GString>) -> Gd<ScriptHost> {
+        let mut script = ScriptHost::new(self.to_gd());
+        script.bind_mut().set_source_and_reload(source.into());
+        script
+    }
+
+    pub(super) fn compile(&self, source: &GString) -> Result<Box<dyn ErasedScript>, ScriptError> {
+        self.language.compile_erased(source)
+    }
+
+    fn info(&self) -> &dyn LanguageInfo {
+        self.language.language()
+    }
+}
+
+/// Result of `complete_code()` and `lookup_code()`, signaling that the feature is not supported.
+fn unavailable_code_result() -> Dictionary {
+    dict! {
+        "result": Error::ERR_UNAVAILABLE,
+        "force": false,
+        "call_hint": "",
+        "options": VariantArray::new(),
+        "type": 0,
+    }
+}
+
+#[godot_api]
+impl IScriptLanguageExtension for ScriptLanguageHost {
+    fn get_name(&self) -> GString {
+        self.info().name()
+    }
+
+    fn init_ext(&mut self) {
+        self.language.language_mut().init();
+    }
+
+    fn get_type(&self) -> GString {
+        ScriptHost::class_name().to_gstring()
+    }
+
+    fn get_extension(&self) -> GString {
+        self.info().extension()
+    }
+
+    fn finish(&mut self) {
+        self.language.language_mut().finish();
+    }
+
+    fn get_reserved_words(&self) -> PackedStringArray {
+        self.info().reserved_words()
+    }
+
+    fn is_control_flow_keyword(&self, keyword: GString) -> bool {
+        self.info().is_control_flow_keyword(&keyword)
+    }
+
+    fn get_comment_delimiters(&self) -> PackedStringArray {
+        self.info().comment_delimiters()
+    }
+
+    fn get_string_delimiters(&self) -> PackedStringArray {
+        self.info().string_delimiters()
+    }
+
+    fn make_template(
+        &self,
+        _template: GString,
+        class_name: GString,
+        base_class_name: GString,
+    ) -> Option<Gd<ScriptClass>> {
+        let source = self.info().template(&class_name, &base_class_name);
+        Some(self.new_script(source).upcast())
+    }
+
+    fn get_built_in_templates(&self, _object: StringName) -> Array<Dictionary> {
+        Array::new()
+    }
+
+    fn is_using_templates(&mut self) -> bool {
+        false
+    }
+
+    fn validate(
+        &self,
+        script: GString,
+        _path: GString,
+        _validate_functions: bool,
+        validate_errors: bool,
+        _validate_warnings: bool,
+        _validate_safe_lines: bool,
+    ) -> Dictionary {
+        let errors: Array<Dictionary> = match self.compile(&script) {
+            Err(err) if validate_errors => [err.to_dictionary()].into_iter().collect(),
+            _ => Array::new(),
+        };
+
+        dict! {
+            "valid": errors.is_empty(),
+            "errors": errors,
+            "warnings": Array::<Dictionary>::new(),
+            "safe_lines": Array::<i64>::new(),
+            "functions": Array::<GString>::new(),
+        }
+    }
+
+    fn validate_path(&self, _path: GString) -> GString {
+        GString::new()
+    }
+
+    fn create_script(&self) -> Option<Gd<Object>> {
+        Some(ScriptHost::new(self.to_gd()).upcast())
+    }
+
+    fn has_named_classes(&self) -> bool {
+        false
+    }
+
+    fn supports_builtin_mode(&self) -> bool {
+        false
+    }
+
+    fn supports_documentation(&self) -> bool {
+        false
+    }
+
+    fn can_inherit_from_file(&self) -> bool {
+        false
+    }
+
+    fn find_function(&self, _class_name: GString, _function_name: GString) -> i32 {
+        -1
+    }
+
+    fn make_function(
+        &self,
+        _class_name: GString,
+        _function_name: GString,
+        _function_args: PackedStringArray,
+    ) -> GString {
+        GString::new()
+    }
+
+    fn open_in_external_editor(
+        &mut self,
+        _script: Option<Gd<ScriptClass>>,
+        _line: i32,
+        _column: i32,
+    ) -> Error {
+        Error::ERR_UNAVAILABLE
+    }
+
+    fn overrides_external_editor(&mut self) -> bool {
+        false
+    }
+
+    fn complete_code(
+        &self,
+        _code: GString,
+        _path: GString,
+        _owner: Option<Gd<Object>>,
+    ) -> Dictionary {
+        unavailable_code_result()
+    }
+
+    fn lookup_code(
+        &self,
+        _code: GString,
+        _symbol: GString,
+        _path: GString,
+        _owner: Option<Gd<Object>>,
+    ) -> Dictionary {
+        unavailable_code_result()
+    }
+
+    fn auto_indent_code(&self, code: GString, _from_line: i32, _to_line: i32) -> GString {
+        code
+    }
+
+    fn add_global_constant(&mut self, _name: StringName, _value: Variant) {}
+
+    fn add_named_global_constant(&mut self, _name: StringName, _value: Variant) {}
+
+    fn remove_named_global_constant(&mut self, _name: StringName) {}
+
+    fn thread_enter(&mut self) {}
+
+    fn thread_exit(&mut self) {}
+
+    fn debug_get_error(&self) -> GString {
+        GString::new()
+    }
+
+    fn debug_get_stack_level_count(&self) -> i32 {
+        0
+    }
+
+    fn debug_get_stack_level_line(&self, _level: i32) -> i32 {
+        -1
+    }
+
+    fn debug_get_stack_level_function(&self, _level: i32) -> GString {
+        GString::new()
+    }
+
+    fn debug_get_stack_level_locals(
+        &mut self,
+        _level: i32,
+        _max_subitems: i32,
+        _max_depth: i32,
+    ) -> Dictionary {
+        Dictionary::new()
+    }
+
+    fn debug_get_stack_level_members(
+        &mut self,
+        _level: i32,
+        _max_subitems: i32,
+        _max_depth: i32,
+    ) -> Dictionary {
+        Dictionary::new()
+    }
+
+    unsafe fn debug_get_stack_level_instance(&mut self, _level: i32) -> *mut c_void {
+        std::ptr::null_mut()
+    }
+
+    fn debug_get_globals(&mut self, _max_subitems: i32, _max_depth: i32) -> Dictionary {
+        Dictionary::new()
+    }
+
+    fn debug_parse_stack_level_expression(
+        &mut self,
+        _level: i32,
+        _expression: GString,
+        _max_subitems: i32,
+        _max_depth: i32,
+    ) -> GString {
+        GString::new()
+    }
+
+    fn debug_get_current_stack_info(&mut self) -> Array<Dictionary> {
+        Array::new()
+    }
+
+    fn reload_all_scripts(&mut self) {}
+
+    fn reload_tool_script(&mut self, script: Option<Gd<ScriptClass>>, _soft_reload: bool) {
+        if let Some(Ok(mut script)) = script.map(|s| s.try_cast::<ScriptHost>()) {
+            script.bind_mut().reload_source();
+        }
+    }
+
+    fn get_recognized_extensions(&self) -> PackedStringArray {
+        PackedStringArray::from(&[self.info().extension()])
+    }
+
+    fn get_public_functions(&self) -> Array<Dictionary> {
+        Array::new()
+    }
+
+    fn get_public_constants(&self) -> Dictionary {
+        Dictionary::new()
+    }
+
+    fn get_public_annotations(&self) -> Array<Dictionary> {
+        Array::new()
+    }
+
+    fn profiling_start(&mut self) {}
+
+    fn profiling_stop(&mut self) {}
+
+    unsafe fn profiling_get_accumulated_data(
+        &mut self,
+        _info_array: *mut ScriptLanguageExtensionProfilingInfo,
+        _info_max: i32,
+    ) -> i32 {
+        0
+    }
+
+    unsafe fn profiling_get_frame_data(
+        &mut self,
+        _info_array: *mut ScriptLanguageExtensionProfilingInfo,
+        _info_max: i32,
+    ) -> i32 {
+        0
+    }
+
+    fn frame(&mut self) {
+        self.language.language_mut().frame();
+    }
+
+    fn handles_global_class_type(&self, type_: GString) -> bool {
+        type_ == self.get_type()
+    }
+
+    fn get_global_class_name(&self, _path: GString) -> Dictionary {
+        Dictionary::new()
+    }
+
+    #[cfg(since_api = "4.3")]
+    fn profiling_set_save_native_calls(&mut self, _enable: bool) {}
+
+    #[cfg(since_api = "4.3")]
+    fn debug_get_stack_level_source(&self, _level: i32) -> GString {
+        GString::new()
+    }
+
+    #[cfg(since_api = "4.3")]
+    fn can_make_function(&self) -> bool {
+        false
+    }
+
+    #[cfg(since_api = "4.3")]
+    fn preferred_file_name_casing(&self) -> crate::classes::script_language::ScriptNameCasing {
+        crate::classes::script_language::ScriptNameCasing::AUTO
+    }
+
+    #[cfg(since_api = "4.4")]
+    fn reload_scripts(&mut self, scripts: Array<Variant>, _soft: bool) {
+        for script in scripts.iter_shared() {
+            if let Ok(mut script) = script.try_to::<Gd<ScriptHost>>() {
+                script.bind_mut().reload_source();
+            }
+        }
+    }
+}
@@ -0,0 +1,78 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Toolkit to implement custom scripting languages.
+//!
+//! Godot supports additional scripting languages through the `ScriptLanguageExtension` and `ScriptExtension` classes. Implementing them
+//! directly means providing dozens of virtual methods, most of which are boilerplate. The low-level building block for script instances
+//! is [`obj::script::ScriptInstance`][crate::obj::script::ScriptInstance].
+//!
+//! This module builds on top of that, and reduces a language to two traits:
+//! - [`ScriptLanguage`] describes the language itself and compiles source code.
+//! - [`Script`] is the compiled form of one script: it declares properties and methods, and executes calls on the objects it is attached to.
+//!
+//! Godot-side objects are provided by [`ScriptLanguageHost`] and [`ScriptHost`], which implement all virtual methods with defaults derived
+//! from the traits: placeholder instances in the editor, reloading, property and method reflection, and registration with [`Engine`].
+//!
+//! [`Engine`]: crate::classes::Engine
+//!
+//! # Example
+//! A language in which each line of the source code declares an integer property. It has no methods.
+//!
+//! ```no_run
+//! use godot::prelude::*;
+//! use godot::meta::PropertyInfo;
+//! use godot::sys;
+//! use godot::tools::scripting::{Script, ScriptError, ScriptLanguage, ScriptLanguageHost};
+//!
+//! struct Counters;
+//!
+//! impl ScriptLanguage for Counters {
+//!     type Script = CounterScript;
+//!
+//!     fn name(&self) -> GString { "Counters".into() }
+//!     fn extension(&self) -> GString { "counters".into() }
+//!
+//!     fn compile(&self, source: &GString) -> Result<CounterScript, ScriptError> {
+//!         let names = source.to_string().lines().map(str::trim).filter(|l| !l.is_empty()).map(String::from).collect();
+//!         Ok(CounterScript { names })
+//!     }
+//! }
+//!
+//! struct CounterScript {
+//!     names: Vec<String>,
+//! }
+//!
+//! impl Script for CounterScript {
+//!     type State = ();
+//!
+//!     fn instantiate(&self, _owner: &Gd<Object>) {}
+//!
+//!     fn properties(&self) -> Vec<PropertyInfo> {
+//!         self.names.iter().map(|name| PropertyInfo::new_var::<i64>(name)).collect()
+//!     }
+//!
+//!     fn call(&self, _state: &mut (), _method: &StringName, _args: &[&Variant]) -> Result<Variant, sys::GDExtensionCallErrorType> {
+//!         Err(sys::GDEXTENSION_CALL_ERROR_INVALID_METHOD)
+//!     }
+//! }
+//!
+//! // Typically during extension initialization:
+//! let language = ScriptLanguageHost::register(Counters);
+//! let script = language.bind().new_script("health\nmana");
+//!
+//! let mut object = Object::new_alloc();
+//! object.set_script(&script.to_variant());
+//! object.set("health", &100.to_variant());
+//! ```
+
+mod instance;
+mod language;
+mod script;
+
+pub use language::{ScriptError, ScriptLanguage, ScriptLanguageHost};
+pub use script::{Script, ScriptHost};
@@ -0,0 +1,531 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::ffi::c_void;
+
+use godot_macros::{godot_api, GodotClass};
+
+use crate::builtin::{Array, Dictionary, GString, StringName, Variant};
+use crate::classes::{
+    Engine, IScriptExtension, Object, Script as ScriptClass, ScriptExtension, ScriptLanguage,
+};
+use crate::global::Error;
+use crate::meta::{MethodInfo, PropertyInfo};
+use crate::obj::script::create_script_instance;
+use crate::obj::{Base, Gd, WithBaseField};
+use crate::sys;
+
+use super::instance::HostedInstance;
+use super::language::{ScriptError, ScriptLanguageHost};
+
+/// Compiled script of a [`ScriptLanguage`][super::ScriptLanguage].
+///
+/// A script describes the members of the objects it is attached to, and executes their methods. The state of each such object is kept
+/// in a separate [`State`][Self::State] value, created by [`instantiate()`][Self::instantiate].
+///
+/// Property and method reflection (`has_method()`, `get_property_list()`, editor inspector, ...) is derived from [`properties()`][Self::properties]
+/// and [`methods()`][Self::methods]. Properties listed there are stored automatically, unless [`get()`][Self::get] and [`set()`][Self::set]
+/// handle them.
+pub trait Script: 'static {
+    /// Per-object state of a script instance.
+    type State: 'static;
+
+    /// Creates the state for a new object that this script is attached to.
+    ///
+    /// Avoid storing `owner` as `Gd`: the state is owned by the object, so this would create a reference cycle for `RefCounted` owners.
+    /// Store its [`InstanceId`][crate::obj::InstanceId] instead.
+    fn instantiate(&self, owner: &Gd<Object>) -> Self::State;
+
+    /// Calls a method on the script instance.
+    ///
+    /// Return `Err(sys::GDEXTENSION_CALL_ERROR_INVALID_METHOD)` for unknown methods, so that Godot can fall back to native methods.
+    fn call(
+        &self,
+        state: &mut Self::State,
+        method: &StringName,
+        args: &[&Variant],
+    ) -> Result<Variant, sys::GDExtensionCallErrorType>;
+
+    /// Reads a property with custom logic. Returns `None` to fall back to the stored value of properties in [`properties()`][Self::properties].
+    fn get(&self, _state: &Self::State, _name: &StringName) -> Option<Variant> {
+        None
+    }
+
+    /// Writes a property with custom logic. Returns `false` to fall back to storing properties in [`properties()`][Self::properties].
+    fn set(&self, _state: &mut Self::State, _name: &StringName, _value: &Variant) -> bool {
+        false
+    }
+
+    /// Name of the engine class which objects must inherit from to use this script, e.g. `"Node"`.
+    fn base_type(&self) -> StringName {
+        StringName::from("Object")
+    }
+
+    /// Name under which the script is available as a global class, or empty.
+    fn global_name(&self) -> StringName {
+        StringName::default()
+    }
+
+    /// Whether the script runs in the editor. Other scripts only get placeholder instances in the editor.
+    fn is_tool(&self) -> bool {
+        false
+    }
+
+    /// Properties declared by the script.
+    fn properties(&self) -> Vec<PropertyInfo> {
+        Vec::new()
+    }
+
+    /// Initial value of a property in [`properties()`][Self::properties], also shown as default in the inspector.
+    fn property_default(&self, _name: &StringName) -> Option<Variant> {
+        None
+    }
+
+    /// Methods declared by the script.
+    fn methods(&self) -> Vec<MethodInfo> {
+        Vec::new()
+    }
+
+    /// Signals declared by the script.
+    fn signals(&self) -> Vec<MethodInfo> {
+        Vec::new()
+    }
+
+    /// Constants declared by the script.
+    fn constants(&self) -> Dictionary {
+        Dictionary::new()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Type erasure
+
+/// Object-safe counterpart of [`Script`], with the state type erased.
+pub(super) trait ErasedScript {
+    fn as_any(&self) -> &dyn Any;
+    fn instantiate(&self, owner: &Gd<Object>) -> Box<dyn Any>;
+    fn call(
+        &self,
+        state: &mut dyn Any,
+        method: &StringName,
+        args: &[&Variant],
+    ) -> Result<Variant, sys::GDExtensionCallErrorType>;
+    fn get(&self, state: &dyn Any, name: &StringName) -> Option<Variant>;
+    fn set(&self, state: &mut dyn Any, name: &StringName, value: &Variant) -> bool;
+    fn base_type(&self) -> StringName;
+    fn global_name(&self) -> StringName;
+    fn is_tool(&self) -> bool;
+    fn properties(&self) -> Vec<PropertyInfo>;
+    fn property_default(&self, name: &StringName) -> Option<Variant>;
+    fn methods(&self) -> Vec<MethodInfo>;
+    fn signals(&self) -> Vec<MethodInfo>;
+    fn constants(&self) -> Dictionary;
+}
+
+fn downcast_state<S: Script>(state: &dyn Any) -> &S::State {
+    state
+        .downcast_ref()
+        .expect("script state has type of the script's language")
+}
+
+impl<S: Script> ErasedScript for S {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn instantiate(&self, owner: &Gd<Object>) -> Box<dyn Any> {
+        Box::new(Script::instantiate(self, owner))
+    }
+
+    fn call(
+        &self,
+        state: &mut dyn Any,
+        method: &StringName,
+        args: &[&Variant],
+    ) -> Result<Variant, sys::GDExtensionCallErrorType> {
+        let state = state
+            .downcast_mut()
+            .expect("script state has type of the script's language");
+
+        Script::call(self, state, method, args)
+    }
+
+    fn get(&self, state: &dyn Any, name: &StringName) -> Option<Variant> {
+        Script::get(self, downcast_state::<S>(state), name)
+    }
+
+    fn set(&self, state: &mut dyn Any, name: &StringName, value: &Variant) -> bool {
+        let state = state
+            .downcast_mut()
+            .expect("script state has type of the script's language");
+
+        Script::set(self, state, name, value)
+    }
+
+    fn base_type(&self) -> StringName {
+        Script::base_type(self)
+    }
+
+    fn global_name(&self) -> StringName {
+        Script::global_name(self)
+    }
+
+    fn is_tool(&self) -> bool {
+        Script::is_tool(self)
+    }
+
+    fn properties(&self) -> Vec<PropertyInfo> {
+        Script::properties(self)
+    }
+
+    fn property_default(&self, name: &StringName) -> Option<Variant> {
+        Script::property_default(self, name)
+    }
+
+    fn methods(&self) -> Vec<MethodInfo> {
+        Script::methods(self)
+    }
+
+    fn signals(&self) -> Vec<MethodInfo> {
+        Script::signals(self)
+    }
+
+    fn constants(&self) -> Dictionary {
+        Script::constants(self)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// ScriptHost
+
+/// Godot-side `Script` resource, which holds the source code and compiled form of a [`Script`].
+///
+/// Created via [`ScriptLanguageHost::new_script()`], or by the editor through the language.
+#[derive(GodotClass)]
+#[class(no_init, tool, base = ScriptExtension)]
+pub struct ScriptHost {
+    language: Gd<ScriptLanguageHost>,
+    source: GString,
+    compiled: Option<Box<dyn ErasedScript>>,
+    compile_error: Option<ScriptError>,
+
+    /// Placeholder instances created by Godot, which need to be updated when the exported properties change.
+    placeholders: RefCell<Vec<*mut c_void>>,
+
+    base: Base<ScriptExtension>,
+}
+
+impl ScriptHost {
+    pub(super) fn new(language: Gd<ScriptLanguageHost>) -> Gd<Self> {
+        Gd::from_init_fn(|base| Self {
+            language,
+            source: GString::new(),
+            compiled: None,
+            compile_error: None,
+            placeholders: RefCell::new(Vec::new()),
+            base,
+        })
+    }
+
+    /// Returns the compiled script, if the source code compiled successfully and `S` is the script type of the language.
+    pub fn compiled<S: Script>(&self) -> Option<&S> {
+        self.compiled.as_ref()?.as_any().downcast_ref()
+    }
+
+    /// Error of the last compilation, if it failed.
+    pub fn compile_error(&self) -> Option<&ScriptError> {
+        self.compile_error.as_ref()
+    }
+
+    /// Returns the language host this script belongs to.
+    pub fn language(&self) -> Gd<ScriptLanguageHost> {
+        self.language.clone()
+    }
+
+    pub(super) fn compiled_erased(&self) -> Option<&dyn ErasedScript> {
+        self.compiled.as_deref()
+    }
+
+    pub(super) fn set_source_and_reload(&mut self, source: GString) {
+        self.source = source;
+        self.reload_source();
+    }
+
+    /// Compiles the current source code. Existing instances keep their state.
+    pub(super) fn reload_source(&mut self) -> Error {
+        let result = self.language.bind().compile(&self.source);
+
+        let err = match result {
+            Ok(compiled) => {
+                self.compiled = Some(compiled);
+                self.compile_error = None;
+                Error::OK
+            }
+            Err(err) => {
+                self.compiled = None;
+                self.compile_error = Some(err);
+                Error::ERR_PARSE_ERROR
+            }
+        };
+
+        self.update_placeholders();
+        err
+    }
+
+    fn property_list(&self) -> Array<Dictionary> {
+        self.compiled_erased()
+            .map(|s| {
+                s.properties()
+                    .iter()
+                    .map(PropertyInfo::to_dictionary)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn find_method(&self, method: &StringName) -> Option<MethodInfo> {
+        self.compiled_erased()?
+            .methods()
+            .into_iter()
+            .find(|info| &info.method_name == method)
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn update_placeholders(&self) {
+        use crate::sys::GodotFfi as _;
+
+        let placeholders = self.placeholders.borrow();
+        if placeholders.is_empty() {
+            return;
+        }
+
+        let properties = self.property_list();
+        let mut values = Dictionary::new();
+        if let Some(compiled) = self.compiled_erased() {
+            for property in compiled.properties() {
+                if let Some(value) = compiled.property_default(&property.property_name) {
+                    values.set(property.property_name, value);
+                }
+            }
+        }
+
+        let update_fn = sys::interface_fn!(placeholder_script_instance_update);
+        for &placeholder in placeholders.iter() {
+            // SAFETY: Placeholders are removed from the list in `placeholder_erased()` before they are destroyed. Array and dictionary
+            // are valid for the duration of the call.
+            unsafe { update_fn(placeholder, properties.sys(), values.sys()) };
+        }
+    }
+
+    #[cfg(before_api = "4.2")]
+    fn update_placeholders(&self) {}
+
+    #[cfg(since_api = "4.2")]
+    fn create_placeholder(&self, for_object: &Gd<Object>) -> *mut c_void {
+        let create_fn = sys::interface_fn!(placeholder_script_instance_create);
+
+        // SAFETY: Language, script and owner are live objects.
+        let placeholder = unsafe {
+            create_fn(
+                self.language.obj_sys(),
+                self.to_gd().obj_sys(),
+                for_object.obj_sys(),
+            )
+        };
+
+        self.placeholders.borrow_mut().push(placeholder);
+        self.update_placeholders();
+        placeholder
+    }
+
+    // Extensions can only create placeholders since Godot 4.2; before, objects in the editor simply have no script instance.
+    #[cfg(before_api = "4.2")]
+    fn create_placeholder(&self, _for_object: &Gd<Object>) -> *mut c_void {
+        std::ptr::null_mut()
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn has_instance(&self, object: &Gd<Object>) -> bool {
+        crate::obj::script::script_instance_exists(object, &self.to_gd())
+    }
+
+    #[cfg(before_api = "4.2")]
+    fn has_instance(&self, object: &Gd<Object>) -> bool {
+        object.get_script().object_id() == Some(self.base().instance_id())
+    }
+}
+
+#[godot_api]
+impl IScriptExtension for ScriptHost {
+    fn editor_can_reload_from_file(&mut self) -> bool {
+        true
+    }
+
+    fn can_instantiate(&self) -> bool {
+        self.is_valid() && (self.is_tool() || !Engine::singleton().is_editor_hint())
+    }
+
+    fn get_base_script(&self) -> Option<Gd<ScriptClass>> {
+        None
+    }
+
+    fn get_global_name(&self) -> StringName {
+        self.compiled_erased()
+            .map(|s| s.global_name())
+            .unwrap_or_default()
+    }
+
+    fn inherits_script(&self, script: Gd<ScriptClass>) -> bool {
+        script.instance_id() == self.base().instance_id()
+    }
+
+    fn get_instance_base_type(&self) -> StringName {
+        self.compiled_erased()
+            .map_or_else(|| StringName::from("Object"), |s| s.base_type())
+    }
+
+    unsafe fn instance_create(&self, for_object: Gd<Object>) -> *mut c_void {
+        let Some(compiled) = self.compiled_erased() else {
+            crate::global::godot_error!("cannot instantiate script which failed to compile");
+            return std::ptr::null_mut();
+        };
+
+        let instance = HostedInstance::new(self.to_gd(), compiled, &for_object);
+        create_script_instance(instance, for_object)
+    }
+
+    unsafe fn placeholder_instance_create(&self, for_object: Gd<Object>) -> *mut c_void {
+        self.create_placeholder(&for_object)
+    }
+
+    unsafe fn placeholder_erased(&mut self, placeholder: *mut c_void) {
+        self.placeholders.get_mut().retain(|&p| p != placeholder);
+    }
+
+    fn instance_has(&self, object: Gd<Object>) -> bool {
+        self.has_instance(&object)
+    }
+
+    fn has_source_code(&self) -> bool {
+        !self.source.is_empty()
+    }
+
+    fn get_source_code(&self) -> GString {
+        self.source.clone()
+    }
+
+    fn set_source_code(&mut self, code: GString) {
+        self.source = code;
+    }
+
+    fn reload(&mut self, _keep_state: bool) -> Error {
+        self.reload_source()
+    }
+
+    fn get_documentation(&self) -> Array<Dictionary> {
+        Array::new()
+    }
+
+    fn has_method(&self, method: StringName) -> bool {
+        self.find_method(&method).is_some()
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn has_static_method(&self, _method: StringName) -> bool {
+        false
+    }
+
+    fn get_method_info(&self, method: StringName) -> Dictionary {
+        self.find_method(&method)
+            .map(|info| info.to_dictionary())
+            .unwrap_or_default()
+    }
+
+    fn is_tool(&self) -> bool {
+        self.compiled_erased().is_some_and(|s| s.is_tool())
+    }
+
+    fn is_valid(&self) -> bool {
+        self.compiled.is_some()
+    }
+
+    fn get_language(&self) -> Option<Gd<ScriptLanguage>> {
+        Some(self.language.clone().upcast())
+    }
+
+    fn has_script_signal(&self, signal: StringName) -> bool {
+        self.compiled_erased()
+            .is_some_and(|s| s.signals().iter().any(|info| info.method_name == signal))
+    }
+
+    fn get_script_signal_list(&self) -> Array<Dictionary> {
+        self.compiled_erased()
+            .map(|s| s.signals().iter().map(MethodInfo::to_dictionary).collect())
+            .unwrap_or_default()
+    }
+
+    fn has_property_default_value(&self, property: StringName) -> bool {
+        self.compiled_erased()
+            .is_some_and(|s| s.property_default(&property).is_some())
+    }
+
+    fn get_property_default_value(&self, property: StringName) -> Variant {
+        self.compiled_erased()
+            .and_then(|s| s.property_default(&property))
+            .unwrap_or_default()
+    }
+
+    fn update_exports(&mut self) {
+        self.update_placeholders();
+    }
+
+    fn get_script_method_list(&self) -> Array<Dictionary> {
+        self.compiled_erased()
+            .map(|s| s.methods().iter().map(MethodInfo::to_dictionary).collect())
+            .unwrap_or_default()
+    }
+
+    fn get_script_property_list(&self) -> Array<Dictionary> {
+        self.property_list()
+    }
+
+    fn get_member_line(&self, _member: StringName) -> i32 {
+        -1
+    }
+
+    fn get_constants(&self) -> Dictionary {
+        self.compiled_erased()
+            .map(|s| s.constants())
+            .unwrap_or_default()
+    }
+
+    fn get_members(&self) -> Array<StringName> {
+        self.compiled_erased()
+            .map(|s| {
+                s.properties()
+                    .into_iter()
+                    .map(|p| p.property_name)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn is_placeholder_fallback_enabled(&self) -> bool {
+        false
+    }
+
+    fn get_rpc_config(&self) -> Variant {
+        Variant::from(Dictionary::new())
+    }
+
+    #[cfg(since_api = "4.4")]
+    fn get_doc_class_name(&self) -> StringName {
+        self.get_global_name()
+    }
+}
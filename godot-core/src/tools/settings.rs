@@ -0,0 +1,1427 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Serde adapters for `ConfigFile` and `ProjectSettings`.
+
+use std::collections::BTreeMap;
+
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, Deserializer as _, IntoDeserializer, MapAccess,
+    SeqAccess, Visitor,
+};
+use serde::ser::{self, Impossible, Serialize, Serializer as _};
+
+use crate::builtin::{
+    real, Color, Dictionary, GString, PackedByteArray, PackedFloat32Array, PackedFloat64Array,
+    PackedInt32Array, PackedInt64Array, PackedStringArray, Variant, VariantArray, VariantType,
+    Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i,
+};
+use crate::classes::{ConfigFile, ProjectSettings};
+use crate::meta::error::SettingsError;
+use crate::meta::ToGodot;
+use crate::obj::Gd;
+
+/// Deserializes a struct from all sections of a [`ConfigFile`].
+///
+/// Each section maps to a field of nested struct type, and each key in that section to a field of the nested struct. Keys containing `/`
+/// map to further nested structs. Values are converted from their `Variant` representation; missing keys are only accepted for `Option`
+/// fields and fields with `#[serde(default)]`.
+///
+/// ```no_run
+/// use godot::classes::ConfigFile;
+/// use godot::prelude::*;
+/// use godot::tools::from_config_file;
+/// use serde::Deserialize;
+///
+/// // [audio]
+/// // volume=0.8
+/// // muted=false
+/// //
+/// // [video]
+/// // fullscreen=true
+/// // resolution=Vector2i(1920, 1080)
+/// #[derive(Deserialize)]
+/// struct Settings {
+///     audio: Audio,
+///     video: Video,
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Audio {
+///     volume: f32,
+///     muted: bool,
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Video {
+///     fullscreen: bool,
+///     resolution: Vector2i,
+/// }
+///
+/// let mut config = ConfigFile::new_gd();
+/// config.load("user://settings.cfg");
+///
+/// match from_config_file::<Settings>(&config) {
+///     Ok(settings) => godot_print!("volume: {}", settings.audio.volume),
+///     Err(err) => godot_error!("invalid settings: {err}"), // e.g. "key `audio/volume`: invalid type: ..."
+/// }
+/// ```
+///
+/// # Supported types
+/// - `bool`, integers, floats, `char`, `String` and [`GString`].
+/// - `Vec<T>`, tuples and other sequences, from `Array` and packed arrays.
+/// - `HashMap<String, T>` and other maps, from `Dictionary` or from all keys of a section.
+/// - [`Vector2`], [`Vector2i`], [`Vector3`], [`Vector3i`], [`Vector4`], [`Vector4i`] and [`Color`].
+/// - Enums with unit variants, from strings.
+pub fn from_config_file<T>(config: &Gd<ConfigFile>) -> Result<T, SettingsError>
+where
+    T: DeserializeOwned,
+{
+    let mut entries = BTreeMap::new();
+    for section in config.get_sections().as_slice() {
+        for key in config.get_section_keys(section).as_slice() {
+            let path = join_path(&section.to_string(), &key.to_string());
+            entries.insert(path, config.get_value(section, key));
+        }
+    }
+
+    T::deserialize(EntryDeserializer {
+        entries: &entries,
+        path: String::new(),
+    })
+}
+
+/// Serializes a struct into sections of a [`ConfigFile`].
+///
+/// The inverse of [`from_config_file()`]: fields of nested struct type become sections. Existing keys are overwritten, other keys are
+/// kept. `None` values are skipped. The file is not saved; call [`ConfigFile::save()`] afterwards.
+pub fn to_config_file<T>(value: &T, config: &mut Gd<ConfigFile>) -> Result<(), SettingsError>
+where
+    T: Serialize + ?Sized,
+{
+    for (path, value) in to_entries(value, "")? {
+        // Values outside of structs end up in the unnamed section at the top of the file.
+        let (section, key) = path.split_once('/').unwrap_or(("", path.as_str()));
+        config.set_value(section, key, &value);
+    }
+
+    Ok(())
+}
+
+/// Deserializes a struct from all [`ProjectSettings`] below `prefix`.
+///
+/// Fields map to settings `<prefix>/<field>`, and nested structs to further path segments. For example, with prefix `"game"`, the field
+/// `volume` of a nested struct in field `audio` is read from setting `game/audio/volume`.
+///
+/// See [`from_config_file()`] for supported types.
+pub fn from_project_settings<T>(prefix: &str) -> Result<T, SettingsError>
+where
+    T: DeserializeOwned,
+{
+    let settings = ProjectSettings::singleton();
+    let prefix = prefix.trim_end_matches('/');
+    let child_prefix = child_prefix(prefix);
+
+    let mut entries = BTreeMap::new();
+    for property in settings.get_property_list().iter_shared() {
+        let name = property.get_or_nil("name").to_string();
+        if name.starts_with(&child_prefix) {
+            let value = settings.get_setting(name.as_str());
+            entries.insert(name, value);
+        }
+    }
+
+    T::deserialize(EntryDeserializer {
+        entries: &entries,
+        path: prefix.to_string(),
+    })
+}
+
+/// Serializes a struct into [`ProjectSettings`] below `prefix`.
+///
+/// The inverse of [`from_project_settings()`]. `None` values are skipped. Settings are only changed in memory; call
+/// [`ProjectSettings::save()`] to persist them in `project.godot`.
+pub fn to_project_settings<T>(value: &T, prefix: &str) -> Result<(), SettingsError>
+where
+    T: Serialize + ?Sized,
+{
+    let entries = to_entries(value, prefix.trim_end_matches('/'))?;
+
+    let mut settings = ProjectSettings::singleton();
+    for (name, value) in entries {
+        settings.set_setting(name.as_str(), &value);
+    }
+
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Deserialization
+
+/// Deserializes the entry at `path`, or the nested struct/map formed by all entries below `path`.
+struct EntryDeserializer<'a> {
+    entries: &'a BTreeMap<String, Variant>,
+    path: String,
+}
+
+impl EntryDeserializer<'_> {
+    fn value(&self) -> Result<VariantDeserializer, SettingsError> {
+        match self.entries.get(&self.path) {
+            Some(value) => Ok(VariantDeserializer {
+                value: value.clone(),
+                path: self.path.clone(),
+            }),
+            None => Err(SettingsError::new("missing key").at_key(&self.path)),
+        }
+    }
+
+    fn child(&self, name: &str) -> Self {
+        EntryDeserializer {
+            entries: self.entries,
+            path: join_path(&self.path, name),
+        }
+    }
+
+    fn exists(&self) -> bool {
+        self.entries.contains_key(&self.path) || !self.child_names().is_empty()
+    }
+
+    /// Names of the direct children, i.e. the next path segment of all entries below `path`.
+    fn child_names(&self) -> Vec<String> {
+        let prefix = child_prefix(&self.path);
+
+        let mut names: Vec<String> = self
+            .entries
+            .range(prefix.clone()..)
+            .map(|(key, _)| key)
+            .take_while(|key| key.starts_with(&prefix))
+            .filter_map(|key| key[prefix.len()..].split('/').next())
+            .map(str::to_string)
+            .collect();
+
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    fn visit_children<'de, V>(
+        self,
+        names: Vec<String>,
+        visitor: V,
+    ) -> Result<V::Value, SettingsError>
+    where
+        V: Visitor<'de>,
+    {
+        let path = self.path.clone();
+        let access = ChildAccess {
+            parent: &self,
+            names: names.into_iter(),
+            current: None,
+        };
+
+        visitor.visit_map(access).map_err(|err| err.at_key(&path))
+    }
+}
+
+macro_rules! forward_to_value {
+    ($($method:ident)*) => {$(
+        fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            self.value()?.$method(visitor)
+        }
+    )*};
+}
+
+impl<'de> de::Deserializer<'de> for EntryDeserializer<'_> {
+    type Error = SettingsError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        if self.entries.contains_key(&self.path) {
+            self.value()?.deserialize_any(visitor)
+        } else {
+            self.deserialize_map(visitor)
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        if self.exists() {
+            visitor.visit_some(self)
+        } else {
+            visitor.visit_none()
+        }
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        // A single entry can hold a whole dictionary.
+        if self.entries.contains_key(&self.path) {
+            return self.value()?.deserialize_map(visitor);
+        }
+
+        let names = self.child_names();
+        self.visit_children(names, visitor)
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        // Built-in structs such as Vector2 are stored as single values.
+        if self.entries.contains_key(&self.path) {
+            return self.value()?.deserialize_struct(name, fields, visitor);
+        }
+
+        // Only present fields are visited; serde reports missing ones (e.g. `video/fullscreen`), unless they are optional or have
+        // defaults. Unknown keys are ignored.
+        let names = fields
+            .iter()
+            .filter(|field| self.child(field).exists())
+            .map(|field| field.to_string())
+            .collect();
+
+        self.visit_children(names, visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_unit_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.value()?.deserialize_unit_struct(name, visitor)
+    }
+
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.value()?.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.value()?.deserialize_tuple_struct(name, len, visitor)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.value()?.deserialize_enum(name, variants, visitor)
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    forward_to_value! {
+        deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64 deserialize_u8 deserialize_u16
+        deserialize_u32 deserialize_u64 deserialize_f32 deserialize_f64 deserialize_char deserialize_str deserialize_string
+        deserialize_bytes deserialize_byte_buf deserialize_unit deserialize_seq deserialize_identifier
+    }
+}
+
+/// Visits the children of an [`EntryDeserializer`] as map or struct fields.
+struct ChildAccess<'p, 'a> {
+    parent: &'p EntryDeserializer<'a>,
+    names: std::vec::IntoIter<String>,
+    current: Option<String>,
+}
+
+impl<'de> MapAccess<'de> for ChildAccess<'_, '_> {
+    type Error = SettingsError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        let Some(name) = self.names.next() else {
+            return Ok(None);
+        };
+
+        let key_de: StrDeserializer = name.as_str().into_deserializer();
+        let key = seed.deserialize(key_de)?;
+        self.current = Some(name);
+
+        Ok(Some(key))
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let name = self
+            .current
+            .take()
+            .expect("next_value_seed() called before next_key_seed()");
+
+        seed.deserialize(self.parent.child(&name))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.names.len())
+    }
+}
+
+type StrDeserializer<'s> = de::value::StrDeserializer<'s, SettingsError>;
+
+/// Deserializes a single `Variant`.
+struct VariantDeserializer {
+    value: Variant,
+
+    /// Key for error messages.
+    path: String,
+}
+
+impl VariantDeserializer {
+    fn visit<'de, V>(self, visitor: V) -> Result<V::Value, SettingsError>
+    where
+        V: Visitor<'de>,
+    {
+        let value = &self.value;
+
+        match value.get_type() {
+            VariantType::NIL => visitor.visit_unit(),
+            VariantType::BOOL => visitor.visit_bool(value.to::<bool>()),
+            VariantType::INT => visitor.visit_i64(value.to::<i64>()),
+            VariantType::FLOAT => visitor.visit_f64(value.to::<f64>()),
+            VariantType::STRING | VariantType::STRING_NAME | VariantType::NODE_PATH => {
+                visitor.visit_string(value.to_string())
+            }
+            VariantType::ARRAY => {
+                // SAFETY: type is ARRAY. Elements are only read, so typed arrays (which cannot be converted to VariantArray) are fine.
+                let array = unsafe { VariantArray::from_variant_unchecked(value) };
+                let elements = array.iter_shared().collect();
+                visitor.visit_seq(VariantSeq::new(elements, self.path))
+            }
+            VariantType::DICTIONARY => {
+                let entries = value.to::<Dictionary>().iter_shared().collect();
+                visitor.visit_map(VariantMap::new(entries, self.path))
+            }
+            ty => {
+                if let Some(elements) = packed_elements(value) {
+                    visitor.visit_seq(VariantSeq::new(elements, self.path))
+                } else if let Some(fields) = builtin_fields(value) {
+                    let entries = fields
+                        .into_iter()
+                        .map(|(name, value)| (name.to_variant(), value))
+                        .collect();
+                    visitor.visit_map(VariantMap::new(entries, self.path))
+                } else {
+                    Err(SettingsError::new(format!(
+                        "unsupported Variant type {ty:?}"
+                    )))
+                }
+            }
+        }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for VariantDeserializer {
+    type Error = SettingsError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let path = self.path.clone();
+        self.visit(visitor).map_err(|err| err.at_key(&path))
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let path = self.path.clone();
+
+        let result = if self.value.is_nil() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        };
+        result.map_err(|err| err.at_key(&path))
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let result = match self.value.get_type() {
+            VariantType::STRING | VariantType::STRING_NAME => {
+                let variant: de::value::StringDeserializer<SettingsError> =
+                    self.value.to_string().into_deserializer();
+                visitor.visit_enum(variant)
+            }
+            ty => Err(SettingsError::new(format!(
+                "expected string for enum, found Variant type {ty:?}"
+            ))),
+        };
+
+        result.map_err(|err| err.at_key(&self.path))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// Elements of an `Array` or packed array.
+struct VariantSeq {
+    elements: std::vec::IntoIter<Variant>,
+    path: String,
+    index: usize,
+}
+
+impl VariantSeq {
+    fn new(elements: Vec<Variant>, path: String) -> Self {
+        Self {
+            elements: elements.into_iter(),
+            path,
+            index: 0,
+        }
+    }
+}
+
+impl<'de> SeqAccess<'de> for VariantSeq {
+    type Error = SettingsError;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        let Some(value) = self.elements.next() else {
+            return Ok(None);
+        };
+
+        let path = format!("{}[{}]", self.path, self.index);
+        self.index += 1;
+
+        seed.deserialize(VariantDeserializer { value, path })
+            .map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.elements.len())
+    }
+}
+
+/// Entries of a `Dictionary`, or the components of a built-in struct such as `Vector2`.
+struct VariantMap {
+    entries: std::vec::IntoIter<(Variant, Variant)>,
+    path: String,
+    current: Option<(String, Variant)>,
+}
+
+impl VariantMap {
+    fn new(entries: Vec<(Variant, Variant)>, path: String) -> Self {
+        Self {
+            entries: entries.into_iter(),
+            path,
+            current: None,
+        }
+    }
+}
+
+impl<'de> MapAccess<'de> for VariantMap {
+    type Error = SettingsError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        let Some((key, value)) = self.entries.next() else {
+            return Ok(None);
+        };
+
+        let path = join_path(&self.path, &key.to_string());
+        let key = seed.deserialize(VariantDeserializer {
+            value: key,
+            path: path.clone(),
+        })?;
+        self.current = Some((path, value));
+
+        Ok(Some(key))
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let (path, value) = self
+            .current
+            .take()
+            .expect("next_value_seed() called before next_key_seed()");
+
+        seed.deserialize(VariantDeserializer { value, path })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Serialization
+
+/// Serializes `value` into `(path, value)` entries; nested structs and maps are flattened into paths below `prefix`.
+fn to_entries<T>(value: &T, prefix: &str) -> Result<Vec<(String, Variant)>, SettingsError>
+where
+    T: Serialize + ?Sized,
+{
+    let mut entries = vec![];
+    value.serialize(EntrySerializer {
+        entries: &mut entries,
+        path: prefix.to_string(),
+    })?;
+
+    Ok(entries)
+}
+
+/// Serializes structs and maps as paths, and everything else as single value at the current path.
+struct EntrySerializer<'a> {
+    entries: &'a mut Vec<(String, Variant)>,
+    path: String,
+}
+
+impl<'a> EntrySerializer<'a> {
+    fn push_value<T>(self, value: &T) -> Result<(), SettingsError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let value = value.serialize(ValueSerializer::new(&self.path))?;
+        push_entry(self.entries, self.path, value)
+    }
+
+    fn push_compound<S>(self, serializer: S) -> PushValue<'a, S> {
+        PushValue {
+            entries: self.entries,
+            path: self.path,
+            serializer,
+        }
+    }
+}
+
+macro_rules! serialize_as_value {
+    ($($method:ident: $T:ty),* $(,)?) => {$(
+        fn $method(self, v: $T) -> Result<Self::Ok, Self::Error> {
+            self.push_value(&v)
+        }
+    )*};
+}
+
+impl<'a> ser::Serializer for EntrySerializer<'a> {
+    type Ok = ();
+    type Error = SettingsError;
+
+    type SerializeSeq = PushValue<'a, ValueSeq>;
+    type SerializeTuple = PushValue<'a, ValueSeq>;
+    type SerializeTupleStruct = PushValue<'a, ValueSeq>;
+    type SerializeTupleVariant = Impossible<(), SettingsError>;
+    type SerializeMap = EntryMap<'a>;
+    type SerializeStruct = EntryStruct<'a>;
+    type SerializeStructVariant = Impossible<(), SettingsError>;
+
+    serialize_as_value! {
+        serialize_bool: bool,
+        serialize_i8: i8,
+        serialize_i16: i16,
+        serialize_i32: i32,
+        serialize_i64: i64,
+        serialize_u8: u8,
+        serialize_u16: u16,
+        serialize_u32: u32,
+        serialize_u64: u64,
+        serialize_f32: f32,
+        serialize_f64: f64,
+        serialize_char: char,
+        serialize_str: &str,
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        // Not via push_value(), which would serialize the slice as sequence.
+        let value = ValueSerializer::new(&self.path).serialize_bytes(v)?;
+        push_entry(self.entries, self.path, value)
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        // Absent values are not written, so that they are read back as None.
+        Ok(())
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.push_value(variant)
+    }
+
+    fn serialize_newtype_struct<T>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        Err(unsupported_variant().at_key(&self.path))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        let seq = ValueSerializer::new(&self.path).serialize_seq(len)?;
+        Ok(self.push_compound(seq))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(unsupported_variant().at_key(&self.path))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(EntryMap {
+            entries: self.entries,
+            path: self.path,
+            key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        if is_builtin_struct(name) && !self.path.is_empty() {
+            let value = ValueSerializer::new(&self.path).serialize_struct(name, len)?;
+            return Ok(EntryStruct::Value(self.push_compound(value)));
+        }
+
+        Ok(EntryStruct::Nested {
+            entries: self.entries,
+            path: self.path,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(unsupported_variant().at_key(&self.path))
+    }
+}
+
+/// Serializes a compound value with a [`ValueSerializer`], and stores the result as single entry.
+struct PushValue<'a, S> {
+    entries: &'a mut Vec<(String, Variant)>,
+    path: String,
+    serializer: S,
+}
+
+impl ser::SerializeSeq for PushValue<'_, ValueSeq> {
+    type Ok = ();
+    type Error = SettingsError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.serializer.serialize_element(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        let value = ser::SerializeSeq::end(self.serializer)?;
+        push_entry(self.entries, self.path, value)
+    }
+}
+
+impl ser::SerializeTuple for PushValue<'_, ValueSeq> {
+    type Ok = ();
+    type Error = SettingsError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for PushValue<'_, ValueSeq> {
+    type Ok = ();
+    type Error = SettingsError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+/// Struct whose fields become entries below the struct's path, or a built-in struct stored as single value.
+enum EntryStruct<'a> {
+    Nested {
+        entries: &'a mut Vec<(String, Variant)>,
+        path: String,
+    },
+    Value(PushValue<'a, ValueStruct>),
+}
+
+impl ser::SerializeStruct for EntryStruct<'_> {
+    type Ok = ();
+    type Error = SettingsError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        match self {
+            EntryStruct::Nested { entries, path } => value.serialize(EntrySerializer {
+                entries: &mut **entries,
+                path: join_path(path, key),
+            }),
+            EntryStruct::Value(push) => {
+                ser::SerializeStruct::serialize_field(&mut push.serializer, key, value)
+            }
+        }
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        match self {
+            EntryStruct::Nested { .. } => Ok(()),
+            EntryStruct::Value(push) => {
+                let value = ser::SerializeStruct::end(push.serializer)?;
+                push_entry(push.entries, push.path, value)
+            }
+        }
+    }
+}
+
+/// Map whose entries become entries below the map's path. Keys must be strings.
+struct EntryMap<'a> {
+    entries: &'a mut Vec<(String, Variant)>,
+    path: String,
+    key: Option<String>,
+}
+
+impl ser::SerializeMap for EntryMap<'_> {
+    type Ok = ();
+    type Error = SettingsError;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let key = key.serialize(ValueSerializer::new(&self.path))?;
+        if key.get_type() != VariantType::STRING {
+            return Err(SettingsError::new("map keys must be strings").at_key(&self.path));
+        }
+
+        self.key = Some(key.to_string());
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let key = self
+            .key
+            .take()
+            .expect("serialize_value() called before serialize_key()");
+
+        value.serialize(EntrySerializer {
+            entries: &mut *self.entries,
+            path: join_path(&self.path, &key),
+        })
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
+
+/// Serializes a value into a single `Variant`. Sequences become `Array`, maps and structs become `Dictionary`.
+struct ValueSerializer {
+    /// Key for error messages.
+    path: String,
+}
+
+impl ValueSerializer {
+    fn new(path: &str) -> Self {
+        Self {
+            path: path.to_string(),
+        }
+    }
+}
+
+macro_rules! serialize_to_variant {
+    ($($method:ident: $T:ty),* $(,)?) => {$(
+        fn $method(self, v: $T) -> Result<Self::Ok, Self::Error> {
+            Ok(v.to_variant())
+        }
+    )*};
+}
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = Variant;
+    type Error = SettingsError;
+
+    type SerializeSeq = ValueSeq;
+    type SerializeTuple = ValueSeq;
+    type SerializeTupleStruct = ValueSeq;
+    type SerializeTupleVariant = Impossible<Variant, SettingsError>;
+    type SerializeMap = ValueMap;
+    type SerializeStruct = ValueStruct;
+    type SerializeStructVariant = Impossible<Variant, SettingsError>;
+
+    serialize_to_variant! {
+        serialize_bool: bool,
+        serialize_i8: i8,
+        serialize_i16: i16,
+        serialize_i32: i32,
+        serialize_i64: i64,
+        serialize_u8: u8,
+        serialize_u16: u16,
+        serialize_u32: u32,
+        serialize_f32: f32,
+        serialize_f64: f64,
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        match i64::try_from(v) {
+            Ok(v) => Ok(v.to_variant()),
+            Err(_) => Err(
+                SettingsError::new(format!("integer {v} does not fit into i64")).at_key(&self.path),
+            ),
+        }
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        Ok(GString::from(v.to_string()).to_variant())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        Ok(GString::from(v).to_variant())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Ok(PackedByteArray::from(v).to_variant())
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Variant::nil())
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Variant::nil())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Ok(Variant::nil())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        Err(unsupported_variant().at_key(&self.path))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(ValueSeq {
+            array: VariantArray::new(),
+            path: self.path,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(unsupported_variant().at_key(&self.path))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(ValueMap {
+            dict: Dictionary::new(),
+            path: self.path,
+            key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(ValueStruct {
+            name,
+            fields: Vec::with_capacity(len),
+            path: self.path,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(unsupported_variant().at_key(&self.path))
+    }
+}
+
+struct ValueSeq {
+    array: VariantArray,
+    path: String,
+}
+
+impl ser::SerializeSeq for ValueSeq {
+    type Ok = Variant;
+    type Error = SettingsError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let path = format!("{}[{}]", self.path, self.array.len());
+        let element = value.serialize(ValueSerializer { path })?;
+        self.array.push(&element);
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.array.to_variant())
+    }
+}
+
+impl ser::SerializeTuple for ValueSeq {
+    type Ok = Variant;
+    type Error = SettingsError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for ValueSeq {
+    type Ok = Variant;
+    type Error = SettingsError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+struct ValueMap {
+    dict: Dictionary,
+    path: String,
+    key: Option<Variant>,
+}
+
+impl ser::SerializeMap for ValueMap {
+    type Ok = Variant;
+    type Error = SettingsError;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.key = Some(key.serialize(ValueSerializer::new(&self.path))?);
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let key = self
+            .key
+            .take()
+            .expect("serialize_value() called before serialize_key()");
+
+        let path = join_path(&self.path, &key.to_string());
+        let value = value.serialize(ValueSerializer { path })?;
+        self.dict.set(key, value);
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.dict.to_variant())
+    }
+}
+
+struct ValueStruct {
+    name: &'static str,
+    fields: Vec<(&'static str, Variant)>,
+    path: String,
+}
+
+impl ser::SerializeStruct for ValueStruct {
+    type Ok = Variant;
+    type Error = SettingsError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let path = join_path(&self.path, key);
+        let value = value.serialize(ValueSerializer { path })?;
+        self.fields.push((key, value));
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        if let Some(value) = builtin_from_fields(self.name, &self.fields) {
+            return Ok(value);
+        }
+
+        let mut dict = Dictionary::new();
+        for (key, value) in self.fields {
+            dict.set(key, value);
+        }
+
+        Ok(dict.to_variant())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Helpers
+
+fn push_entry(
+    entries: &mut Vec<(String, Variant)>,
+    path: String,
+    value: Variant,
+) -> Result<(), SettingsError> {
+    if path.is_empty() {
+        return Err(SettingsError::new(
+            "top-level value must be a struct or map",
+        ));
+    }
+
+    entries.push((path, value));
+    Ok(())
+}
+
+fn join_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{path}/{name}")
+    }
+}
+
+/// Prefix shared by all entries below `path`.
+fn child_prefix(path: &str) -> String {
+    if path.is_empty() {
+        String::new()
+    } else {
+        format!("{path}/")
+    }
+}
+
+fn unsupported_variant() -> SettingsError {
+    SettingsError::new("only enums with unit variants are supported")
+}
+
+fn is_builtin_struct(name: &str) -> bool {
+    matches!(
+        name,
+        "Vector2" | "Vector2i" | "Vector3" | "Vector3i" | "Vector4" | "Vector4i" | "Color"
+    )
+}
+
+/// Components of built-in structs, named like the fields of their `Serialize` impls.
+fn builtin_fields(value: &Variant) -> Option<Vec<(&'static str, Variant)>> {
+    let fields = match value.get_type() {
+        VariantType::VECTOR2 => {
+            let v = value.to::<Vector2>();
+            vec![("x", v.x.to_variant()), ("y", v.y.to_variant())]
+        }
+        VariantType::VECTOR2I => {
+            let v = value.to::<Vector2i>();
+            vec![("x", v.x.to_variant()), ("y", v.y.to_variant())]
+        }
+        VariantType::VECTOR3 => {
+            let v = value.to::<Vector3>();
+            vec![
+                ("x", v.x.to_variant()),
+                ("y", v.y.to_variant()),
+                ("z", v.z.to_variant()),
+            ]
+        }
+        VariantType::VECTOR3I => {
+            let v = value.to::<Vector3i>();
+            vec![
+                ("x", v.x.to_variant()),
+                ("y", v.y.to_variant()),
+                ("z", v.z.to_variant()),
+            ]
+        }
+        VariantType::VECTOR4 => {
+            let v = value.to::<Vector4>();
+            vec![
+                ("x", v.x.to_variant()),
+                ("y", v.y.to_variant()),
+                ("z", v.z.to_variant()),
+                ("w", v.w.to_variant()),
+            ]
+        }
+        VariantType::VECTOR4I => {
+            let v = value.to::<Vector4i>();
+            vec![
+                ("x", v.x.to_variant()),
+                ("y", v.y.to_variant()),
+                ("z", v.z.to_variant()),
+                ("w", v.w.to_variant()),
+            ]
+        }
+        VariantType::COLOR => {
+            let c = value.to::<Color>();
+            vec![
+                ("r", c.r.to_variant()),
+                ("g", c.g.to_variant()),
+                ("b", c.b.to_variant()),
+                ("a", c.a.to_variant()),
+            ]
+        }
+        _ => return None,
+    };
+
+    Some(fields)
+}
+
+/// Inverse of [`builtin_fields()`]: builds the `Variant` of a built-in struct from its serialized fields.
+fn builtin_from_fields(name: &str, fields: &[(&'static str, Variant)]) -> Option<Variant> {
+    let float = |i: usize| fields.get(i)?.1.try_to::<f64>().ok();
+    let component = |i: usize| float(i).map(|v| v as real);
+    let int = |i: usize| fields.get(i)?.1.try_to::<i32>().ok();
+
+    let value = match name {
+        "Vector2" => Vector2::new(component(0)?, component(1)?).to_variant(),
+        "Vector2i" => Vector2i::new(int(0)?, int(1)?).to_variant(),
+        "Vector3" => Vector3::new(component(0)?, component(1)?, component(2)?).to_variant(),
+        "Vector3i" => Vector3i::new(int(0)?, int(1)?, int(2)?).to_variant(),
+        "Vector4" => {
+            Vector4::new(component(0)?, component(1)?, component(2)?, component(3)?).to_variant()
+        }
+        "Vector4i" => Vector4i::new(int(0)?, int(1)?, int(2)?, int(3)?).to_variant(),
+        "Color" => {
+            let [r, g, b, a] = [float(0)?, float(1)?, float(2)?, float(3)?].map(|v| v as f32);
+            Color::from_rgba(r, g, b, a).to_variant()
+        }
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+/// Elements of packed arrays, as `Variant`s.
+fn packed_elements(value: &Variant) -> Option<Vec<Variant>> {
+    fn to_variants<T: ToGodot>(elements: &[T]) -> Vec<Variant> {
+        elements.iter().map(ToGodot::to_variant).collect()
+    }
+
+    let elements = match value.get_type() {
+        VariantType::PACKED_BYTE_ARRAY => to_variants(value.to::<PackedByteArray>().as_slice()),
+        VariantType::PACKED_INT32_ARRAY => to_variants(value.to::<PackedInt32Array>().as_slice()),
+        VariantType::PACKED_INT64_ARRAY => to_variants(value.to::<PackedInt64Array>().as_slice()),
+        VariantType::PACKED_FLOAT32_ARRAY => {
+            to_variants(value.to::<PackedFloat32Array>().as_slice())
+        }
+        VariantType::PACKED_FLOAT64_ARRAY => {
+            to_variants(value.to::<PackedFloat64Array>().as_slice())
+        }
+        VariantType::PACKED_STRING_ARRAY => to_variants(value.to::<PackedStringArray>().as_slice()),
+        _ => return None,
+    };
+
+    Some(elements)
+}
@@ -34,6 +34,10 @@ pub use crate::{tr, tr_n};
 /// ```
 /// The methods are called from the [`Engine`](crate::classes::Engine) singleton.
 ///
+/// To collect all messages into a `.pot` template for translators, use the
+/// [`godot-translation-template`](https://docs.rs/godot-translation-template) crate in a build script. Since placeholders are currently
+/// substituted before the lookup, only messages without placeholders are extracted.
+///
 /// See also: [Translation contexts](https://docs.godotengine.org/en/stable/tutorials/i18n/internationalizing_games.html#translation-contexts)
 /// in Godot.
 #[macro_export]
@@ -82,6 +86,10 @@ macro_rules! tr {
 /// ```
 /// The methods are called from the [`Engine`](crate::classes::Engine) singleton.
 ///
+/// To collect all messages into a `.pot` template for translators, use the
+/// [`godot-translation-template`](https://docs.rs/godot-translation-template) crate in a build script. Since placeholders are currently
+/// substituted before the lookup, only messages without placeholders are extracted.
+///
 /// See also: [Translation contexts](https://docs.godotengine.org/en/stable/tutorials/i18n/internationalizing_games.html#translation-contexts)
 /// in Godot.
 #[macro_export]
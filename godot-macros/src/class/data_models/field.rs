@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::class::{FieldExport, FieldVar};
+use crate::class::{FieldExport, FieldNotify, FieldVar};
 use crate::util::{error, KvParser};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, ToTokens};
@@ -18,6 +18,8 @@ pub struct Field {
     pub export: Option<FieldExport>,
     pub is_onready: bool,
     pub is_oneditor: bool,
+    /// Whether the generated setter asks the editor to refresh configuration warnings (`OnEditor` exports in tool classes).
+    pub updates_configuration_warnings: bool,
     #[cfg(feature = "register-docs")]
     pub attributes: Vec<venial::Attribute>,
     pub span: Span,
@@ -33,12 +35,24 @@ impl Field {
             export: None,
             is_onready: false,
             is_oneditor: false,
+            updates_configuration_warnings: false,
             #[cfg(feature = "register-docs")]
             attributes: field.attributes.clone(),
             span: field.span(),
         }
     }
 
+    /// Change notification requested by `#[var]` or `#[export]`, if any.
+    pub fn notify(&self) -> Option<&FieldNotify> {
+        let var_notify = self.var.as_ref().map(|var| &var.notify);
+        let export_notify = self.export.as_ref().map(|export| &export.notify);
+
+        var_notify
+            .into_iter()
+            .chain(export_notify)
+            .find(|notify| !notify.is_empty())
+    }
+
     /// For a previously performed check, either pastes the generated code, or a syntactically valid fallback.
     ///
     /// In case of incorrect proc-macro usage, it's nice if the resulting generated code is still syntactically valid, to not trip over
@@ -78,7 +92,7 @@ impl Field {
         if self.default_val.is_some() {
             errors.push(error!(
                 span,
-                "#[init] can have at most one key among `val|node|load`"
+                "#[init] can have at most one key among `val|node|load|scene_nodes`"
             ));
         }
 
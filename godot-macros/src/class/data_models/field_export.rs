@@ -9,19 +9,29 @@ use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use std::collections::{HashMap, HashSet};
 
+use crate::class::{parse_flatten, FieldNotify};
 use crate::util::{KvParser, ListParser};
 use crate::ParseResult;
 
 pub struct FieldExport {
     pub export_type: ExportType,
+    pub flatten: bool,
+    pub notify: FieldNotify,
     pub span: Span,
 }
 
 impl FieldExport {
     pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
         let span = parser.span();
+        let flatten = parse_flatten(parser, "export")?;
+        let notify = FieldNotify::new_from_kv(parser)?;
         let export_type = ExportType::new_from_kv(parser)?;
-        Ok(Self { export_type, span })
+        Ok(Self {
+            export_type,
+            flatten,
+            notify,
+            span,
+        })
     }
 
     pub fn to_export_hint(&self) -> Option<TokenStream> {
@@ -12,8 +12,8 @@ use crate::class::{
     into_signature_info, make_existence_check, make_method_registration, Field, FieldHint,
     FuncDefinition,
 };
-use crate::util::make_funcs_collection_constant;
 use crate::util::KvParser;
+use crate::util::{bail, make_funcs_collection_constant, require_api_version};
 use crate::{util, ParseResult};
 
 /// Store info from `#[var]` attribute.
@@ -23,6 +23,8 @@ pub struct FieldVar {
     pub setter: GetterSetter,
     pub hint: FieldHint,
     pub usage_flags: UsageFlags,
+    pub flatten: bool,
+    pub notify: FieldNotify,
     pub span: Span,
 }
 
@@ -35,8 +37,12 @@ impl FieldVar {
     /// - `hint = ident`
     /// - `hint_string = expr`
     /// - `usage_flags =
+    /// - `flatten`
+    /// - `notify`, `notify = ident`, `notify_signal = ident`
     pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
         let span = parser.span();
+        let flatten = parse_flatten(parser, "var")?;
+        let notify = FieldNotify::new_from_kv(parser)?;
         let mut getter = GetterSetter::parse(parser, "get")?;
         let mut setter = GetterSetter::parse(parser, "set")?;
 
@@ -45,6 +51,15 @@ impl FieldVar {
             setter = GetterSetter::Generated;
         }
 
+        if let Some(notify_span) = notify.span() {
+            if setter != GetterSetter::Generated {
+                return bail!(
+                    notify_span,
+                    "#[var] change notification requires a generated setter; with a custom or omitted setter, notify from your own code",
+                );
+            }
+        }
+
         let hint = parser.handle_ident("hint")?;
 
         let hint = if let Some(hint) = hint {
@@ -74,11 +89,82 @@ impl FieldVar {
             setter,
             hint,
             usage_flags,
+            flatten,
+            notify,
             span,
         })
     }
 }
 
+/// Parses the `flatten` key of `#[var]` or `#[export]`, which must be the only key.
+pub(crate) fn parse_flatten(parser: &mut KvParser, attr: &str) -> ParseResult<bool> {
+    let Some(flatten) = parser.handle_alone_with_span("flatten")? else {
+        return Ok(false);
+    };
+
+    require_api_version!("4.2", &flatten, "#[var(flatten)] and #[export(flatten)]")?;
+
+    if !parser.is_empty() {
+        return bail!(
+            &flatten,
+            "#[{attr}(flatten)] cannot be combined with other keys; annotate the fields of the flattened struct instead",
+        );
+    }
+
+    Ok(true)
+}
+
+/// Store info from the `notify` and `notify_signal` keys of `#[var]` and `#[export]`.
+#[derive(Default, Clone, Debug)]
+pub struct FieldNotify {
+    /// `notify`: emit the per-property signal `{field}_changed(value)`. Holds the key, for spans.
+    pub property_signal: Option<Ident>,
+
+    /// `notify = hook`: call the method `self.hook()`.
+    pub hook: Option<Ident>,
+
+    /// `notify_signal = signal`: emit the class-wide signal `signal(property: StringName)`.
+    pub class_signal: Option<Ident>,
+}
+
+impl FieldNotify {
+    pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
+        let mut notify = Self::default();
+
+        match parser.handle_any_entry("notify") {
+            None => {}
+            Some((key, None)) => notify.property_signal = Some(key),
+            Some((_key, Some(value))) => notify.hook = Some(value.ident()?),
+        }
+
+        notify.class_signal = parser.handle_ident("notify_signal")?;
+
+        // Signals are emitted through the typed signal API.
+        if let Some(signal) = notify
+            .property_signal
+            .as_ref()
+            .or(notify.class_signal.as_ref())
+        {
+            require_api_version!("4.2", signal, "Signal-based property change notification")?;
+        }
+
+        Ok(notify)
+    }
+
+    /// Span of the first notify key, or `None` if no change notification was requested.
+    pub fn span(&self) -> Option<Span> {
+        [&self.property_signal, &self.hook, &self.class_signal]
+            .into_iter()
+            .flatten()
+            .map(|ident| ident.span())
+            .next()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.span().is_none()
+    }
+}
+
 impl Default for FieldVar {
     fn default() -> Self {
         Self {
@@ -86,6 +172,8 @@ impl Default for FieldVar {
             setter: Default::default(),
             hint: Default::default(),
             usage_flags: Default::default(),
+            flatten: false,
+            notify: Default::default(),
             span: Span::call_site(),
         }
     }
@@ -196,12 +284,68 @@ impl GetterSetterImpl {
                 signature = quote! {
                     fn #function_name(&mut self, #field_name: <#field_type as ::godot::meta::GodotConvert>::Via)
                 };
+                let assign = match field.notify() {
+                    Some(notify) => make_notifying_setter_body(field, notify),
+                    None => quote! {
+                        <#field_type as ::godot::register::property::Var>::set_property(&mut self.#field_name, #field_name);
+                    },
+                };
+
+                let update_warnings = field.updates_configuration_warnings.then(|| {
+                    quote! { ::godot::private::update_configuration_warnings(self); }
+                });
+
                 function_body = quote! {
-                    <#field_type as ::godot::register::property::Var>::set_property(&mut self.#field_name, #field_name);
+                    #assign
+                    #update_warnings
                 };
             }
         }
 
+        Self::from_signature(class_name, function_name, signature, function_body)
+    }
+
+    /// Indexed getter/setter serving all properties of a `#[var(flatten)]` field.
+    pub(super) fn from_flattened_impl(class_name: &Ident, kind: GetSet, field: &Field) -> Self {
+        let Field {
+            name: field_name,
+            ty: field_type,
+            ..
+        } = field;
+
+        let function_name = format_ident!("{}{field_name}_indexed", kind.prefix());
+
+        let signature;
+        let function_body;
+
+        match kind {
+            GetSet::Get => {
+                signature = quote! {
+                    fn #function_name(&self, index: i64) -> ::godot::builtin::Variant
+                };
+                function_body = quote! {
+                    <#field_type as ::godot::register::property::PropertyGroup>::__get_group_property(&self.#field_name, index as usize)
+                };
+            }
+            GetSet::Set => {
+                signature = quote! {
+                    fn #function_name(&mut self, index: i64, value: ::godot::builtin::Variant)
+                };
+                function_body = quote! {
+                    <#field_type as ::godot::register::property::PropertyGroup>::__set_group_property(&mut self.#field_name, index as usize, &value);
+                };
+            }
+        }
+
+        Self::from_signature(class_name, function_name, signature, function_body)
+    }
+
+    fn from_signature(
+        class_name: &Ident,
+        function_name: Ident,
+        signature: TokenStream,
+        function_body: TokenStream,
+    ) -> Self {
         let function_impl = quote! {
             pub #signature {
                 #function_body
@@ -225,6 +369,9 @@ impl GetterSetterImpl {
                 registered_name: None,
                 is_script_virtual: false,
                 rpc_info: None,
+                default_params: vec![],
+                has_varargs: false,
+                is_async: false,
             },
             None,
         );
@@ -249,6 +396,48 @@ impl GetterSetterImpl {
     }
 }
 
+/// Setter that only assigns and notifies if the new value differs from the old one.
+fn make_notifying_setter_body(field: &Field, notify: &FieldNotify) -> TokenStream {
+    let Field {
+        name: field_name,
+        ty: field_type,
+        ..
+    } = field;
+
+    let hook = notify.hook.as_ref().map(|hook| {
+        quote! { self.#hook(); }
+    });
+
+    let property_signal = notify.property_signal.as_ref().map(|key| {
+        let signal_name = format_ident!("{field_name}_changed", span = key.span());
+        quote! {
+            let value = <#field_type as ::godot::register::property::Var>::get_property(&self.#field_name);
+            <Self as ::godot::obj::WithUserSignals>::signals(self).#signal_name().emit(value);
+        }
+    });
+
+    let class_signal = notify.class_signal.as_ref().map(|signal_name| {
+        let field_name_str = field_name.to_string();
+        quote! {
+            <Self as ::godot::obj::WithUserSignals>::signals(self)
+                .#signal_name()
+                .emit(::godot::builtin::StringName::from(#field_name_str));
+        }
+    });
+
+    quote! {
+        if <#field_type as ::godot::register::property::Var>::get_property(&self.#field_name) == #field_name {
+            return;
+        }
+
+        <#field_type as ::godot::register::property::Var>::set_property(&mut self.#field_name, #field_name);
+
+        #hook
+        #property_signal
+        #class_signal
+    }
+}
+
 #[derive(Default, Clone, Debug)]
 pub enum UsageFlags {
     /// The usage flags should be inferred based on context.
@@ -29,6 +29,15 @@ pub struct FuncDefinition {
 
     /// Information about the RPC configuration, if provided.
     pub rpc_info: Option<RpcAttr>,
+
+    /// Default values of the last `default_params.len()` parameters, declared with `#[opt(default = ...)]`.
+    pub default_params: Vec<TokenStream>,
+
+    /// True if the function is variadic, i.e. its last parameter is `&[Variant]` (not part of [`signature_info`]).
+    pub has_varargs: bool,
+
+    /// True for `async fn`. The registered function spawns the future and returns a `Signal`, emitted on completion.
+    pub is_async: bool,
 }
 
 impl FuncDefinition {
@@ -56,8 +65,14 @@ pub fn make_virtual_callback(
 ) -> TokenStream {
     let method_name = &signature_info.method_name;
 
-    let wrapped_method =
-        make_forwarding_closure(class_name, signature_info, before_kind, interface_trait);
+    let wrapped_method = make_forwarding_closure(
+        class_name,
+        signature_info,
+        before_kind,
+        interface_trait,
+        false,
+        false,
+    );
     let sig_tuple = signature_info.tuple_type();
 
     let call_ctx = make_call_context(
@@ -97,16 +112,20 @@ pub fn make_method_registration(
     let sig_tuple = signature_info.tuple_type();
 
     let is_script_virtual = func_definition.is_script_virtual;
-    let method_flags = match make_method_flags(signature_info.receiver_type, is_script_virtual) {
-        Ok(mf) => mf,
-        Err(msg) => return bail_fn(msg, &signature_info.method_name),
-    };
+    let has_varargs = func_definition.has_varargs;
+    let method_flags =
+        match make_method_flags(signature_info.receiver_type, is_script_virtual, has_varargs) {
+            Ok(mf) => mf,
+            Err(msg) => return bail_fn(msg, &signature_info.method_name),
+        };
 
     let forwarding_closure = make_forwarding_closure(
         class_name,
         signature_info,
         BeforeKind::Without,
         interface_trait,
+        has_varargs,
+        func_definition.is_async,
     );
 
     // String literals
@@ -114,8 +133,24 @@ pub fn make_method_registration(
     let method_name_str = func_definition.godot_name();
 
     let call_ctx = make_call_context(&class_name_str, &method_name_str);
-    let varcall_fn_decl = make_varcall_fn(&call_ctx, &forwarding_closure);
-    let ptrcall_fn_decl = make_ptrcall_fn(&call_ctx, &forwarding_closure);
+    let default_args_fn_decl =
+        make_default_args_fn(signature_info, &func_definition.default_params);
+    let varcall_fn_decl = make_varcall_fn(
+        &call_ctx,
+        &forwarding_closure,
+        !func_definition.default_params.is_empty(),
+        has_varargs,
+    );
+
+    // Variadic functions can only be called through varcall.
+    let (ptrcall_fn_decl, ptrcall_fn) = if has_varargs {
+        (TokenStream::new(), quote! { None })
+    } else {
+        (
+            make_ptrcall_fn(&call_ctx, &forwarding_closure),
+            quote! { Some(ptrcall_fn) },
+        )
+    };
 
     // String literals II
     let param_ident_strs = signature_info
@@ -141,6 +176,7 @@ pub fn make_method_registration(
 
             let method_name = StringName::from(#method_name_str);
 
+            #default_args_fn_decl;
             #varcall_fn_decl;
             #ptrcall_fn_decl;
 
@@ -149,11 +185,12 @@ pub fn make_method_registration(
                 ClassMethodInfo::from_signature::<#class_name, Sig>(
                     method_name,
                     Some(varcall_fn),
-                    Some(ptrcall_fn),
+                    #ptrcall_fn,
                     #method_flags,
                     &[
                         #( #param_ident_strs ),*
                     ],
+                    default_args(),
                 )
             };
 
@@ -209,6 +246,18 @@ impl SignatureInfo {
         }
     }
 
+    /// Generated `get_configuration_warnings()` for `#[class(tool)]` classes, forwarding to `UserClass::__configuration_warnings()`.
+    pub fn fn_configuration_warnings() -> Self {
+        Self {
+            method_name: ident("__configuration_warnings"),
+            receiver_type: ReceiverType::Ref,
+            param_idents: vec![],
+            param_types: vec![],
+            return_type: quote! { ::godot::builtin::PackedStringArray },
+            modified_param_types: vec![],
+        }
+    }
+
     // The below functions share quite a bit of tokenization. If ever we run into codegen slowness, we could cache/reuse identical
     // sub-expressions.
 
@@ -226,7 +275,7 @@ pub enum BeforeKind {
     /// Call `before_{method}` before calling the method itself.
     WithBefore,
 
-    /// Call **only** `before_{method}`, not the method itself.
+    /// Call **only** `before_{method}`, not the method itself. Rust base classes may still handle the method.
     OnlyBefore,
 }
 
@@ -236,10 +285,19 @@ fn make_forwarding_closure(
     signature_info: &SignatureInfo,
     before_kind: BeforeKind,
     interface_trait: Option<&venial::TypeExpr>,
+    has_varargs: bool,
+    is_async: bool,
 ) -> TokenStream {
     let method_name = &signature_info.method_name;
     let params = &signature_info.param_idents;
 
+    // Variadic functions receive the extra arguments as an additional closure parameter, forwarded as the last argument.
+    let (varargs_param, varargs_arg) = if has_varargs {
+        (quote! { , __varargs }, vec![quote! { __varargs }])
+    } else {
+        (TokenStream::new(), vec![])
+    };
+
     let instance_decl = match &signature_info.receiver_type {
         ReceiverType::Ref => quote! {
             let instance = ::godot::private::Storage::get(storage);
@@ -251,9 +309,26 @@ fn make_forwarding_closure(
     };
 
     let before_method_call = match before_kind {
-        BeforeKind::WithBefore | BeforeKind::OnlyBefore => {
+        BeforeKind::WithBefore => {
+            let before_method = format_ident!("__before_{}", method_name);
+
+            // Rust base classes (if any) are prepared first, as their own ready() is replaced by this one.
+            quote! {
+                unsafe {
+                    <<#class_name as ::godot::obj::GodotClass>::Base as ::godot::obj::GodotClass>::__godot_before_ready_chain(instance_ptr)
+                };
+                instance.#before_method();
+            }
+        }
+        BeforeKind::OnlyBefore => {
             let before_method = format_ident!("__before_{}", method_name);
-            quote! { instance.#before_method(); }
+
+            // No user implementation on this level: release the instance, then let Rust base classes (if any) run their ready().
+            quote! {
+                instance.#before_method();
+                ::std::mem::drop(instance);
+                unsafe { ::godot::private::ready_inherited::<#class_name>(instance_ptr) };
+            }
         }
         BeforeKind::Without => TokenStream::new(),
     };
@@ -274,16 +349,16 @@ fn make_forwarding_closure(
                             _ => unreachable!("unexpected receiver type"), // checked above.
                         };
 
-                        quote! { <#class_name as #interface_trait>::#method_name( #instance_ref, #(#params),* ) }
+                        quote! { <#class_name as #interface_trait>::#method_name( #instance_ref, #(#params,)* #(#varargs_arg)* ) }
                     }
 
                     // impl Class {...}
-                    None => quote! { instance.#method_name( #(#params),* ) },
+                    None => quote! { instance.#method_name( #(#params,)* #(#varargs_arg)* ) },
                 }
             };
 
             quote! {
-                |instance_ptr, params| {
+                |instance_ptr, params #varargs_param| {
                     let ( #(#params,)* ) = params;
 
                     let storage =
@@ -295,27 +370,56 @@ fn make_forwarding_closure(
                 }
             }
         }
+        ReceiverType::GdSelf if is_async => {
+            // The future owns the Gd pointer; it is canceled once the object is freed, or only referenced by the future itself.
+            quote! {
+                |instance_ptr, params #varargs_param| {
+                    let ( #(#params,)* ) = params;
+
+                    let storage =
+                        unsafe { ::godot::private::as_storage::<#class_name>(instance_ptr) };
+
+                    let __this = ::godot::private::Storage::get_gd(storage);
+                    let __owner = __this.instance_id();
+                    ::godot::private::spawn_async_func(
+                        Some(__owner),
+                        #class_name::#method_name(__this, #(#params,)* #(#varargs_arg)*),
+                    )
+                }
+            }
+        }
         ReceiverType::GdSelf => {
             // Method call is always present, since GdSelf implies that the user declares the method.
             // (Absent method is only used in the case of a generated default virtual method, e.g. for ready()).
             quote! {
-                |instance_ptr, params| {
+                |instance_ptr, params #varargs_param| {
                     let ( #(#params,)* ) = params;
 
                     let storage =
                         unsafe { ::godot::private::as_storage::<#class_name>(instance_ptr) };
 
                     #before_method_call
-                    #class_name::#method_name(::godot::private::Storage::get_gd(storage), #(#params),*)
+                    #class_name::#method_name(::godot::private::Storage::get_gd(storage), #(#params,)* #(#varargs_arg)*)
+                }
+            }
+        }
+        ReceiverType::Static if is_async => {
+            quote! {
+                |_, params #varargs_param| {
+                    let ( #(#params,)* ) = params;
+                    ::godot::private::spawn_async_func(
+                        None,
+                        #class_name::#method_name(#(#params,)* #(#varargs_arg)*),
+                    )
                 }
             }
         }
         ReceiverType::Static => {
             // No before-call needed, since static methods are not virtual.
             quote! {
-                |_, params| {
+                |_, params #varargs_param| {
                     let ( #(#params,)* ) = params;
-                    #class_name::#method_name(#(#params),*)
+                    #class_name::#method_name(#(#params,)* #(#varargs_arg)*)
                 }
             }
         }
@@ -324,7 +428,7 @@ fn make_forwarding_closure(
 
 /// Maps each usage of `Self` to the struct it's referencing,
 /// since `Self` can't be used inside nested functions.
-fn map_self_to_class_name<In, Out>(tokens: In, class_name: &Ident) -> Out
+pub(crate) fn map_self_to_class_name<In, Out>(tokens: In, class_name: &Ident) -> Out
 where
     In: IntoIterator<Item = TokenTree>,
     Out: FromIterator<TokenTree>,
@@ -454,9 +558,10 @@ pub(crate) fn maybe_rename_parameter(param_ident: Ident, next_unnamed_index: &mu
     }
 }
 
-fn make_method_flags(
+pub(crate) fn make_method_flags(
     method_type: ReceiverType,
     is_script_virtual: bool,
+    has_varargs: bool,
 ) -> Result<TokenStream, String> {
     let flags = quote! { ::godot::global::MethodFlags };
 
@@ -478,18 +583,70 @@ fn make_method_flags(
         }
     };
 
-    let flags = if is_script_virtual {
+    let base_flags = if is_script_virtual {
         quote! { #base_flags | #flags::VIRTUAL }
     } else {
         base_flags
     };
 
+    let flags = if has_varargs {
+        quote! { #base_flags | #flags::VARARG }
+    } else {
+        base_flags
+    };
+
     Ok(flags)
 }
 
+/// Generate code for a function returning the default values of trailing parameters, as variants.
+pub(crate) fn make_default_args_fn(
+    signature_info: &SignatureInfo,
+    default_params: &[TokenStream],
+) -> TokenStream {
+    let first_default = signature_info.param_types.len() - default_params.len();
+    let default_types = &signature_info.param_types[first_default..];
+
+    // Type annotation ensures that each value matches its parameter type.
+    quote! {
+        fn default_args() -> Vec<Variant> {
+            vec![
+                #(
+                    {
+                        let value: #default_types = #default_params;
+                        ::godot::meta::ToGodot::to_variant(&value)
+                    }
+                ),*
+            ]
+        }
+    }
+}
+
 /// Generate code for a C FFI function that performs a varcall.
-fn make_varcall_fn(call_ctx: &TokenStream, wrapped_method: &TokenStream) -> TokenStream {
-    let invocation = make_varcall_invocation(wrapped_method);
+fn make_varcall_fn(
+    call_ctx: &TokenStream,
+    wrapped_method: &TokenStream,
+    has_default_args: bool,
+    has_varargs: bool,
+) -> TokenStream {
+    let invocation = make_varcall_invocation(wrapped_method, has_varargs);
+
+    // Godot passes varcall arguments as provided by the caller; omitted trailing arguments are filled in here.
+    let fill_default_args = if has_default_args {
+        quote! {
+            let filled_args = ::godot::private::fill_default_args(
+                args_ptr,
+                arg_count,
+                <Sig as ::godot::meta::VarcallSignatureTuple>::PARAM_COUNT,
+                default_args,
+            );
+            let (args_ptr, arg_count) = match &filled_args {
+                Some(filled_args) => (filled_args.args_ptr(), filled_args.arg_count()),
+                None => (args_ptr, arg_count),
+            };
+        }
+    } else {
+        TokenStream::new()
+    };
 
     // TODO reduce amount of code generated, by delegating work to a library function. Could even be one that produces this function pointer.
     quote! {
@@ -505,7 +662,11 @@ fn make_varcall_fn(call_ctx: &TokenStream, wrapped_method: &TokenStream) -> Toke
             ::godot::private::handle_varcall_panic(
                 &call_ctx,
                 &mut *err,
-                || #invocation
+                // Default values are user expressions, which may panic as well.
+                || {
+                    #fill_default_args
+                    #invocation
+                }
             );
         }
     }
@@ -556,9 +717,15 @@ fn make_ptrcall_invocation(wrapped_method: &TokenStream, is_virtual: bool) -> To
 }
 
 /// Generate code for a `varcall()` call expression.
-fn make_varcall_invocation(wrapped_method: &TokenStream) -> TokenStream {
+fn make_varcall_invocation(wrapped_method: &TokenStream, has_varargs: bool) -> TokenStream {
+    let in_varcall = if has_varargs {
+        quote! { in_varcall_varargs }
+    } else {
+        quote! { in_varcall }
+    };
+
     quote! {
-        <Sig as ::godot::meta::VarcallSignatureTuple>::in_varcall(
+        <Sig as ::godot::meta::VarcallSignatureTuple>::#in_varcall(
             instance_ptr,
             &call_ctx,
             args_ptr,
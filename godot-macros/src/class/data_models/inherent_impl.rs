@@ -7,8 +7,8 @@
 
 use crate::class::{
     into_signature_info, make_constant_registration, make_method_registration,
-    make_signal_registrations, ConstDefinition, FuncDefinition, RpcAttr, RpcMode, SignalDefinition,
-    SignatureInfo, TransferMode,
+    make_script_instance_api, make_signal_registrations, ConstDefinition, FuncDefinition,
+    ReceiverType, RpcAttr, RpcMode, SignalDefinition, SignatureInfo, TransferMode,
 };
 use crate::util::{
     bail, c_str, format_funcs_collection_struct, ident, make_funcs_collection_constants,
@@ -16,9 +16,9 @@ use crate::util::{
 };
 use crate::{handle_mutually_exclusive_keys, util, ParseResult};
 
-use proc_macro2::{Delimiter, Group, Ident, TokenStream};
+use proc_macro2::{Delimiter, Group, Ident, TokenStream, TokenTree};
 use quote::spanned::Spanned;
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, quote_spanned};
 
 /// Attribute for user-declared function.
 enum ItemAttrType {
@@ -77,6 +77,9 @@ pub struct InherentImplAttr {
     /// For implementation reasons, there can be a single 'primary' impl block and 0 or more 'secondary' impl blocks.
     /// For now, this is controlled by a key in the 'godot_api' attribute.
     pub secondary: bool,
+
+    /// `#[godot_api(script_instance)]` declares the `#[func]` methods of a `#[derive(ScriptInstance)]` type, rather than a class.
+    pub script_instance: bool,
 }
 
 /// Codegen for `#[godot_api] impl MyType`
@@ -110,11 +113,29 @@ pub fn transform_inherent_impl(
     let (signal_registrations, signal_symbol_types) =
         make_signal_registrations(&signals, &class_name, &class_name_obj)?;
 
+    // RPC configs and the rpcs() collection are only generated by the primary impl block. Secondary blocks register such methods as
+    // plain #[func]s, as they always did.
+    let rpc_deprecations = funcs
+        .iter()
+        .filter(|func_def| meta.secondary && func_def.rpc_info.is_some())
+        .map(|func_def| {
+            let method_name = &func_def.signature_info.method_name;
+            quote_spanned! { method_name.span()=>
+                ::godot::__deprecated::emit_deprecated_warning!(rpc_secondary_impl);
+            }
+        })
+        .collect::<Vec<_>>();
+
     #[cfg(feature = "codegen-full")]
     let rpc_registrations = crate::class::make_rpc_registrations_fn(&class_name, &funcs);
     #[cfg(not(feature = "codegen-full"))]
     let rpc_registrations = TokenStream::new();
 
+    #[cfg(feature = "codegen-full")]
+    let rpc_collection = crate::class::make_rpc_collection(&class_name, &funcs);
+    #[cfg(not(feature = "codegen-full"))]
+    let rpc_collection: Option<TokenStream> = None;
+
     let method_registrations: Vec<TokenStream> = funcs
         .into_iter()
         .map(|func_def| make_method_registration(&class_name, func_def, None))
@@ -187,6 +208,7 @@ pub fn transform_inherent_impl(
                 #( #func_name_constants )*
             }
             #signal_symbol_types
+            #rpc_collection
         };
 
         Ok(result)
@@ -196,6 +218,7 @@ pub fn transform_inherent_impl(
 
         let result = quote! {
             #impl_block
+            #( #rpc_deprecations )*
             #fill_storage
             impl #funcs_collection {
                 #( #func_name_constants )*
@@ -206,6 +229,115 @@ pub fn transform_inherent_impl(
     }
 }
 
+/// Codegen for `#[godot_api(script_instance)] impl MyInstance`
+pub fn transform_script_instance_impl(mut impl_block: venial::Impl) -> ParseResult<TokenStream> {
+    let class_name = util::validate_impl(&impl_block, None, "godot_api")?;
+
+    let (funcs, signals) = process_godot_fns(&class_name, &mut impl_block, false)?;
+    if let Some(signal) = signals.first() {
+        return bail!(
+            &signal.fn_signature.name,
+            "#[signal] is not supported in #[godot_api(script_instance)]"
+        );
+    }
+
+    let consts = process_godot_constants(&mut impl_block)?;
+    if let Some(constant) = consts.first() {
+        return bail!(
+            &constant.raw_constant.name,
+            "#[constant] is not supported in #[godot_api(script_instance)]"
+        );
+    }
+
+    let script_api = make_script_instance_api(&class_name, funcs)?;
+
+    Ok(quote! {
+        #impl_block
+        #script_api
+    })
+}
+
+/// Removes `#[opt(default = ...)]` attributes from the parameters of `function`, and returns the default values.
+///
+/// Defaults are only allowed on trailing parameters, so the returned values belong to the last N parameters.
+fn extract_default_params(function: &mut venial::Function) -> ParseResult<Vec<TokenStream>> {
+    let mut default_params = vec![];
+
+    let param_count = function.params.len();
+    for (index, (param, _)) in function.params.inner.iter_mut().enumerate() {
+        let venial::FnParam::Typed(param) = param else {
+            continue;
+        };
+
+        // Variadic parameter is not part of the signature registered with Godot.
+        if index + 1 == param_count && is_varargs_param(param) {
+            if KvParser::parse(&param.attributes, "opt")?.is_some() {
+                return bail!(
+                    &param.name,
+                    "variadic parameter cannot have a default value"
+                );
+            }
+            continue;
+        }
+
+        let Some(mut parser) = KvParser::parse(&param.attributes, "opt")? else {
+            if !default_params.is_empty() {
+                return bail!(
+                    &param.name,
+                    "parameter without #[opt(default = ...)] cannot follow parameters with default values"
+                );
+            }
+            continue;
+        };
+
+        default_params.push(parser.handle_expr_required("default")?);
+        parser.finish()?;
+
+        param
+            .attributes
+            .retain(|attr| !matches!(attr.get_single_path_segment(), Some(name) if name == "opt"));
+    }
+
+    Ok(default_params)
+}
+
+/// Whether the parameter has type `&[Variant]`, receiving the extra arguments of a variadic function.
+fn is_varargs_param(param: &venial::FnTypedParam) -> bool {
+    let [TokenTree::Punct(amp), TokenTree::Group(group)] = param.ty.tokens.as_slice() else {
+        return false;
+    };
+
+    amp.as_char() == '&'
+        && group.delimiter() == Delimiter::Bracket
+        && matches!(group.stream().into_iter().last(), Some(TokenTree::Ident(ident)) if ident == "Variant")
+}
+
+/// Checks that an `async fn` can be registered: its future must be `'static`, so it cannot borrow `self` or arguments.
+fn validate_async_func(
+    attr_name: &Ident,
+    function: &venial::Function,
+    signature_info: &SignatureInfo,
+    func: &FuncAttr,
+    has_varargs: bool,
+) -> ParseResult<()> {
+    require_api_version!("4.2", &function.name, "async #[func]")?;
+
+    let error = if matches!(
+        signature_info.receiver_type,
+        ReceiverType::Ref | ReceiverType::Mut
+    ) {
+        "async #[func] cannot take `&self` or `&mut self`; use #[func(gd_self)] with a `Gd<Self>` parameter instead"
+    } else if func.is_virtual {
+        "#[func(virtual)] cannot be async"
+    } else if has_varargs {
+        "async #[func] does not support variadic parameters"
+    } else {
+        return Ok(());
+    };
+
+    bail_attr(attr_name.clone(), error, function)
+}
+
 fn process_godot_fns(
     class_name: &Ident,
     impl_block: &mut venial::Impl,
@@ -225,9 +357,13 @@ fn process_godot_fns(
             continue;
         };
 
+        // `async` is only supported for #[func], see below.
+        let is_async = function.qualifiers.tk_async.is_some();
+        let is_func = matches!(attr.ty, ItemAttrType::Func(..));
+
         if function.qualifiers.tk_default.is_some()
             || function.qualifiers.tk_const.is_some()
-            || function.qualifiers.tk_async.is_some()
+            || (is_async && !is_func)
             || function.qualifiers.tk_unsafe.is_some()
             || function.qualifiers.tk_extern.is_some()
             || function.qualifiers.extern_abi.is_some()
@@ -242,6 +378,7 @@ fn process_godot_fns(
         match attr.ty {
             ItemAttrType::Func(func, rpc_info) => {
                 let external_attributes = function.attributes.clone();
+                let default_params = extract_default_params(function)?;
 
                 // Signatures are the same thing without body.
                 let mut signature = util::reduce_to_signature(function);
@@ -270,10 +407,46 @@ fn process_godot_fns(
                     None
                 };
 
+                // Trailing `&[Variant]` receives extra arguments; it's not part of the registered signature.
+                let has_varargs = match signature.params.inner.last() {
+                    Some((venial::FnParam::Typed(param), _)) => is_varargs_param(param),
+                    _ => false,
+                };
+                if has_varargs {
+                    if func.is_virtual {
+                        return bail_attr(
+                            attr.attr_name,
+                            "#[func(virtual)] does not support variadic parameters",
+                            function,
+                        );
+                    }
+                    signature.params.inner.pop();
+                }
+
                 // Clone might not strictly be necessary, but the 2 other callers of into_signature_info() are better off with pass-by-value.
-                let signature_info =
+                let mut signature_info =
                     into_signature_info(signature.clone(), class_name, gd_self_parameter.is_some());
 
+                // Async functions are spawned as tasks; Godot receives a signal that is emitted on completion.
+                if is_async {
+                    validate_async_func(
+                        &attr.attr_name,
+                        function,
+                        &signature_info,
+                        &func,
+                        has_varargs,
+                    )?;
+                    signature_info.return_type = quote! { ::godot::builtin::Signal };
+                }
+
+                if default_params.len() > signature_info.param_types.len() {
+                    return bail_attr(
+                        attr.attr_name,
+                        "with attribute key `gd_self`, the first parameter cannot have a default value",
+                        function,
+                    );
+                }
+
                 // For virtual methods, rename/mangle existing user method and create a new method with the original name,
                 // which performs a dynamic dispatch.
                 let registered_name = if func.is_virtual {
@@ -297,6 +470,9 @@ fn process_godot_fns(
                     registered_name,
                     is_script_virtual: func.is_virtual,
                     rpc_info,
+                    default_params,
+                    has_varargs,
+                    is_async,
                 });
             }
 
@@ -92,6 +92,11 @@ pub fn transform_trait_impl(mut original_impl: venial::Impl) -> ParseResult<Toke
                     method.body = Some(new_body);
                     //panic!("modify params: {}", method.params.to_token_stream().to_string());
                 }
+
+                // Merge warnings generated by #[derive(GodotClass)] (e.g. unset OnEditor fields) into the user's.
+                if regular_virtual_fn == "get_configuration_warnings" {
+                    method.body = Some(make_merged_configuration_warnings(&method.body));
+                }
             }
         }
     }
@@ -136,17 +141,19 @@ pub fn transform_trait_impl(mut original_impl: venial::Impl) -> ParseResult<Toke
     };
 
     // See also __default_virtual_call() codegen.
-    let (hash_param, hashes_use, match_expr);
+    let (hash_param, hashes_use, match_expr, default_call_args);
     if cfg!(since_api = "4.4") {
         hash_param = quote! { hash: u32, };
         hashes_use = quote! {
             use ::godot::sys::known_virtual_hashes::#trait_base_class as hashes;
         };
         match_expr = quote! { (name, hash) };
+        default_call_args = quote! { name, hash };
     } else {
         hash_param = TokenStream::new();
         hashes_use = TokenStream::new();
         match_expr = quote! { name };
+        default_call_args = quote! { name };
     };
 
     let virtual_match_arms = decls
@@ -154,6 +161,23 @@ pub fn transform_trait_impl(mut original_impl: venial::Impl) -> ParseResult<Toke
         .iter()
         .map(|v| v.make_match_arm(&class_name));
 
+    // Without a user-defined get_configuration_warnings(), the one generated by #[derive(GodotClass)] is used -- if there is one.
+    let configuration_warnings_arm = if is_possibly_node_class(&trait_base_class)
+        && !decls
+            .overridden_virtuals
+            .iter()
+            .any(|v| v.method_name == "_get_configuration_warnings")
+    {
+        quote! {
+            _ if <Self as ::godot::obj::UserClass>::__HAS_CONFIGURATION_WARNINGS
+                && name == "_get_configuration_warnings" => {
+                <Self as ::godot::obj::UserClass>::__default_virtual_call(#default_call_args)
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
     let mut result = quote! {
         // #original_impl inserted below.
         #decls
@@ -169,6 +193,7 @@ pub fn transform_trait_impl(mut original_impl: venial::Impl) -> ParseResult<Toke
                 #hashes_use
                 match #match_expr {
                     #( #virtual_match_arms )*
+                    #configuration_warnings_arm
                     _ => None,
                 }
             }
@@ -532,6 +557,19 @@ fn handle_regular_virtual_fn<'a>(
     updated_function
 }
 
+/// Wraps the body of a user-defined `get_configuration_warnings()`, appending the warnings generated by `#[derive(GodotClass)]`.
+fn make_merged_configuration_warnings(original_body: &Option<Group>) -> Group {
+    let body_code = quote! {
+        // Closure, so that `return` statements in the user's body still work.
+        #[allow(clippy::redundant_closure_call)]
+        let mut warnings: ::godot::builtin::PackedStringArray = (|| #original_body)();
+        warnings.extend_array(&<Self as ::godot::obj::UserClass>::__configuration_warnings(self));
+        warnings
+    };
+
+    Group::new(Delimiter::Brace, body_code)
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Rest of implementation
 
@@ -7,7 +7,7 @@
 
 //! Parses the `#[var]` and `#[export]` attributes on fields.
 
-use crate::class::{Field, FieldVar, Fields, GetSet, GetterSetterImpl, UsageFlags};
+use crate::class::{Field, FieldExport, FieldVar, Fields, GetSet, GetterSetterImpl, UsageFlags};
 use crate::util::{format_funcs_collection_constant, format_funcs_collection_struct};
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
@@ -67,74 +67,59 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
 
         let field_name = field_ident.to_string();
 
+        // #[var(flatten)] or #[export(flatten)]: one indexed getter/setter pair for all properties of the group.
+        if var.flatten || export.as_ref().is_some_and(|export| export.flatten) {
+            let getter_tokens = make_getter_setter(
+                Some(GetterSetterImpl::from_flattened_impl(
+                    class_name,
+                    GetSet::Get,
+                    field,
+                )),
+                &mut getter_setter_impls,
+                &mut func_name_consts,
+                &mut export_tokens,
+                class_name,
+            );
+            let setter_tokens = make_getter_setter(
+                Some(GetterSetterImpl::from_flattened_impl(
+                    class_name,
+                    GetSet::Set,
+                    field,
+                )),
+                &mut getter_setter_impls,
+                &mut func_name_consts,
+                &mut export_tokens,
+                class_name,
+            );
+            let exported = export.is_some();
+
+            export_tokens.push(quote! {
+                ::godot::register::private::register_flattened::<#class_name, #field_type>(
+                    #field_name,
+                    #getter_tokens,
+                    #setter_tokens,
+                    #exported,
+                );
+            });
+            continue;
+        }
+
         let FieldVar {
             getter,
             setter,
             hint,
-            mut usage_flags,
+            usage_flags,
             ..
         } = var;
 
-        let export_hint;
-        let registration_fn;
-
-        if let Some(export) = export {
-            if usage_flags.is_inferred() {
-                usage_flags = UsageFlags::InferredExport;
-            }
-
-            export_hint = export.to_export_hint();
-            registration_fn = quote! { register_export };
+        let registration_fn = if export.is_some() {
+            quote! { register_export }
         } else {
-            export_hint = None;
-            registration_fn = quote! { register_var };
-        }
-
-        let usage_flags = match usage_flags {
-            UsageFlags::Inferred => {
-                quote! { ::godot::global::PropertyUsageFlags::NONE }
-            }
-            UsageFlags::InferredExport => {
-                quote! { ::godot::global::PropertyUsageFlags::DEFAULT }
-            }
-            UsageFlags::Custom(flags) => quote! {
-                #(
-                    ::godot::global::PropertyUsageFlags::#flags
-                )|*
-            },
+            quote! { register_var }
         };
 
-        let hint = match hint {
-            FieldHint::Inferred => {
-                if let Some(export_hint) = export_hint {
-                    quote! { #export_hint }
-                } else if export.is_some() {
-                    quote! { <#field_type as ::godot::register::property::Export>::export_hint() }
-                } else {
-                    quote! { <#field_type as ::godot::register::property::Var>::var_hint() }
-                }
-            }
-            FieldHint::Hint(hint) => {
-                let hint_string = if let Some(export_hint) = export_hint {
-                    quote! { #export_hint.hint_string }
-                } else {
-                    quote! { ::godot::builtin::GString::new() }
-                };
-
-                quote! {
-                    ::godot::meta::PropertyHintInfo {
-                        hint: ::godot::global::PropertyHint::#hint,
-                        hint_string: #hint_string,
-                    }
-                }
-            }
-            FieldHint::HintWithString { hint, hint_string } => quote! {
-                ::godot::meta::PropertyHintInfo {
-                    hint: ::godot::global::PropertyHint::#hint,
-                    hint_string: ::godot::builtin::GString::from(#hint_string),
-                }
-            },
-        };
+        let (hint, usage_flags) =
+            make_hint_and_usage_flags(field_type, export.as_ref(), hint, usage_flags);
 
         // Note: {getter,setter}_tokens can be either a path `Class_Functions::constant_name` or an empty string `""`.
 
@@ -189,6 +174,73 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
     }
 }
 
+/// Returns the expressions for property hint and usage flags of a `#[var]` or `#[export]` field.
+pub(crate) fn make_hint_and_usage_flags(
+    field_type: &venial::TypeExpr,
+    export: Option<&FieldExport>,
+    hint: FieldHint,
+    mut usage_flags: UsageFlags,
+) -> (TokenStream, TokenStream) {
+    let export_hint = match export {
+        Some(export) => {
+            if usage_flags.is_inferred() {
+                usage_flags = UsageFlags::InferredExport;
+            }
+
+            export.to_export_hint()
+        }
+        None => None,
+    };
+
+    let usage_flags = match usage_flags {
+        UsageFlags::Inferred => {
+            quote! { ::godot::global::PropertyUsageFlags::NONE }
+        }
+        UsageFlags::InferredExport => {
+            quote! { ::godot::global::PropertyUsageFlags::DEFAULT }
+        }
+        UsageFlags::Custom(flags) => quote! {
+            #(
+                ::godot::global::PropertyUsageFlags::#flags
+            )|*
+        },
+    };
+
+    let hint = match hint {
+        FieldHint::Inferred => {
+            if let Some(export_hint) = export_hint {
+                quote! { #export_hint }
+            } else if export.is_some() {
+                quote! { <#field_type as ::godot::register::property::Export>::export_hint() }
+            } else {
+                quote! { <#field_type as ::godot::register::property::Var>::var_hint() }
+            }
+        }
+        FieldHint::Hint(hint) => {
+            let hint_string = if let Some(export_hint) = export_hint {
+                quote! { #export_hint.hint_string }
+            } else {
+                quote! { ::godot::builtin::GString::new() }
+            };
+
+            quote! {
+                ::godot::meta::PropertyHintInfo {
+                    hint: ::godot::global::PropertyHint::#hint,
+                    hint_string: #hint_string,
+                }
+            }
+        }
+        FieldHint::HintWithString { hint, hint_string } => quote! {
+            ::godot::meta::PropertyHintInfo {
+                hint: ::godot::global::PropertyHint::#hint,
+                hint_string: ::godot::builtin::GString::from(#hint_string),
+            }
+        },
+    };
+
+    (hint, usage_flags)
+}
+
 fn make_getter_setter(
     getter_setter_impl: Option<GetterSetterImpl>,
     getter_setter_impls: &mut Vec<TokenStream>,
@@ -5,9 +5,10 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::class::FuncDefinition;
+use crate::class::{map_self_to_class_name, FuncDefinition};
+use crate::util;
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{format_ident, quote};
 
 /// Possible ways the user can specify RPC configuration.
 pub enum RpcAttr {
@@ -160,3 +161,89 @@ fn make_rpc_registration(func_def: &FuncDefinition) -> Option<TokenStream> {
 
     Some(registration)
 }
+
+/// Generates the typed RPC collection accessible via `rpcs()`, with one method per `#[rpc]` function.
+pub fn make_rpc_collection(class_name: &Ident, funcs: &[FuncDefinition]) -> Option<TokenStream> {
+    let rpc_methods = funcs
+        .iter()
+        .filter(|func_def| func_def.rpc_info.is_some())
+        .map(|func_def| make_rpc_collection_method(class_name, func_def))
+        .collect::<Vec<TokenStream>>();
+
+    if rpc_methods.is_empty() {
+        return None;
+    }
+
+    let collection_struct_name = format_ident!("__godot_Rpcs_{}", class_name);
+
+    // Same visibility as the class, see signal collection for rationale.
+    let visibility_macro = util::format_class_visibility_macro(class_name);
+
+    let code = quote! {
+        #visibility_macro! {
+            #[allow(non_camel_case_types)]
+            #[doc(hidden)] // Only on struct, not methods, to allow completion in IDEs.
+            struct #collection_struct_name {
+                #[doc(hidden)]
+                __node: ::godot::obj::Gd<::godot::classes::Node>,
+            }
+        }
+
+        impl #collection_struct_name {
+            #( #rpc_methods )*
+        }
+
+        impl ::godot::obj::WithUserRpcs for #class_name {
+            type RpcCollection = #collection_struct_name;
+
+            #[doc(hidden)]
+            fn __rpcs_from_node(node: ::godot::obj::Gd<::godot::classes::Node>) -> Self::RpcCollection {
+                #collection_struct_name { __node: node }
+            }
+        }
+    };
+
+    Some(code)
+}
+
+fn make_rpc_collection_method(class_name: &Ident, func_def: &FuncDefinition) -> TokenStream {
+    let signature_info = &func_def.signature_info;
+    let method_name = &signature_info.method_name;
+    let param_idents = &signature_info.param_idents;
+
+    // `Self` would refer to the collection struct.
+    let param_types = signature_info
+        .param_types
+        .iter()
+        .map(|ty| map_self_to_class_name::<_, TokenStream>(ty.tokens.clone(), class_name));
+    let method_name_str = func_def.godot_name();
+    let cfg_attrs = util::extract_cfg_attrs(&func_def.external_attributes)
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    let (varargs_param, collect_args) = if func_def.has_varargs {
+        (
+            quote! { varargs: &[::godot::builtin::Variant] },
+            quote! {
+                let mut args = vec![ #( ::godot::meta::ToGodot::to_variant(&#param_idents) ),* ];
+                args.extend_from_slice(varargs);
+            },
+        )
+    } else {
+        (
+            TokenStream::new(),
+            quote! {
+                let args: Vec<::godot::builtin::Variant> =
+                    vec![ #( ::godot::meta::ToGodot::to_variant(&#param_idents) ),* ];
+            },
+        )
+    };
+
+    quote! {
+        #(#cfg_attrs)*
+        pub fn #method_name(&self, #( #param_idents: #param_types, )* #varargs_param) -> ::godot::register::RpcCall {
+            #collect_args
+            ::godot::register::RpcCall::new(self.__node.clone(), #method_name_str, args)
+        }
+    }
+}
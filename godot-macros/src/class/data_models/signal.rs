@@ -387,7 +387,8 @@ fn make_signal_symbols(
     collection_api: SignalCollection,
     // max_visibility: SignalVisibility,
 ) -> Option<TokenStream> {
-    // Future note: if we add Rust->Rust inheritance, then the WithSignals trait must be unconditionally implemented.
+    // Classes inheriting a Rust class can only declare signals if that base declares signals, too (Deref below needs `Base: WithSignals`).
+    // Lifting this limitation requires implementing the WithSignals trait unconditionally.
     if collection_api.is_empty() {
         return None;
     }
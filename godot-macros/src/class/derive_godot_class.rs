@@ -7,15 +7,15 @@
 
 use crate::class::{
     make_property_impl, make_virtual_callback, BeforeKind, Field, FieldCond, FieldDefault,
-    FieldExport, FieldVar, Fields, SignatureInfo,
+    FieldExport, FieldVar, Fields, GetterSetter, SignatureInfo,
 };
 use crate::util::{
-    bail, error, format_funcs_collection_struct, ident, path_ends_with_complex,
-    require_api_version, KvParser,
+    bail, error, format_funcs_collection_struct, ident, parse_class_instances,
+    path_ends_with_complex, require_api_version, ClassInstance, KvParser,
 };
 use crate::{handle_mutually_exclusive_keys, util, ParseResult};
-use proc_macro2::{Ident, Punct, TokenStream};
-use quote::{format_ident, quote, quote_spanned};
+use proc_macro2::{Ident, Punct, TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
 
 pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
     let class = item.as_struct().ok_or_else(|| {
@@ -25,35 +25,145 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         )
     })?;
 
-    if class.generic_params.is_some() {
+    // Field errors are reported before attribute errors, see named_fields().
+    named_fields(class)?;
+    let mut struct_cfg = parse_struct_attributes(class)?;
+    let deprecations = std::mem::take(&mut struct_cfg.deprecations);
+
+    let class_impls = if let Some(instances) = struct_cfg.instances.take() {
+        make_generic_class(class, instances, &struct_cfg)?
+    } else if class.generic_params.is_some() {
         return bail!(
             &class.generic_params,
-            "#[derive(GodotClass)] does not support lifetimes or generic parameters",
+            "#[derive(GodotClass)] on generic structs requires #[class(instances(...))], e.g. `instances(Pool<i64> = \"IntPool\")`",
         );
+    } else {
+        let class_name_str = struct_cfg
+            .rename
+            .clone()
+            .unwrap_or_else(|| class.name.clone())
+            .to_string();
+
+        make_class(class, &class.name, &class_name_str, &struct_cfg)?
+    };
+
+    Ok(quote! {
+        #class_impls
+        #( #deprecations )*
+    })
+}
+
+/// Registers each instance of a generic class as a separate Godot class, declared as type alias named after the instance.
+fn make_generic_class(
+    class: &venial::Struct,
+    instances: Vec<ClassInstance>,
+    struct_cfg: &ClassAttributes,
+) -> ParseResult<TokenStream> {
+    let Some(generic_params) = &class.generic_params else {
+        return bail!(
+            &class.name,
+            "#[class(instances)] requires a struct with generic parameters"
+        );
+    };
+
+    if let Some(rename) = &struct_cfg.rename {
+        return bail!(
+            rename,
+            "#[class(rename)] cannot be combined with #[class(instances)], which names each instance"
+        );
+    }
+
+    let params = generic_params
+        .params
+        .iter()
+        .map(|(param, _punct)| match &param.tk_prefix {
+            Some(TokenTree::Punct(_lifetime)) => bail!(
+                param.name.clone(),
+                "#[derive(GodotClass)] does not support lifetime parameters"
+            ),
+            _ => Ok(param.name.clone()),
+        })
+        .collect::<ParseResult<Vec<Ident>>>()?;
+
+    let struct_name = &class.name;
+    let vis_marker = &class.vis_marker;
+    let mut class_impls = vec![];
+    let mut instance_entries = vec![];
+
+    for instance in instances {
+        let replacements = instance.map_generic_args(&params)?;
+
+        // Each instance is a concrete class: replace generic parameters in field types and #[init] expressions.
+        let mut generic_class = class.clone();
+        generic_class.generic_params = None;
+        generic_class.where_clause = None;
+        let concrete_item = venial::parse_item(util::substitute_idents(
+            generic_class.to_token_stream(),
+            &replacements,
+        ))?;
+        let concrete_class = concrete_item
+            .as_struct()
+            .expect("struct after substitution");
+
+        let name = &instance.name;
+        let name_str = name.to_string();
+        let ty = &instance.ty;
+        let doc = format!("Godot class `{name_str}`, registered as instance of [`{struct_name}`].");
+
+        let class_impl = make_class(concrete_class, name, &name_str, struct_cfg)?;
+        class_impls.push(quote! {
+            #[doc = #doc]
+            #vis_marker type #name = #ty;
+
+            #class_impl
+        });
+        instance_entries.push(quote! { #ty = #name_str });
     }
 
+    let instances_macro = make_instances_macro(struct_name, &instance_entries);
+
+    Ok(quote! {
+        #( #class_impls )*
+        #instances_macro
+    })
+}
+
+/// Generates a decl-macro, which forwards a generic `#[godot_api]` impl block together with the instances of the class.
+///
+/// `#[godot_api]` cannot know the instances itself; it thus invokes this macro, which re-applies the attribute with `__instances(...)`.
+fn make_instances_macro(struct_name: &Ident, instance_entries: &[TokenStream]) -> TokenStream {
+    let macro_name = util::format_class_instances_macro(struct_name);
+
+    quote! {
+        macro_rules! #macro_name {
+            ( ( $( $meta:tt )* ) $( $item:tt )+ ) => {
+                #[::godot::register::godot_api( $( $meta )* __instances( #( #instance_entries ),* ) )]
+                $( $item )+
+            };
+        }
+    }
+}
+
+/// Generates all items for a (non-generic) class.
+fn make_class(
+    class: &venial::Struct,
+    class_name: &Ident,
+    class_name_str: &str,
+    struct_cfg: &ClassAttributes,
+) -> ParseResult<TokenStream> {
     let mut modifiers = Vec::new();
     let named_fields = named_fields(class)?;
-    let mut struct_cfg = parse_struct_attributes(class)?;
     let mut fields = parse_fields(named_fields, struct_cfg.init_strategy)?;
     if struct_cfg.is_editor_plugin() {
         modifiers.push(quote! { with_editor_plugin })
     }
 
-    let mut deprecations = std::mem::take(&mut struct_cfg.deprecations);
-    deprecations.append(&mut fields.deprecations);
-
+    let deprecations = std::mem::take(&mut fields.deprecations);
     let errors = fields.errors.iter().map(|error| error.to_compile_error());
 
-    let class_name = &class.name;
-    let class_name_str: String = struct_cfg
-        .rename
-        .map_or_else(|| class.name.clone(), |rename| rename)
-        .to_string();
-
     // Determine if we can use ASCII for the class name (in most cases).
     let class_name_allocation = if class_name_str.is_ascii() {
-        let c_str = util::c_str(&class_name_str);
+        let c_str = util::c_str(class_name_str);
         quote! { ClassName::alloc_next_ascii(#c_str) }
     } else {
         quote! { ClassName::alloc_next_unicode(#class_name_str) }
@@ -68,10 +178,16 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         crate::docs::document_struct(base_ty.to_string(), &class.attributes, &fields.all_fields);
     #[cfg(not(all(feature = "register-docs", since_api = "4.3")))]
     let docs = quote! {};
-    let base_class = quote! { ::godot::classes::#base_ty };
-    let inherits_macro = format_ident!("unsafe_inherits_transitive_{}", base_ty);
 
     let prv = quote! { ::godot::private };
+
+    // Tool classes refresh the editor's configuration warnings when an `OnEditor` export changes. This needs a `Base<T>` field.
+    if struct_cfg.is_tool && fields.base_field.is_some() {
+        for field in fields.all_fields.iter_mut() {
+            field.updates_configuration_warnings = field.is_oneditor && field.export.is_some();
+        }
+    }
+
     let godot_exports_impl = make_property_impl(class_name, &fields);
 
     let godot_withbase_impl = if let Some(Field { name, ty, .. }) = &fields.base_field {
@@ -121,6 +237,38 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
             is_instantiable = false;
         }
     };
+
+    // If another Rust class inherits this one, this creates the Rust part of this class for that object.
+    let inherited_storage_impl = if is_instantiable {
+        quote! {
+            fn __godot_inherited_storage(
+                object_ptr: ::godot::sys::GDExtensionObjectPtr,
+            ) -> Option<#prv::InheritedStorage> {
+                Some(#prv::callbacks::create_inherited_storage::<Self>(object_ptr))
+            }
+        }
+    } else {
+        quote! {
+            const __IS_INSTANTIABLE: bool = false;
+
+            fn __godot_inherited_storage(
+                _object_ptr: ::godot::sys::GDExtensionObjectPtr,
+            ) -> Option<#prv::InheritedStorage> {
+                unreachable!("inheriting classes without init is rejected at compile time")
+            }
+        }
+    };
+
+    // A Rust base class must be able to construct its own part of the object.
+    let base_instantiable_message = format!(
+        "base class of `{class_name}` has no init and can thus not be inherited by another Rust class"
+    );
+    let base_instantiable_check = quote! {
+        const _: () = assert!(
+            <<#class_name as ::godot::obj::GodotClass>::Base as ::godot::obj::GodotClass>::__IS_INSTANTIABLE,
+            #base_instantiable_message
+        );
+    };
     if is_instantiable {
         modifiers.push(quote! { with_instantiable });
     }
@@ -133,6 +281,14 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         modifiers.push(quote! { with_tool })
     }
 
+    if struct_cfg.is_abstract {
+        modifiers.push(quote! { with_abstract })
+    }
+
+    if struct_cfg.is_runtime {
+        modifiers.push(quote! { with_runtime })
+    }
+
     // Declares a "funcs collection" struct that, for holds a constant for each #[func].
     // That constant maps the Rust name (constant ident) to the Godot registered name (string value).
     let funcs_collection_struct_name = format_funcs_collection_struct(class_name);
@@ -145,19 +301,58 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
     let visibility_macro = make_visibility_macro(class_name, class.vis_marker.as_ref());
 
     Ok(quote! {
-        impl ::godot::obj::GodotClass for #class_name {
-            type Base = #base_class;
+        const _: () = {
+            #[doc(hidden)]
+            pub trait __GodotResolveBase {
+                type Base;
+            }
+
+            #[doc(hidden)]
+            pub struct __GodotBaseResolver;
+
+            // Only the base is resolved in this scope. It can be an engine class or another user class: engine classes are brought into
+            // scope by the glob import, user classes are found in the surrounding scope. The class itself is named outside this block, so
+            // user types named like engine classes (e.g. `struct Timer`) are not shadowed.
+            const _: () = {
+                #[allow(unused_imports)]
+                use ::godot::classes::*;
 
-            // Code duplicated in godot-codegen.
-            fn class_name() -> ::godot::meta::ClassName {
-                use ::godot::meta::ClassName;
+                impl __GodotResolveBase for __GodotBaseResolver {
+                    type Base = #base_ty;
+                }
+            };
+
+            impl ::godot::obj::GodotClass for #class_name {
+                type Base = <__GodotBaseResolver as __GodotResolveBase>::Base;
 
-                // Optimization note: instead of lazy init, could use separate static which is manually initialized during registration.
-                static CLASS_NAME: std::sync::OnceLock<ClassName> = std::sync::OnceLock::new();
+                // Code duplicated in godot-codegen.
+                fn class_name() -> ::godot::meta::ClassName {
+                    use ::godot::meta::ClassName;
 
-                let name: &'static ClassName = CLASS_NAME.get_or_init(|| #class_name_allocation);
-                *name
+                    // Optimization note: instead of lazy init, could use separate static which is manually initialized during registration.
+                    static CLASS_NAME: std::sync::OnceLock<ClassName> = std::sync::OnceLock::new();
+
+                    let name: &'static ClassName = CLASS_NAME.get_or_init(|| #class_name_allocation);
+                    *name
+                }
+
+                #inherited_storage_impl
+
+                unsafe fn __godot_before_ready_chain(instance_ptr: ::godot::sys::GDExtensionClassInstancePtr) {
+                    <<Self as ::godot::obj::GodotClass>::Base as ::godot::obj::GodotClass>::__godot_before_ready_chain(instance_ptr);
+
+                    let storage = #prv::as_storage::<Self>(instance_ptr);
+                    ::godot::obj::UserClass::__before_ready(&mut *#prv::Storage::get_mut(storage));
+                }
             }
+        };
+
+        // SAFETY: the class inherits from everything its base class inherits from (including the base itself).
+        unsafe impl<T> ::godot::obj::Inherits<T> for #class_name
+        where
+            T: ::godot::obj::GodotClass,
+            <#class_name as ::godot::obj::GodotClass>::Base: ::godot::obj::Inherits<T>,
+        {
         }
 
         unsafe impl ::godot::obj::Bounds for #class_name {
@@ -167,6 +362,7 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
             type Exportable = <<Self as ::godot::obj::GodotClass>::Base as ::godot::obj::Bounds>::Exportable;
         }
 
+        #base_instantiable_check
         #funcs_collection_struct
         #godot_init_impl
         #godot_withbase_impl
@@ -182,8 +378,6 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
                 #prv::Struct::new::<#class_name>(#docs)#(.#modifiers())*
             )
         ));
-
-        #prv::class_macros::#inherits_macro!(#class_name);
     })
 }
 
@@ -235,7 +429,10 @@ struct ClassAttributes {
     init_strategy: InitStrategy,
     is_tool: bool,
     is_internal: bool,
+    is_abstract: bool,
+    is_runtime: bool,
     rename: Option<Ident>,
+    instances: Option<Vec<ClassInstance>>,
     deprecations: Vec<TokenStream>,
 }
 
@@ -348,6 +545,47 @@ fn make_oneditor_panic_inits(class_name: &Ident, all_fields: &[Field]) -> TokenS
     }
 }
 
+/// For `#[class(tool)]` classes, lists `OnEditor` fields that are still unset as configuration warnings in the editor.
+fn make_oneditor_configuration_warnings(
+    is_tool: bool,
+    all_fields: &[Field],
+) -> Option<TokenStream> {
+    if !is_tool {
+        return None;
+    }
+
+    let field_checks = all_fields
+        .iter()
+        .filter(|&field| field.is_oneditor)
+        .map(|field| {
+            let field = &field.name;
+            let warning_message = format!("Required property `{field}` has not been set.");
+
+            quote! {
+                if self.#field.is_invalid() {
+                    warnings.push(#warning_message);
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if field_checks.is_empty() {
+        return None;
+    }
+
+    Some(quote! {
+        #[doc(hidden)]
+        const __HAS_CONFIGURATION_WARNINGS: bool = true;
+
+        #[doc(hidden)]
+        fn __configuration_warnings(&self) -> ::godot::builtin::PackedStringArray {
+            let mut warnings = ::godot::builtin::PackedStringArray::new();
+            #( #field_checks )*
+            warnings
+        }
+    })
+}
+
 fn make_user_class_impl(
     class_name: &Ident,
     is_tool: bool,
@@ -363,26 +601,66 @@ fn make_user_class_impl(
 
     let oneditor_panic_inits = make_oneditor_panic_inits(class_name, all_fields);
 
+    let configuration_warnings = make_oneditor_configuration_warnings(is_tool, all_fields);
+
     let run_before_ready = !onready_inits.is_empty() || !oneditor_panic_inits.is_empty();
 
-    let default_virtual_fn = if run_before_ready {
-        let tool_check = util::make_virtual_tool_check();
-        let signature_info = SignatureInfo::fn_ready();
+    // See also __virtual_call() codegen.
+    // This doesn't explicitly check if the base class inherits from Node (and thus has `_ready` and `_get_configuration_warnings`), but the
+    // derive-macro already does this for the `OnReady` and `OnEditor` field declarations.
+    let hash_param = if cfg!(since_api = "4.4") {
+        quote! { hash: u32, }
+    } else {
+        TokenStream::new()
+    };
+    let make_hash_check = |method: &str| {
+        if cfg!(since_api = "4.4") {
+            let method = ident(method);
+            quote! { && hash == ::godot::sys::known_virtual_hashes::Node::#method }
+        } else {
+            TokenStream::new()
+        }
+    };
 
+    let mut default_virtual_arms = vec![];
+    if run_before_ready {
+        let signature_info = SignatureInfo::fn_ready();
         let callback =
             make_virtual_callback(class_name, &signature_info, BeforeKind::OnlyBefore, None);
+        let hash_check = make_hash_check("ready");
 
-        // See also __virtual_call() codegen.
-        // This doesn't explicitly check if the base class inherits from Node (and thus has `_ready`), but the derive-macro already does
-        // this for the `OnReady` field declaration.
-        let (hash_param, hash_check);
-        if cfg!(since_api = "4.4") {
-            hash_param = quote! { hash: u32, };
-            hash_check = quote! { && hash == ::godot::sys::known_virtual_hashes::Node::ready };
-        } else {
-            hash_param = TokenStream::new();
-            hash_check = TokenStream::new();
-        }
+        default_virtual_arms.push(quote! {
+            if name == "_ready" #hash_check {
+                #callback
+            }
+        });
+    }
+
+    // Without a user-defined get_configuration_warnings(), report the generated warnings directly.
+    if configuration_warnings.is_some() {
+        let signature_info = SignatureInfo::fn_configuration_warnings();
+        let user_class_trait = venial::TypeExpr {
+            tokens: quote! { ::godot::obj::UserClass }.into_iter().collect(),
+        };
+        let callback = make_virtual_callback(
+            class_name,
+            &signature_info,
+            BeforeKind::Without,
+            Some(&user_class_trait),
+        );
+        let hash_check = make_hash_check("get_configuration_warnings");
+
+        default_virtual_arms.push(quote! {
+            if name == "_get_configuration_warnings" #hash_check {
+                #callback
+            }
+        });
+    }
+
+    let default_virtual_fn = if default_virtual_arms.is_empty() {
+        None
+    } else {
+        let tool_check = util::make_virtual_tool_check();
 
         let default_virtual_fn = quote! {
             fn __default_virtual_call(
@@ -392,16 +670,12 @@ fn make_user_class_impl(
                 use ::godot::obj::UserClass as _;
                 #tool_check
 
-                if name == "_ready" #hash_check {
-                    #callback
-                } else {
+                #( #default_virtual_arms else )* {
                     None
                 }
             }
         };
         Some(default_virtual_fn)
-    } else {
-        None
     };
 
     let user_class_impl = quote! {
@@ -420,6 +694,7 @@ fn make_user_class_impl(
                 #onready_inits
             }
 
+            #configuration_warnings
             #default_virtual_fn
         }
     };
@@ -433,7 +708,10 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
     let mut init_strategy = InitStrategy::UserDefined;
     let mut is_tool = false;
     let mut is_internal = false;
+    let mut is_abstract = false;
+    let mut is_runtime = false;
     let mut rename: Option<Ident> = None;
+    let mut instances = None;
     let mut deprecations = vec![];
 
     // #[class] attribute on struct
@@ -450,9 +728,20 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
             None => {}
         }
 
-        // #[class(tool)]
-        if parser.handle_alone("tool")? {
-            is_tool = true;
+        // #[class(tool)], #[class(runtime)]
+        let tool_span = parser.span();
+        match handle_mutually_exclusive_keys(&mut parser, "class", &["tool", "runtime"])? {
+            Some(0) => is_tool = true,
+            Some(_) => {
+                require_api_version!("4.3", tool_span, "#[class(runtime)]")?;
+                is_runtime = true;
+            }
+            None => {}
+        }
+
+        // #[class(abstract)]
+        if parser.handle_alone("abstract")? {
+            is_abstract = true;
         }
 
         // Deprecated #[class(editor_plugin)]
@@ -465,6 +754,17 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
         // #[class(rename = NewName)]
         rename = parser.handle_ident("rename")?;
 
+        // #[class(instances(Type<Args> = "Name", ...))]
+        if let Some((key, list)) = parser.handle_any_entry("instances") {
+            let Some(list) = list else {
+                return bail!(
+                    key,
+                    "expected list of instances, e.g. `instances(Pool<i64> = \"IntPool\")`"
+                );
+            };
+            instances = Some(parse_class_instances(list.single()?)?);
+        }
+
         // #[class(internal)]
         // Named "internal" following Godot terminology: https://github.com/godotengine/godot-cpp/blob/master/include/godot_cpp/core/class_db.hpp#L327
         if let Some(span) = parser.handle_alone_with_span("internal")? {
@@ -492,7 +792,10 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
         init_strategy,
         is_tool,
         is_internal,
+        is_abstract,
+        is_runtime,
         rename,
+        instances,
         deprecations,
     })
 }
@@ -601,6 +904,16 @@ fn parse_fields(
                 );
             }
 
+            // #[init(scene_nodes)]
+            if parser.handle_alone("scene_nodes")? {
+                field.set_default_val_if(
+                    || quote! { OnReady::from_scene_nodes() },
+                    FieldCond::IsOnReady,
+                    &parser,
+                    &mut errors,
+                );
+            }
+
             // #[init(sentinel = EXPR)]
             if let Some(sentinel_value) = parser.handle_expr("sentinel")? {
                 field.set_default_val_if(
@@ -628,6 +941,23 @@ fn parse_fields(
             parser.finish()?;
         }
 
+        // #[export(notify)] relies on the setter generated by #[var], and must not conflict with #[var(notify)].
+        if let (Some(var), Some(export)) = (&field.var, &field.export) {
+            if let Some(notify_span) = export.notify.span() {
+                if !var.notify.is_empty() {
+                    errors.push(error!(
+                        notify_span,
+                        "change notification must be specified in either #[var] or #[export], not both"
+                    ));
+                } else if var.setter != GetterSetter::Generated {
+                    errors.push(error!(
+                        notify_span,
+                        "#[export] change notification requires a generated setter; with a custom or omitted setter, notify from your own code"
+                    ));
+                }
+            }
+        }
+
         // #[hint] to override type inference (must be at the end).
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "hint")? {
             if let Some(override_base) = handle_opposite_keys(&mut parser, "base", "hint")? {
@@ -0,0 +1,214 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+use crate::class::{make_hint_and_usage_flags, FieldExport, FieldVar, GetterSetter};
+use crate::util::{bail, KvParser};
+use crate::ParseResult;
+
+/// A field of a property group; every field is exposed, `#[var]`/`#[export]` only customize it.
+struct GroupProperty {
+    field_name: Ident,
+    ty: venial::TypeExpr,
+    var: FieldVar,
+    export: Option<FieldExport>,
+}
+
+/// Codegen for `#[derive(PropertyGroup)]`.
+pub fn derive_property_group(item: venial::Item) -> ParseResult<TokenStream> {
+    let decl = match item {
+        venial::Item::Struct(decl) => decl,
+        _ => bail!(
+            item,
+            "#[derive(PropertyGroup)] is only supported on structs",
+        )?,
+    };
+
+    if decl.generic_params.is_some() {
+        bail!(
+            &decl.generic_params,
+            "#[derive(PropertyGroup)] does not support lifetimes or generic parameters",
+        )?;
+    }
+
+    let struct_name = &decl.name;
+    let properties = parse_properties(&decl)?;
+
+    let property_infos = properties.iter().map(make_property_info);
+    let get_arms = properties
+        .iter()
+        .enumerate()
+        .map(|(index, property)| make_get_arm(index, property));
+    let set_arms = properties
+        .iter()
+        .enumerate()
+        .map(|(index, property)| make_set_arm(index, property, struct_name));
+
+    Ok(quote! {
+        impl ::godot::register::property::PropertyGroup for #struct_name {
+            #[allow(unused_variables)]
+            fn __group_properties(exported: bool) -> Vec<::godot::meta::PropertyInfo> {
+                vec![ #( #property_infos, )* ]
+            }
+
+            fn __get_group_property(&self, index: usize) -> ::godot::builtin::Variant {
+                match index {
+                    #( #get_arms )*
+                    _ => ::godot::builtin::Variant::nil(),
+                }
+            }
+
+            #[allow(unused_variables)]
+            fn __set_group_property(&mut self, index: usize, value: &::godot::builtin::Variant) {
+                match index {
+                    #( #set_arms )*
+                    _ => {}
+                }
+            }
+        }
+    })
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+fn parse_properties(decl: &venial::Struct) -> ParseResult<Vec<GroupProperty>> {
+    let named_fields = match &decl.fields {
+        venial::Fields::Unit => return Ok(vec![]),
+        venial::Fields::Tuple(_) => bail!(
+            &decl.fields,
+            "#[derive(PropertyGroup)] is not supported for tuple structs",
+        )?,
+        venial::Fields::Named(fields) => &fields.fields.inner,
+    };
+
+    let mut properties = vec![];
+    for (named_field, _punct) in named_fields {
+        // #[export]
+        let export = match KvParser::parse(&named_field.attributes, "export")? {
+            Some(mut parser) => {
+                let export = FieldExport::new_from_kv(&mut parser)?;
+                parser.finish()?;
+                Some(export)
+            }
+            None => None,
+        };
+
+        // #[var]
+        let var = match KvParser::parse(&named_field.attributes, "var")? {
+            Some(mut parser) => {
+                let var = FieldVar::new_from_kv(&mut parser)?;
+                parser.finish()?;
+                var
+            }
+            None => FieldVar::default(),
+        };
+
+        if var.flatten || export.as_ref().is_some_and(|export| export.flatten) {
+            return bail!(
+                named_field,
+                "#[derive(PropertyGroup)] does not support nested flattening",
+            );
+        }
+
+        if let Some(notify_span) = var
+            .notify
+            .span()
+            .or_else(|| export.as_ref().and_then(|export| export.notify.span()))
+        {
+            return bail!(
+                notify_span,
+                "#[derive(PropertyGroup)] does not support property change notification",
+            );
+        }
+
+        properties.push(GroupProperty {
+            field_name: named_field.name.clone(),
+            ty: named_field.ty.clone(),
+            var,
+            export,
+        });
+    }
+
+    Ok(properties)
+}
+
+fn make_property_info(property: &GroupProperty) -> TokenStream {
+    let GroupProperty {
+        field_name,
+        ty,
+        var,
+        export,
+    } = property;
+
+    let name_str = field_name.to_string();
+    let (hint, mut usage_flags) = make_hint_and_usage_flags(
+        ty,
+        export.as_ref(),
+        var.hint.clone(),
+        var.usage_flags.clone(),
+    );
+
+    // Without explicit #[export] or usage flags, visibility in the editor follows the enclosing field.
+    if export.is_none() && var.usage_flags.is_inferred() {
+        usage_flags = quote! {
+            if exported {
+                ::godot::global::PropertyUsageFlags::DEFAULT
+            } else {
+                ::godot::global::PropertyUsageFlags::NONE
+            }
+        };
+    }
+
+    quote! {
+        ::godot::meta::PropertyInfo {
+            usage: #usage_flags,
+            ..::godot::meta::PropertyInfo::new_var::<#ty>(#name_str).with_hint_info(#hint)
+        }
+    }
+}
+
+fn make_get_arm(index: usize, property: &GroupProperty) -> TokenStream {
+    let field_name = &property.field_name;
+    let field_type = &property.ty;
+
+    let value = match &property.var.getter {
+        GetterSetter::Omitted => return TokenStream::new(),
+        GetterSetter::Generated => quote! {
+            <#field_type as ::godot::register::property::Var>::get_property(&self.#field_name)
+        },
+        GetterSetter::Custom(getter) => quote! { self.#getter() },
+    };
+
+    quote! {
+        #index => ::godot::meta::ToGodot::to_variant(&#value),
+    }
+}
+
+fn make_set_arm(index: usize, property: &GroupProperty, struct_name: &Ident) -> TokenStream {
+    let field_name = &property.field_name;
+    let field_type = &property.ty;
+
+    let assign = match &property.var.setter {
+        GetterSetter::Omitted => return TokenStream::new(),
+        GetterSetter::Generated => quote! {
+            <#field_type as ::godot::register::property::Var>::set_property(&mut self.#field_name, value)
+        },
+        GetterSetter::Custom(setter) => quote! { self.#setter(value) },
+    };
+
+    let property_path = format!("{struct_name}::{field_name}");
+
+    quote! {
+        #index => match ::godot::meta::FromGodot::try_from_variant(value) {
+            Ok(value) => #assign,
+            Err(err) => ::godot::global::godot_error!("cannot set property {}: {err}", #property_path),
+        },
+    }
+}
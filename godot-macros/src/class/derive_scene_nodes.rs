@@ -0,0 +1,360 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+
+use proc_macro2::{Ident, Literal, TokenStream, TokenTree};
+use quote::{quote, quote_spanned};
+
+use crate::util::{bail, error, extract_typename, ident, path_is_single, KvParser};
+use crate::ParseResult;
+
+/// A field annotated with `#[node("path")]`.
+struct NodeField {
+    field_name: Ident,
+    ty: venial::TypeExpr,
+    path: String,
+    path_lit: Literal,
+}
+
+/// Codegen for `#[derive(SceneNodes)]`.
+pub fn derive_scene_nodes(item: venial::Item) -> ParseResult<TokenStream> {
+    let decl = match item {
+        venial::Item::Struct(decl) => decl,
+        _ => bail!(item, "#[derive(SceneNodes)] is only supported on structs")?,
+    };
+
+    if decl.generic_params.is_some() {
+        bail!(
+            &decl.generic_params,
+            "#[derive(SceneNodes)] does not support lifetimes or generic parameters",
+        )?;
+    }
+
+    let named_fields = match &decl.fields {
+        venial::Fields::Named(fields) => &fields.fields.inner,
+        _ => bail!(
+            &decl.fields,
+            "#[derive(SceneNodes)] is only supported for structs with named fields",
+        )?,
+    };
+
+    let mut node_fields = vec![];
+    let mut other_fields = vec![];
+    for (named_field, _punct) in named_fields {
+        match parse_node_path(&named_field.attributes)? {
+            Some((path, path_lit)) => node_fields.push(NodeField {
+                field_name: named_field.name.clone(),
+                ty: named_field.ty.clone(),
+                path,
+                path_lit,
+            }),
+            None => other_fields.push(named_field.name.clone()),
+        }
+    }
+
+    let tscn_check = match KvParser::parse(&decl.attributes, "scene_nodes")? {
+        Some(mut parser) => {
+            let tscn = parser.handle_expr_required("tscn")?;
+            let check_types = parser.handle_alone("check_types")?;
+            parser.finish()?;
+            make_tscn_check(&tscn, check_types, &node_fields)?
+        }
+        None => TokenStream::new(),
+    };
+
+    let struct_name = &decl.name;
+    let struct_name_str = struct_name.to_string();
+
+    let resolve_stmts = node_fields.iter().map(|field| {
+        let NodeField {
+            field_name,
+            ty,
+            path_lit,
+            ..
+        } = field;
+
+        quote! {
+            let #field_name = <#ty as ::godot::obj::SceneNodeField>::__resolve_field(root, #path_lit, error);
+        }
+    });
+    let node_field_names = node_fields.iter().map(|field| &field.field_name);
+
+    Ok(quote! {
+        impl ::godot::obj::SceneNodes for #struct_name {
+            const __STRUCT_NAME: &'static str = #struct_name_str;
+
+            fn __resolve(
+                root: &::godot::obj::Gd<::godot::classes::Node>,
+                error: &mut ::godot::meta::error::SceneNodesError,
+            ) -> Option<Self> {
+                // Resolve all fields before bailing out, so the error lists every problem.
+                #( #resolve_stmts )*
+
+                Some(Self {
+                    #( #node_field_names: #node_field_names?, )*
+                    #( #other_fields: ::std::default::Default::default(), )*
+                })
+            }
+        }
+
+        #tscn_check
+    })
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+/// Parses `#[node("path")]`, returning the unquoted path and the original literal.
+fn parse_node_path(attributes: &[venial::Attribute]) -> ParseResult<Option<(String, Literal)>> {
+    let mut found = None;
+
+    for attr in attributes {
+        if !path_is_single(&attr.path, "node") {
+            continue;
+        }
+
+        if found.is_some() {
+            return bail!(attr, "only a single #[node] attribute allowed");
+        }
+
+        let tokens = attr.value.get_value_tokens();
+        let lit = match tokens {
+            [TokenTree::Literal(lit)] => lit,
+            _ => return bail!(attr, "expected #[node(\"path\")] with a string literal"),
+        };
+
+        let text = lit.to_string();
+        let path = text
+            .strip_prefix('"')
+            .and_then(|s| s.strip_suffix('"'))
+            .filter(|path| !path.is_empty() && !path.contains('\\'));
+
+        let Some(path) = path else {
+            return bail!(
+                lit,
+                "#[node] path must be a non-empty string literal without escapes"
+            );
+        };
+
+        found = Some((path.to_string(), lit.clone()));
+    }
+
+    Ok(found)
+}
+
+/// A `[node]` section of a `.tscn` file.
+struct TscnNode {
+    /// Class name, if the node is not an instanced scene.
+    ty: Option<String>,
+    is_instance: bool,
+}
+
+/// Verifies the paths of all `#[node]` fields against a `.tscn` file, at compile time.
+///
+/// Missing paths are reported directly. With `check_types`, compile-time assertions are emitted, which check that the class in the scene
+/// inherits the declared class.
+fn make_tscn_check(
+    tscn: &TokenStream,
+    check_types: bool,
+    node_fields: &[NodeField],
+) -> ParseResult<TokenStream> {
+    let Some((tscn_path, tscn_lit)) = parse_tscn_path(tscn) else {
+        return bail!(tscn, "#[scene_nodes(tscn)] expects a string literal");
+    };
+
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let full_path = std::path::Path::new(&manifest_dir).join(&tscn_path);
+    let content = match std::fs::read_to_string(&full_path) {
+        Ok(content) => content,
+        Err(err) => {
+            return bail!(
+                tscn,
+                "cannot read scene file `{}`: {err}",
+                full_path.display()
+            )
+        }
+    };
+
+    let scene = parse_tscn(&content);
+
+    let mut missing = vec![];
+    let mut type_checks = vec![];
+    for field in node_fields {
+        let path = field.path.as_str();
+
+        // Unique names, parent-relative and absolute paths cannot be resolved statically.
+        if path.starts_with('%') || path.starts_with('/') || path.split('/').any(|s| s == "..") {
+            continue;
+        }
+
+        match scene.get(path) {
+            Some(TscnNode {
+                ty: Some(class_name),
+                ..
+            }) if check_types => {
+                let class = ident(class_name);
+                let field_ty = &field.ty;
+                let span = field.path_lit.span();
+
+                type_checks.push(quote_spanned! { span=>
+                    __assert_inherits::<#class, <#field_ty as ::godot::obj::SceneNodeField>::Class>();
+                });
+            }
+            Some(_) => {}
+            // Optional nodes may legitimately be absent from the scene.
+            None if is_inside_instance(&scene, path) || is_optional(&field.ty) => {}
+            None => missing.push(field),
+        }
+    }
+
+    if let Some(first) = missing.first() {
+        let paths = missing
+            .iter()
+            .map(|field| format!("`{}`", field.path))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let mut err = error!(
+            &first.path_lit,
+            "node paths not found in scene `{tscn_path}`: {paths}"
+        );
+        for field in &missing[1..] {
+            err.combine(error!(&field.path_lit, "node path not found"));
+        }
+        return Err(err);
+    }
+
+    // Classes are resolved in the scope of the struct, not via `godot::classes`: the scene may use classes of other extensions or
+    // user classes named like engine classes.
+    let type_check = if check_types {
+        quote! {
+            fn __assert_inherits<Derived, Base>()
+            where
+                Derived: ::godot::obj::Inherits<Base>,
+                Base: ::godot::obj::GodotClass,
+            {
+            }
+
+            #[allow(dead_code)]
+            fn __check_scene_node_types() {
+                #( #type_checks )*
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    Ok(quote! {
+        const _: () = {
+            // Rebuild when the scene changes.
+            const _: &[u8] = include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/", #tscn_lit));
+
+            #type_check
+        };
+    })
+}
+
+fn parse_tscn_path(tscn: &TokenStream) -> Option<(String, Literal)> {
+    let mut tokens = tscn.clone().into_iter();
+    let (Some(TokenTree::Literal(lit)), None) = (tokens.next(), tokens.next()) else {
+        return None;
+    };
+
+    let text = lit.to_string();
+    let path = text.strip_prefix('"')?.strip_suffix('"')?.to_string();
+
+    Some((path, lit))
+}
+
+/// Maps node paths relative to the scene root (e.g. `Top/Health`) to their `[node]` sections.
+///
+/// Only the section headers are parsed, e.g. `[node name="Health" type="ProgressBar" parent="Top"]`. The root node has no `parent`.
+fn parse_tscn(content: &str) -> HashMap<String, TscnNode> {
+    let mut nodes = HashMap::new();
+
+    for line in content.lines() {
+        let Some(header) = line
+            .trim()
+            .strip_prefix("[node ")
+            .and_then(|s| s.strip_suffix(']'))
+        else {
+            continue;
+        };
+
+        let attrs = parse_tscn_header(header);
+        let (Some(name), Some(parent)) = (attrs.get("name"), attrs.get("parent")) else {
+            // Root node.
+            continue;
+        };
+
+        let path = match parent.as_str() {
+            "." => name.clone(),
+            parent => format!("{parent}/{name}"),
+        };
+
+        nodes.insert(
+            path,
+            TscnNode {
+                ty: attrs.get("type").cloned(),
+                is_instance: attrs.contains_key("instance"),
+            },
+        );
+    }
+
+    nodes
+}
+
+/// Parses `key="value" key=Value(...)` pairs of a section header. Quotes are removed from values.
+fn parse_tscn_header(header: &str) -> HashMap<&str, String> {
+    let mut attrs = HashMap::new();
+    let mut rest = header.trim();
+
+    while let Some((key, after)) = rest.split_once('=') {
+        let key = key.trim();
+
+        let (value, after) = if let Some(quoted) = after.strip_prefix('"') {
+            let end = quoted.find('"').unwrap_or(quoted.len());
+            (
+                quoted[..end].to_string(),
+                quoted.get(end + 1..).unwrap_or(""),
+            )
+        } else {
+            // Unquoted values such as `instance=ExtResource("1_abc")` may contain spaces only inside parentheses.
+            let mut depth = 0;
+            let end = after
+                .char_indices()
+                .find(|&(_, c)| {
+                    match c {
+                        '(' => depth += 1,
+                        ')' => depth -= 1,
+                        ' ' if depth == 0 => return true,
+                        _ => {}
+                    }
+                    false
+                })
+                .map_or(after.len(), |(i, _)| i);
+            (after[..end].to_string(), &after[end..])
+        };
+
+        attrs.insert(key, value);
+        rest = after.trim_start();
+    }
+
+    attrs
+}
+
+/// Whether `path` points inside an instanced sub-scene, whose nodes are not listed in the `.tscn` file.
+fn is_inside_instance(scene: &HashMap<String, TscnNode>, path: &str) -> bool {
+    path.match_indices('/')
+        .any(|(i, _)| scene.get(&path[..i]).is_some_and(|node| node.is_instance))
+}
+
+/// Whether the field is declared as `Option<Gd<T>>`.
+fn is_optional(ty: &venial::TypeExpr) -> bool {
+    extract_typename(ty).is_some_and(|segment| segment.ident == "Option")
+}
@@ -0,0 +1,468 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+use crate::class::{
+    make_default_args_fn, make_hint_and_usage_flags, make_method_flags, FieldExport, FieldVar,
+    FuncDefinition, GetterSetter, ReceiverType, UsageFlags,
+};
+use crate::util::{bail, ident, KvParser};
+use crate::{util, ParseResult};
+
+/// A `#[var]` or `#[export]` field of a script instance.
+struct ScriptProperty {
+    field_name: Ident,
+    ty: venial::TypeExpr,
+    var: FieldVar,
+    export: Option<FieldExport>,
+}
+
+/// Codegen for `#[derive(ScriptInstance)]`.
+pub fn derive_script_instance(item: venial::Item) -> ParseResult<TokenStream> {
+    let decl = match item {
+        venial::Item::Struct(decl) => decl,
+        _ => bail!(
+            item,
+            "#[derive(ScriptInstance)] can only be applied on structs",
+        )?,
+    };
+
+    if decl.generic_params.is_some() {
+        return bail!(
+            &decl.generic_params,
+            "#[derive(ScriptInstance)] does not support generic structs",
+        );
+    }
+
+    let struct_name = &decl.name;
+    let mut base_ty = ident("Object");
+    let struct_name_str = struct_name.to_string();
+    let mut class_name = quote! { #struct_name_str };
+
+    // #[script_instance(base = Node, class_name = "Player")]
+    if let Some(mut parser) = KvParser::parse(&decl.attributes, "script_instance")? {
+        if let Some(base) = parser.handle_ident("base")? {
+            base_ty = base;
+        }
+
+        if let Some(name) = parser.handle_expr("class_name")? {
+            class_name = name;
+        }
+
+        parser.finish()?;
+    }
+
+    let properties = parse_properties(&decl)?;
+
+    let property_infos = properties.iter().map(make_property_info);
+    let get_arms = properties.iter().filter_map(make_get_arm);
+    let set_arms = properties.iter().filter_map(make_set_arm);
+
+    // The #[godot_api(script_instance)] block is optional; without it, the instance has no methods.
+    let script_api = quote! { (&&::godot::private::ScriptApi::<Self>::new()) };
+
+    #[cfg(since_api = "4.3")]
+    let method_argument_count = quote! {
+        fn get_method_argument_count(&self, method: ::godot::builtin::StringName) -> Option<u32> {
+            #script_api.script_method_argument_count(&method.to_string())
+        }
+    };
+    #[cfg(before_api = "4.3")]
+    let method_argument_count = TokenStream::new();
+
+    Ok(quote! {
+        const _: () = {
+            // Engine classes are brought into scope for the base type, like in #[derive(GodotClass)].
+            #[allow(unused_imports)]
+            use ::godot::classes::*;
+            use ::godot::private::{ScriptApiImplemented as _, ScriptApiMissing as _};
+
+            impl ::godot::obj::script::ScriptInstance for #struct_name {
+                type Base = #base_ty;
+
+                fn class_name(&self) -> ::godot::builtin::GString {
+                    ::godot::builtin::GString::from(#class_name)
+                }
+
+                #[allow(unused_mut, unused_variables)]
+                fn set_property(
+                    mut this: ::godot::obj::script::SiMut<Self>,
+                    name: ::godot::builtin::StringName,
+                    value: &::godot::builtin::Variant,
+                ) -> bool {
+                    match name.to_string().as_str() {
+                        #( #set_arms )*
+                        _ => false,
+                    }
+                }
+
+                fn get_property(&self, name: ::godot::builtin::StringName) -> Option<::godot::builtin::Variant> {
+                    match name.to_string().as_str() {
+                        #( #get_arms )*
+                        _ => None,
+                    }
+                }
+
+                fn get_property_list(&self) -> Vec<::godot::meta::PropertyInfo> {
+                    vec![ #( #property_infos ),* ]
+                }
+
+                fn get_method_list(&self) -> Vec<::godot::meta::MethodInfo> {
+                    #script_api.script_methods()
+                }
+
+                fn call(
+                    mut this: ::godot::obj::script::SiMut<Self>,
+                    method: ::godot::builtin::StringName,
+                    args: &[&::godot::builtin::Variant],
+                ) -> Result<::godot::builtin::Variant, ::godot::sys::GDExtensionCallErrorType> {
+                    let method = method.to_string();
+                    #script_api
+                        .script_call(&mut *this, &method, args)
+                        .unwrap_or(Err(::godot::sys::GDEXTENSION_CALL_ERROR_INVALID_METHOD))
+                }
+
+                fn is_placeholder(&self) -> bool {
+                    false
+                }
+
+                fn has_method(&self, method: ::godot::builtin::StringName) -> bool {
+                    #script_api
+                        .script_method_argument_count(&method.to_string())
+                        .is_some()
+                }
+
+                fn get_script(&self) -> &::godot::obj::Gd<::godot::classes::Script> {
+                    <Self as ::godot::obj::script::ScriptInstanceSource>::get_script(self)
+                }
+
+                fn get_property_type(&self, name: ::godot::builtin::StringName) -> ::godot::builtin::VariantType {
+                    self.get_property_list()
+                        .into_iter()
+                        .find(|info| info.property_name == name)
+                        .map_or(::godot::builtin::VariantType::NIL, |info| info.variant_type)
+                }
+
+                fn to_string(&self) -> ::godot::builtin::GString {
+                    ::godot::builtin::GString::from(format!("<{}>", #class_name))
+                }
+
+                fn get_property_state(&self) -> Vec<(::godot::builtin::StringName, ::godot::builtin::Variant)> {
+                    self.get_property_list()
+                        .into_iter()
+                        .filter_map(|info| {
+                            let value = self.get_property(info.property_name.clone())?;
+                            Some((info.property_name, value))
+                        })
+                        .collect()
+                }
+
+                fn get_language(&self) -> ::godot::obj::Gd<::godot::classes::ScriptLanguage> {
+                    <Self as ::godot::obj::script::ScriptInstanceSource>::get_language(self)
+                }
+
+                fn on_refcount_decremented(&self) -> bool {
+                    true
+                }
+
+                fn on_refcount_incremented(&self) {}
+
+                fn property_get_fallback(&self, _name: ::godot::builtin::StringName) -> Option<::godot::builtin::Variant> {
+                    None
+                }
+
+                fn property_set_fallback(
+                    _this: ::godot::obj::script::SiMut<Self>,
+                    _name: ::godot::builtin::StringName,
+                    _value: &::godot::builtin::Variant,
+                ) -> bool {
+                    false
+                }
+
+                #method_argument_count
+            }
+        };
+    })
+}
+
+/// Codegen for the `#[func]` methods in `#[godot_api(script_instance)] impl MyInstance`.
+pub fn make_script_instance_api(
+    class_name: &Ident,
+    funcs: Vec<FuncDefinition>,
+) -> ParseResult<TokenStream> {
+    let mut method_infos = vec![];
+    let mut call_arms = vec![];
+    let mut argument_count_arms = vec![];
+
+    for (id, func) in funcs.into_iter().enumerate() {
+        let signature_info = &func.signature_info;
+        let method_name = &signature_info.method_name;
+
+        let unsupported = if func.is_script_virtual {
+            Some("#[func(virtual)]")
+        } else if func.rpc_info.is_some() {
+            Some("#[rpc]")
+        } else if func.has_varargs {
+            Some("variadic #[func]")
+        } else if func.is_async {
+            Some("async #[func]")
+        } else {
+            None
+        };
+
+        if let Some(unsupported) = unsupported {
+            return bail!(
+                method_name,
+                "{unsupported} is not supported in #[godot_api(script_instance)]"
+            );
+        }
+
+        if !matches!(
+            signature_info.receiver_type,
+            ReceiverType::Ref | ReceiverType::Mut
+        ) {
+            return bail!(
+                method_name,
+                "#[func] in #[godot_api(script_instance)] requires a `&self` or `&mut self` receiver"
+            );
+        }
+
+        let method_flags =
+            make_method_flags(signature_info.receiver_type, false, false).expect("not static");
+        let default_args_fn = make_default_args_fn(signature_info, &func.default_params);
+
+        let sig_tuple = signature_info.tuple_type();
+        let godot_name = func.godot_name();
+        let param_count = signature_info.param_types.len();
+        let param_types = &signature_info.param_types;
+        let param_indices = 0..param_count;
+        let param_name_strs = signature_info
+            .param_idents
+            .iter()
+            .map(|ident| ident.to_string());
+
+        let cfg_attrs = util::extract_cfg_attrs(&func.external_attributes)
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        let id = id as i32;
+        method_infos.push(quote! {
+            #(#cfg_attrs)*
+            {
+                use ::godot::builtin::Variant;
+
+                type Sig = #sig_tuple;
+                #default_args_fn
+
+                methods.push(::godot::private::script_method_info::<Sig>(
+                    #id,
+                    #godot_name,
+                    &[ #( #param_name_strs ),* ],
+                    #method_flags,
+                    default_args(),
+                ));
+            }
+        });
+
+        argument_count_arms.push(quote! {
+            #(#cfg_attrs)*
+            #godot_name => Some(#param_count as u32),
+        });
+
+        call_arms.push(quote! {
+            #(#cfg_attrs)*
+            #godot_name => {
+                use ::godot::builtin::Variant;
+                #default_args_fn
+
+                let result = ::godot::private::script_call_args(args, #param_count, default_args)
+                    .and_then(|args| {
+                        Ok(self.#method_name(
+                            #( ::godot::private::script_arg::<#param_types>(&args[#param_indices])?, )*
+                        ))
+                    })
+                    .map(|ret| ::godot::meta::ToGodot::to_variant(&ret));
+
+                Some(result)
+            }
+        });
+    }
+
+    Ok(quote! {
+        impl ::godot::obj::script::ScriptInstanceApi for #class_name {
+            fn __script_methods() -> Vec<::godot::meta::MethodInfo> {
+                #[allow(unused_mut)]
+                let mut methods = Vec::new();
+                #( #method_infos )*
+                methods
+            }
+
+            fn __script_method_argument_count(method: &str) -> Option<u32> {
+                match method {
+                    #( #argument_count_arms )*
+                    _ => None,
+                }
+            }
+
+            #[allow(unused_variables)]
+            fn __script_call(
+                &mut self,
+                method: &str,
+                args: &[&::godot::builtin::Variant],
+            ) -> Option<Result<::godot::builtin::Variant, ::godot::sys::GDExtensionCallErrorType>> {
+                match method {
+                    #( #call_arms )*
+                    _ => None,
+                }
+            }
+        }
+    })
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+fn parse_properties(decl: &venial::Struct) -> ParseResult<Vec<ScriptProperty>> {
+    let named_fields = match &decl.fields {
+        venial::Fields::Unit => return Ok(vec![]),
+        venial::Fields::Tuple(_) => bail!(
+            &decl.fields,
+            "#[derive(ScriptInstance)] is not supported for tuple structs",
+        )?,
+        venial::Fields::Named(fields) => &fields.fields.inner,
+    };
+
+    let mut properties = vec![];
+    for (named_field, _punct) in named_fields {
+        // #[export]
+        let export = match KvParser::parse(&named_field.attributes, "export")? {
+            Some(mut parser) => {
+                let export = FieldExport::new_from_kv(&mut parser)?;
+                parser.finish()?;
+                Some(export)
+            }
+            None => None,
+        };
+
+        // #[var]
+        let var = match KvParser::parse(&named_field.attributes, "var")? {
+            Some(mut parser) => {
+                let var = FieldVar::new_from_kv(&mut parser)?;
+                parser.finish()?;
+                Some(var)
+            }
+            None => None,
+        };
+
+        if var.as_ref().is_some_and(|var| var.flatten)
+            || export.as_ref().is_some_and(|export| export.flatten)
+        {
+            return bail!(
+                named_field,
+                "#[derive(ScriptInstance)] does not support flattened properties",
+            );
+        }
+
+        if let Some(notify_span) = var
+            .as_ref()
+            .and_then(|var| var.notify.span())
+            .or_else(|| export.as_ref().and_then(|export| export.notify.span()))
+        {
+            return bail!(
+                notify_span,
+                "#[derive(ScriptInstance)] does not support property change notification",
+            );
+        }
+
+        // Like in classes, #[export] alone implies #[var].
+        let var = match (&export, var) {
+            (Some(_), None) => FieldVar {
+                usage_flags: UsageFlags::InferredExport,
+                ..Default::default()
+            },
+            (_, Some(var)) => var,
+            (None, None) => continue,
+        };
+
+        properties.push(ScriptProperty {
+            field_name: named_field.name.clone(),
+            ty: named_field.ty.clone(),
+            var,
+            export,
+        });
+    }
+
+    Ok(properties)
+}
+
+fn make_property_info(property: &ScriptProperty) -> TokenStream {
+    let ScriptProperty {
+        field_name,
+        ty,
+        var,
+        export,
+    } = property;
+
+    let name_str = field_name.to_string();
+    let (hint, usage_flags) = make_hint_and_usage_flags(
+        ty,
+        export.as_ref(),
+        var.hint.clone(),
+        var.usage_flags.clone(),
+    );
+
+    quote! {
+        ::godot::meta::PropertyInfo {
+            usage: #usage_flags,
+            ..::godot::meta::PropertyInfo::new_var::<#ty>(#name_str).with_hint_info(#hint)
+        }
+    }
+}
+
+fn make_get_arm(property: &ScriptProperty) -> Option<TokenStream> {
+    let field_name = &property.field_name;
+    let field_type = &property.ty;
+    let name_str = field_name.to_string();
+
+    let value = match &property.var.getter {
+        GetterSetter::Omitted => return None,
+        GetterSetter::Generated => quote! {
+            <#field_type as ::godot::register::property::Var>::get_property(&self.#field_name)
+        },
+        GetterSetter::Custom(getter) => quote! { self.#getter() },
+    };
+
+    Some(quote! {
+        #name_str => Some(::godot::meta::ToGodot::to_variant(&#value)),
+    })
+}
+
+fn make_set_arm(property: &ScriptProperty) -> Option<TokenStream> {
+    let field_name = &property.field_name;
+    let field_type = &property.ty;
+    let name_str = field_name.to_string();
+
+    let assign = match &property.var.setter {
+        GetterSetter::Omitted => return None,
+        GetterSetter::Generated => quote! {
+            <#field_type as ::godot::register::property::Var>::set_property(&mut this.#field_name, value)
+        },
+        GetterSetter::Custom(setter) => quote! { this.#setter(value) },
+    };
+
+    Some(quote! {
+        #name_str => match ::godot::meta::FromGodot::try_from_variant(value) {
+            Ok(value) => {
+                #assign;
+                true
+            }
+            Err(_) => false,
+        },
+    })
+}
@@ -5,21 +5,32 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::TokenStream;
+use proc_macro2::{TokenStream, TokenTree};
 
-use crate::class::{transform_inherent_impl, transform_trait_impl};
-use crate::util::{bail, venial_parse_meta, KvParser};
-use crate::ParseResult;
+use crate::class::{transform_inherent_impl, transform_script_instance_impl, transform_trait_impl};
+use crate::util::{bail, venial_parse_meta, ClassInstance, KvParser};
+use crate::{util, ParseResult};
 
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, ToTokens};
 
 fn parse_inherent_impl_attr(meta: TokenStream) -> Result<super::InherentImplAttr, venial::Error> {
     let item = venial_parse_meta(&meta, format_ident!("godot_api"), &quote! { fn func(); })?;
     let mut attr = KvParser::parse_required(item.attributes(), "godot_api", &meta)?;
     let secondary = attr.handle_alone("secondary")?;
+    let script_instance = attr.handle_alone("script_instance")?;
     attr.finish()?;
 
-    Ok(super::InherentImplAttr { secondary })
+    if secondary && script_instance {
+        return bail!(
+            meta,
+            "#[godot_api(script_instance)] cannot be combined with `secondary`"
+        );
+    }
+
+    Ok(super::InherentImplAttr {
+        secondary,
+        script_instance,
+    })
 }
 
 pub fn attribute_godot_api(
@@ -35,10 +46,7 @@ pub fn attribute_godot_api(
     };
 
     if decl.impl_generic_params.is_some() {
-        bail!(
-            &decl,
-            "#[godot_api] does not support lifetimes or generic parameters",
-        )?;
+        return transform_generic_impl(meta, decl);
     }
 
     let Some(self_path) = decl.self_ty.as_path() else {
@@ -57,8 +65,113 @@ pub fn attribute_godot_api(
         transform_trait_impl(decl)
     } else {
         match parse_inherent_impl_attr(meta) {
+            Ok(meta) if meta.script_instance => transform_script_instance_impl(decl),
             Ok(meta) => transform_inherent_impl(meta, decl, self_path),
             Err(err) => Err(err),
         }
     }
 }
+
+/// Codegen for `#[godot_api] impl<T> MyClass<T>`, which is expanded once for each instance of the generic class.
+///
+/// The instances are declared by `#[derive(GodotClass)]`, which provides a macro that re-applies `#[godot_api]` with `__instances(...)`.
+fn transform_generic_impl(meta: TokenStream, decl: venial::Impl) -> ParseResult<TokenStream> {
+    let Some(self_segment) = util::extract_typename(&decl.self_ty) else {
+        return bail!(decl, "invalid Self type for #[godot_api] impl");
+    };
+
+    let (meta, instances) = split_instances_meta(meta)?;
+    let Some(instances) = instances else {
+        let instances_macro = util::format_class_instances_macro(&self_segment.ident);
+        let meta = if meta.is_empty() {
+            meta
+        } else {
+            quote! { #meta, }
+        };
+
+        return Ok(quote! {
+            #instances_macro! { (#meta) #decl }
+        });
+    };
+
+    // Generic parameters of the impl, in the order of the class's generic arguments (`U` in `impl<U> Pool<U>`).
+    let impl_params = decl.impl_generic_params.as_ref().expect("generic impl");
+    let self_args = self_segment
+        .generic_args
+        .as_ref()
+        .map(|list| {
+            list.args
+                .iter()
+                .map(|(arg, _punct)| arg)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    let mut params = vec![];
+    for arg in self_args {
+        let param = match arg {
+            venial::GenericArg::TypeOrConst { expr } => match expr.tokens.as_slice() {
+                [TokenTree::Ident(ident)] => impl_params
+                    .params
+                    .iter()
+                    .any(|(param, _punct)| param.name == *ident)
+                    .then(|| ident.clone()),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        match param {
+            Some(param) => params.push(param),
+            None => {
+                return bail!(
+                    arg,
+                    "#[godot_api] on generic classes requires each generic argument of Self to be a generic parameter of the impl"
+                )
+            }
+        }
+    }
+
+    let mut result = TokenStream::new();
+    for instance in instances {
+        let replacements = instance.map_generic_args(&params)?;
+
+        let mut generic_decl = decl.clone();
+        generic_decl.impl_generic_params = None;
+        generic_decl.where_clause = None;
+        generic_decl.self_ty = venial::TypeExpr {
+            tokens: vec![TokenTree::Ident(instance.name.clone())],
+        };
+
+        let concrete_decl = venial::parse_item(util::substitute_idents(
+            generic_decl.to_token_stream(),
+            &replacements,
+        ))?;
+
+        result.extend(attribute_godot_api(meta.clone(), concrete_decl)?);
+    }
+
+    Ok(result)
+}
+
+/// Separates the trailing `__instances(...)` from the rest of the `#[godot_api]` parameters.
+fn split_instances_meta(
+    meta: TokenStream,
+) -> ParseResult<(TokenStream, Option<Vec<ClassInstance>>)> {
+    let mut tokens = meta.into_iter().collect::<Vec<_>>();
+
+    let list = match tokens.as_slice() {
+        [.., TokenTree::Ident(key), TokenTree::Group(_)] if key == "__instances" => {
+            tokens.pop().expect("list")
+        }
+        _ => return Ok((tokens.into_iter().collect(), None)),
+    };
+
+    tokens.pop(); // `__instances`
+    if tokens.last().is_some_and(|tt| tt.to_string() == ",") {
+        tokens.pop();
+    }
+
+    let instances = util::parse_class_instances(list)?;
+    Ok((tokens.into_iter().collect(), Some(instances)))
+}
@@ -0,0 +1,177 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::util::{bail, ident, path_ends_with_complex};
+use crate::ParseResult;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+pub fn attribute_godot_proxy(input_decl: venial::Item) -> ParseResult<TokenStream> {
+    let venial::Item::Trait(decl) = input_decl else {
+        return bail!(input_decl, "#[godot_proxy] can only be applied on traits");
+    };
+
+    if decl.generic_params.is_some() || decl.where_clause.is_some() {
+        bail!(
+            &decl,
+            "#[godot_proxy] does not support lifetimes or generic parameters",
+        )?;
+    }
+
+    if let Some(bound) = &decl.bound {
+        bail!(bound, "#[godot_proxy] does not support supertraits")?;
+    }
+
+    let trait_name = &decl.name;
+    let proxy_name = format_ident!("{}Proxy", trait_name);
+    let vis = &decl.vis_marker;
+
+    let trait_name_str = trait_name.to_string();
+    let mut method_entries = vec![];
+    let mut method_impls = vec![];
+    for member in &decl.body_items {
+        let venial::TraitMember::AssocFunction(function) = member else {
+            return bail!(
+                member,
+                "#[godot_proxy] traits can only contain methods; associated types, constants and macros are not supported",
+            );
+        };
+
+        let (entry, method_impl) = make_proxy_method(function, &trait_name_str)?;
+        method_entries.push(entry);
+        method_impls.push(method_impl);
+    }
+
+    let doc = format!("Typed proxy implementing [`{trait_name}`] by dynamic calls on an object; see [`GodotProxy`](godot::obj::GodotProxy).");
+
+    Ok(quote! {
+        #decl
+
+        #[doc = #doc]
+        #[derive(Clone, Debug)]
+        #vis struct #proxy_name {
+            object: ::godot::obj::Gd<::godot::classes::Object>,
+        }
+
+        impl ::godot::obj::GodotProxy for #proxy_name {
+            const __PROXY_NAME: &'static str = #trait_name_str;
+            const __METHODS: &'static [(&'static str, usize)] = &[
+                #( #method_entries, )*
+            ];
+
+            fn __from_object_unchecked(object: ::godot::obj::Gd<::godot::classes::Object>) -> Self {
+                Self { object }
+            }
+
+            fn object(&self) -> &::godot::obj::Gd<::godot::classes::Object> {
+                &self.object
+            }
+
+            fn into_object(self) -> ::godot::obj::Gd<::godot::classes::Object> {
+                self.object
+            }
+        }
+
+        impl #trait_name for #proxy_name {
+            #( #method_impls )*
+        }
+    })
+}
+
+/// Returns the `(name, param_count)` entry for validation, and the method implementation dispatching to `Object::call()`.
+fn make_proxy_method(
+    function: &venial::Function,
+    trait_name_str: &str,
+) -> ParseResult<(TokenStream, TokenStream)> {
+    if function.body.is_some() {
+        return bail!(
+            function,
+            "#[godot_proxy] methods cannot have a default implementation",
+        );
+    }
+
+    if function.generic_params.is_some() || function.where_clause.is_some() {
+        return bail!(
+            function,
+            "#[godot_proxy] methods cannot have generic parameters",
+        );
+    }
+
+    let qualifiers = &function.qualifiers;
+    if qualifiers.tk_async.is_some()
+        || qualifiers.tk_const.is_some()
+        || qualifiers.tk_unsafe.is_some()
+        || qualifiers.tk_extern.is_some()
+    {
+        return bail!(
+            function,
+            "#[godot_proxy] methods cannot be async, const, unsafe or extern",
+        );
+    }
+
+    let mut receiver = None;
+    let mut params = vec![];
+    for (index, (param, _punct)) in function.params.iter().enumerate() {
+        match param {
+            venial::FnParam::Receiver(param) => receiver = Some(param),
+            venial::FnParam::Typed(param) => {
+                // `_` cannot be referenced in the call; give it a name.
+                let name = if param.name == "_" {
+                    ident(&format!("__arg{index}"))
+                } else {
+                    param.name.clone()
+                };
+                params.push((name, &param.ty));
+            }
+        }
+    }
+
+    let Some(receiver) = receiver else {
+        return bail!(
+            function,
+            "#[godot_proxy] methods must take `&self` or `&mut self`",
+        );
+    };
+
+    let method_name = &function.name;
+    let method_name_str = method_name.to_string();
+    let param_count = params.len();
+    let param_decls = params.iter().map(|(name, ty)| quote! { #name: #ty });
+    let param_names = params.iter().map(|(name, _)| name);
+    let return_ty = match &function.return_ty {
+        Some(ty) => quote! { #ty },
+        None => quote! { () },
+    };
+
+    let args = quote! {
+        &[ #( ::godot::meta::ToGodot::to_variant(&#param_names) ),* ]
+    };
+
+    // Methods returning `Result<T, CallError>` report call and conversion errors instead of panicking.
+    let body = match &function.return_ty {
+        Some(ty) if path_ends_with_complex(ty, "Result") => quote! {
+            ::godot::private::proxy_try_call(&self.object, #trait_name_str, #method_name_str, #args)
+        },
+        Some(_) => quote! {
+            ::godot::private::proxy_call::<#return_ty>(&self.object, #method_name_str, #args)
+        },
+        // Ignore the return value, so that methods returning something can still be declared without a return type.
+        None => quote! {
+            ::godot::private::proxy_call::<::godot::builtin::Variant>(&self.object, #method_name_str, #args);
+        },
+    };
+
+    let attributes = &function.attributes;
+    let method_impl = quote! {
+        #( #attributes )*
+        fn #method_name(#receiver, #( #param_decls ),*) -> #return_ty {
+            #body
+        }
+    };
+
+    Ok((quote! { (#method_name_str, #param_count) }, method_impl))
+}
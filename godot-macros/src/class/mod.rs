@@ -6,8 +6,12 @@
  */
 
 mod derive_godot_class;
+mod derive_property_group;
+mod derive_scene_nodes;
+mod derive_script_instance;
 mod godot_api;
 mod godot_dyn;
+mod godot_proxy;
 mod data_models {
     pub mod constant;
     pub mod field;
@@ -33,5 +37,9 @@ pub(crate) use data_models::property::*;
 pub(crate) use data_models::rpc::*;
 pub(crate) use data_models::signal::*;
 pub(crate) use derive_godot_class::*;
+pub(crate) use derive_property_group::*;
+pub(crate) use derive_scene_nodes::*;
+pub(crate) use derive_script_instance::*;
 pub(crate) use godot_api::*;
 pub(crate) use godot_dyn::*;
+pub(crate) use godot_proxy::*;
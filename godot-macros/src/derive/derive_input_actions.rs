@@ -0,0 +1,184 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+use crate::util::{bail, KvParser, ListParser};
+use crate::ParseResult;
+
+/// A variant of the enum, with its `#[action]` attribute.
+struct Action {
+    variant: Ident,
+    name: TokenStream,
+    bindings: Vec<TokenStream>,
+}
+
+/// Codegen for `#[derive(InputActions)]`.
+pub fn derive_input_actions(item: venial::Item) -> ParseResult<TokenStream> {
+    let decl = match item {
+        venial::Item::Enum(decl) => decl,
+        _ => bail!(item, "#[derive(InputActions)] is only supported on enums")?,
+    };
+
+    if decl.generic_params.is_some() {
+        bail!(
+            &decl.generic_params,
+            "#[derive(InputActions)] does not support lifetimes or generic parameters",
+        )?;
+    }
+
+    let mut actions = vec![];
+    for (variant, _punct) in decl.variants.inner.iter() {
+        if !matches!(variant.fields, venial::Fields::Unit) {
+            return bail!(
+                variant,
+                "#[derive(InputActions)] only supports variants without fields",
+            );
+        }
+
+        actions.push(parse_action(variant)?);
+    }
+
+    if actions.is_empty() {
+        return bail!(
+            &decl.name,
+            "#[derive(InputActions)] requires at least one variant"
+        );
+    }
+
+    let enum_name = &decl.name;
+    let enum_name_str = enum_name.to_string();
+
+    let variants = actions.iter().map(|action| &action.variant);
+    let name_arms = actions.iter().map(|action| {
+        let Action { variant, name, .. } = action;
+        quote! { Self::#variant => #name, }
+    });
+    let index_arms = actions.iter().enumerate().map(|(index, action)| {
+        let variant = &action.variant;
+        quote! { Self::#variant => #index, }
+    });
+    let binding_arms = actions.iter().map(|action| {
+        let Action {
+            variant, bindings, ..
+        } = action;
+        quote! { Self::#variant => vec![ #( #bindings ),* ], }
+    });
+
+    Ok(quote! {
+        impl ::godot::tools::InputActions for #enum_name {
+            const ALL: &'static [Self] = &[ #( Self::#variants ),* ];
+            const __ENUM_NAME: &'static str = #enum_name_str;
+
+            fn action_name(self) -> &'static str {
+                match self {
+                    #( #name_arms )*
+                }
+            }
+
+            fn __index(self) -> usize {
+                match self {
+                    #( #index_arms )*
+                }
+            }
+
+            fn __default_bindings(self) -> Vec<::godot::obj::Gd<::godot::classes::InputEvent>> {
+                match self {
+                    #( #binding_arms )*
+                }
+            }
+        }
+    })
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+fn parse_action(variant: &venial::EnumVariant) -> ParseResult<Action> {
+    let mut action = Action {
+        variant: variant.name.clone(),
+        name: {
+            let name = to_snake_case(&variant.name.to_string());
+            quote! { #name }
+        },
+        bindings: vec![],
+    };
+
+    let Some(mut parser) = KvParser::parse(&variant.attributes, "action")? else {
+        return Ok(action);
+    };
+
+    // #[action(name = "...")]
+    if let Some(name) = parser.handle_expr("name")? {
+        action.name = name;
+    }
+
+    // #[action(keys = [SPACE, W])]
+    if let Some(list) = parser.handle_array("keys")? {
+        let make = |key: Ident| {
+            quote! { ::godot::tools::__key_binding(::godot::global::Key::#key) }
+        };
+        action.bindings.extend(parse_bindings(list, make)?);
+    }
+
+    // #[action(mouse_buttons = [LEFT])]
+    if let Some(list) = parser.handle_array("mouse_buttons")? {
+        let make = |button: Ident| {
+            quote! { ::godot::tools::__mouse_button_binding(::godot::global::MouseButton::#button) }
+        };
+        action.bindings.extend(parse_bindings(list, make)?);
+    }
+
+    // #[action(joy_buttons = [A])]
+    if let Some(list) = parser.handle_array("joy_buttons")? {
+        let make = |button: Ident| {
+            quote! { ::godot::tools::__joy_button_binding(::godot::global::JoyButton::#button) }
+        };
+        action.bindings.extend(parse_bindings(list, make)?);
+    }
+
+    parser.finish()?;
+    Ok(action)
+}
+
+fn parse_bindings(
+    mut list: ListParser,
+    make_binding: impl Fn(Ident) -> TokenStream,
+) -> ParseResult<Vec<TokenStream>> {
+    let mut bindings = vec![];
+    while let Some(ident) = list.next_ident()? {
+        bindings.push(make_binding(ident));
+    }
+
+    list.finish()?;
+    Ok(bindings)
+}
+
+/// Converts `MoveLeft` to `move_left` and `UIAccept` to `ui_accept`.
+fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut result = String::with_capacity(name.len() + 4);
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+
+            if prev.is_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_uppercase() && next_is_lower)
+            {
+                result.push('_');
+            }
+        }
+
+        result.extend(c.to_lowercase());
+    }
+
+    result
+}
@@ -0,0 +1,212 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+use crate::class::{make_hint_and_usage_flags, FieldExport, FieldHint, UsageFlags};
+use crate::util::{bail, KvParser};
+use crate::ParseResult;
+
+/// A field of the struct, with its `#[setting]` and `#[export]` attributes.
+struct Setting {
+    field_name: Ident,
+    ty: venial::TypeExpr,
+    /// Expression evaluating to the full name, e.g. `concat!("my_plugin", "/", "general", "/", "max_speed")`.
+    name: TokenStream,
+    default: TokenStream,
+    export: Option<FieldExport>,
+    advanced: bool,
+    restart: bool,
+}
+
+/// Codegen for `#[derive(ProjectSettings)]`.
+pub fn derive_project_settings(item: venial::Item) -> ParseResult<TokenStream> {
+    let decl = match item {
+        venial::Item::Struct(decl) => decl,
+        _ => bail!(
+            item,
+            "#[derive(ProjectSettings)] is only supported on structs",
+        )?,
+    };
+
+    if decl.generic_params.is_some() {
+        bail!(
+            &decl.generic_params,
+            "#[derive(ProjectSettings)] does not support lifetimes or generic parameters",
+        )?;
+    }
+
+    // #[settings(prefix = "my_plugin", section = "general")]
+    let mut parser = KvParser::parse_required(&decl.attributes, "settings", &decl.name)?;
+    let prefix = parser.handle_expr_required("prefix")?;
+    let section = parser.handle_expr("section")?;
+    parser.finish()?;
+
+    let settings = parse_settings(&decl, &prefix, section.as_ref())?;
+
+    let struct_name = &decl.name;
+    let names = settings.iter().map(|setting| &setting.name);
+    let load_fields = settings.iter().map(|setting| {
+        let Setting {
+            field_name,
+            ty,
+            name,
+            default,
+            ..
+        } = setting;
+
+        quote! {
+            #field_name: ::godot::tools::__load_setting::<#ty>(#name, || #default),
+        }
+    });
+    let setting_infos = settings.iter().map(make_setting_info);
+
+    Ok(quote! {
+        impl ::godot::tools::TypedProjectSettings for #struct_name {
+            const PREFIX: &'static str = #prefix;
+            const SETTING_NAMES: &'static [&'static str] = &[ #( #names ),* ];
+
+            fn load() -> Self {
+                Self {
+                    #( #load_fields )*
+                }
+            }
+
+            fn __settings() -> Vec<::godot::tools::ProjectSettingInfo> {
+                vec![ #( #setting_infos, )* ]
+            }
+        }
+
+        ::godot::sys::plugin_add!(
+            ::godot::tools::__GODOT_PROJECT_SETTINGS;
+            <#struct_name as ::godot::tools::TypedProjectSettings>::register
+        );
+    })
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+fn parse_settings(
+    decl: &venial::Struct,
+    prefix: &TokenStream,
+    struct_section: Option<&TokenStream>,
+) -> ParseResult<Vec<Setting>> {
+    let named_fields = match &decl.fields {
+        venial::Fields::Named(fields) => &fields.fields.inner,
+        _ => bail!(
+            &decl.fields,
+            "#[derive(ProjectSettings)] is only supported for structs with named fields",
+        )?,
+    };
+
+    let mut settings = vec![];
+    for (named_field, _punct) in named_fields {
+        let field_name = named_field.name.clone();
+        let ty = named_field.ty.clone();
+
+        let mut section = struct_section.cloned();
+        let mut default = quote! { ::std::default::Default::default() };
+        let mut advanced = false;
+        let mut restart = false;
+
+        // #[setting(section = "debug", default = 4.5, advanced, restart)]
+        if let Some(mut parser) = KvParser::parse(&named_field.attributes, "setting")? {
+            if let Some(field_section) = parser.handle_expr("section")? {
+                section = Some(field_section);
+            }
+
+            if let Some(expr) = parser.handle_expr("default")? {
+                default = expr;
+            }
+
+            advanced = parser.handle_alone("advanced")?;
+            restart = parser.handle_alone("restart")?;
+            parser.finish()?;
+        }
+
+        // #[export(range = (0.0, 10.0))]
+        let export = match KvParser::parse(&named_field.attributes, "export")? {
+            Some(mut parser) => {
+                let export = FieldExport::new_from_kv(&mut parser)?;
+                parser.finish()?;
+                Some(export)
+            }
+            None => None,
+        };
+
+        if let Some(export) = &export {
+            if export.flatten {
+                return bail!(
+                    named_field,
+                    "#[derive(ProjectSettings)] does not support flattening",
+                );
+            }
+
+            if let Some(notify_span) = export.notify.span() {
+                return bail!(
+                    notify_span,
+                    "#[derive(ProjectSettings)] does not support property change notification",
+                );
+            }
+        }
+
+        let field_name_str = field_name.to_string();
+        let name = match section {
+            Some(section) => {
+                quote! { ::std::concat!(#prefix, "/", #section, "/", #field_name_str) }
+            }
+            None => quote! { ::std::concat!(#prefix, "/", #field_name_str) },
+        };
+
+        settings.push(Setting {
+            field_name,
+            ty,
+            name,
+            default,
+            export,
+            advanced,
+            restart,
+        });
+    }
+
+    Ok(settings)
+}
+
+fn make_setting_info(setting: &Setting) -> TokenStream {
+    let Setting {
+        ty,
+        name,
+        default,
+        export,
+        advanced,
+        restart,
+        ..
+    } = setting;
+
+    // Usage flags are not relevant for project settings; Godot only reads type and hint.
+    let (hint, _usage_flags) = make_hint_and_usage_flags(
+        ty,
+        export.as_ref(),
+        FieldHint::Inferred,
+        UsageFlags::Inferred,
+    );
+    let basic = !advanced;
+
+    quote! {
+        ::godot::tools::ProjectSettingInfo {
+            default: {
+                let default: #ty = #default;
+                ::godot::meta::ToGodot::to_variant(&default)
+            },
+            property: ::godot::meta::PropertyInfo::new_var::<#ty>(#name).with_hint_info(#hint),
+            basic: #basic,
+            restart_if_changed: #restart,
+        }
+    }
+}
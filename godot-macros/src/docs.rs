@@ -365,12 +365,19 @@ fn format_method_xml(method: &FuncDefinition) -> Option<String> {
     let return_ty = signature.return_type.to_token_stream().to_string();
     let return_ty = xml_escape(return_ty);
 
+    // Default values of parameters are not listed: only their Rust expressions are known here, not the values as Godot would format them.
     let param_names_and_types = signature.param_idents.iter().zip(&signature.param_types);
     let params = format_params_xml(param_names_and_types);
 
+    let qualifiers_attr = if method.has_varargs {
+        r#" qualifiers="vararg""#
+    } else {
+        ""
+    };
+
     Some(format!(
         r#"
-<method name="{name}"{deprecated_attr}{experimental_attr}>
+<method name="{name}"{qualifiers_attr}{deprecated_attr}{experimental_attr}>
   <return type="{return_ty}" />
   {params}
   <description>
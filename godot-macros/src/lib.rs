@@ -139,6 +139,53 @@ use crate::util::{bail, ident, KvParser};
 /// }
 /// ```
 ///
+/// ## Inheriting Rust classes
+///
+/// The base can also be another class declared with `#[derive(GodotClass)]`, as long as it has an `init` constructor. Godot sees the
+/// real hierarchy, so `is_class()`, `ClassDB` queries and GDScript `extends` work as expected. Each level keeps its own Rust instance:
+/// `Gd<Derived>` can be upcast to `Gd<MyBase>`, and `bind()`/`bind_mut()` on either pointer accesses the respective struct.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node2D)]
+/// struct Enemy {
+///     #[var]
+///     health: i64,
+///     base: Base<Node2D>,
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Enemy)]
+/// struct Boss {
+///     phase: u8,
+///     base: Base<Enemy>,
+/// }
+/// ```
+///
+/// `#[func]`, `#[var]`, `#[signal]` and virtual methods of the base class are inherited. The derived class can implement the `I*`
+/// interface trait of the closest engine class (`INode2D` above). A virtual method overridden in the derived class replaces the one of
+/// the base class, like in GDScript; `OnReady` fields are still initialized on all levels. Rust base classes are initialized first.
+///
+/// A base class without `init` cannot construct its part of the object, so inheriting it fails to compile:
+///
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(no_init, base=Node)]
+/// struct Spawner {}
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Spawner)]
+/// struct EnemySpawner {}
+/// ```
+///
+/// Current limitations:
+/// - The base must be named by a single identifier (import it with `use` if needed).
+/// - If a Rust class has the same name as an engine class (e.g. `Timer`), then `base=Timer` refers to the engine class. Rename or alias
+///   the Rust class on import, e.g. `use crate::timer::Timer as MyTimer;`.
+/// - A derived class can only declare `#[signal]`s if its Rust base class declares signals, too.
+///
 ///
 /// # Properties and exports
 ///
@@ -327,6 +374,75 @@ use crate::util::{bail, ident, KvParser};
 /// }
 /// ```
 ///
+/// ## Flattened properties
+///
+/// Related properties can be grouped in a plain Rust struct deriving [`PropertyGroup`](derive.PropertyGroup.html). A field of that type,
+/// annotated with `#[var(flatten)]` or `#[export(flatten)]`, exposes each inner field as its own property, prefixed by the outer field
+/// name. In the editor, they appear in an inspector group. This requires Godot 4.2 or later.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(PropertyGroup, Default)]
+/// struct Movement {
+///     #[export(range = (0.0, 500.0))]
+///     speed: f32,
+///     accel: f32,
+/// }
+///
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct Player {
+///     // Registers the properties `movement/speed` and `movement/accel`.
+///     #[export(flatten)]
+///     movement: Movement,
+/// }
+/// ```
+///
+/// `flatten` cannot be combined with other keys; hints and usage flags are specified on the inner fields.
+///
+/// ## Change notification
+///
+/// Instead of writing a setter only to react to changes, `#[var]` and `#[export]` accept keys that make the generated setter notify.
+/// The setter compares the new value with the old one (requiring `PartialEq` on the property's `Via` type), and does nothing if they
+/// are equal. Otherwise, it stores the value and then:
+///
+/// - `notify = method` calls `self.method()`, e.g. to invoke `notify_property_list_changed()`.
+/// - `notify` emits the typed signal `{field}_changed(value)`, which must be declared with `#[signal]`.
+/// - `notify_signal = signal` emits the typed signal `signal(property: StringName)` with the property name, allowing a single
+///   class-wide signal for several properties.
+///
+/// `notify_signal` can be combined with either form of `notify`. The signal-based variants require Godot 4.2 or later.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init, base=Node)]
+/// struct Player {
+///     #[export(notify)]
+///     speed: f32,
+///
+///     #[var(notify = on_health_changed, notify_signal = stats_changed)]
+///     health: i32,
+///
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     #[signal]
+///     fn speed_changed(value: f32);
+///
+///     #[signal]
+///     fn stats_changed(property: StringName);
+///
+///     fn on_health_changed(&mut self) {
+///         godot_print!("health: {}", self.health);
+///     }
+/// }
+/// ```
+///
+/// Notification is only available for generated setters; custom setters can emit signals themselves.
+///
 /// # Further class customization
 ///
 /// ## Running code in the editor
@@ -339,6 +455,31 @@ use crate::util::{bail, ident, KvParser};
 ///
 /// This is very similar to [GDScript's `@tool` feature](https://docs.godotengine.org/en/stable/tutorials/plugins/running_code_in_the_editor.html).
 ///
+/// ## Runtime classes
+///
+/// Since Godot 4.3, classes can be marked as runtime classes with `#[class(runtime)]`. In the editor, such classes are replaced with
+/// placeholders: their properties can be edited, but none of their code (lifecycle methods, setters, etc.) runs. This is the default
+/// behavior for non-tool classes if [`ExtensionLibrary::editor_run_behavior()`](../init/trait.ExtensionLibrary.html#method.editor_run_behavior)
+/// returns `ToolClassesOnly`; the key enforces it independently of that setting. It cannot be combined with `#[class(tool)]`.
+///
+/// ## Abstract classes
+///
+/// Classes annotated with `#[class(abstract)]` cannot be instantiated through Godot (e.g. `ClassDB.instantiate()` or `.new()` in GDScript),
+/// and `ClassDB.can_instantiate()` returns `false` for them. Scripts and other classes can still extend them or use them as types.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, abstract, base=Node)]
+/// pub struct Weapon {
+///     damage: i32,
+///     base: Base<Node>,
+/// }
+/// ```
+///
+/// In contrast to `#[class(no_init)]`, abstract classes may have an init function. It is used whenever a Rust class inheriting from them
+/// is instantiated, and can also be invoked from Rust, e.g. via `Weapon::new_alloc()`.
+///
 /// ## Editor plugins
 ///
 /// If you annotate a class with `#[class(editor_plugin)]`, it will be turned into an editor plugin. The
@@ -377,6 +518,40 @@ use crate::util::{bail, ident, KvParser};
 ///
 /// These classes will appear in the Godot editor and GDScript as "AnimalToad" or "NpcToad".
 ///
+/// ## Generic classes
+///
+/// Godot has no notion of generics, so a generic struct cannot be registered as-is. Instead, you can list concrete instantiations
+/// with `#[class(instances(...))]`, each of which is registered as a separate Godot class under the given name. For every instance,
+/// a type alias with that name is declared alongside the struct, with the same visibility.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::meta::ArrayElement;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node, instances(Pool<i64> = "IntPool", Pool<Vector3> = "Vector3Pool"))]
+/// pub struct Pool<T: ArrayElement> {
+///     items: Array<T>,
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl<T: ArrayElement> Pool<T> {
+///     #[func]
+///     fn size(&self) -> i64 {
+///         self.items.len() as i64
+///     }
+/// }
+///
+/// fn make_pool() -> Gd<IntPool> {
+///     IntPool::new_alloc()
+/// }
+/// ```
+///
+/// `#[godot_api]` impl blocks (inherent and `I*` trait impls) may be generic, too. They are expanded once per instance, with generic
+/// parameters replaced by the instance's arguments; their methods are thus only available on the concrete types. Plain generic code
+/// can still live in a separate `impl` block without `#[godot_api]`. Such `#[godot_api]` blocks must appear after the struct in the
+/// same module, and `#[class(instances)]` cannot be combined with `#[class(rename)]`.
+///
 /// ## Class hiding
 ///
 /// If you want to register a class with Godot, but not have it show up in the editor then you can use `#[class(internal)]`.
@@ -511,6 +686,9 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// - [Lifecycle functions](#lifecycle-functions)
 /// - [User-defined functions](#user-defined-functions)
 ///   - [Associated functions and methods](#associated-functions-and-methods)
+///   - [Default parameters](#default-parameters)
+///   - [Variadic functions](#variadic-functions)
+///   - [Async functions](#async-functions)
 ///   - [Virtual methods](#virtual-methods)
 ///   - [RPC attributes](#rpc-attributes)
 /// - [Signals](#signals)
@@ -636,6 +814,110 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ## Default parameters
+///
+/// Trailing parameters of a `#[func]` can be given a default value with `#[opt(default = expr)]`. When the function is called from
+/// GDScript or through `Object::call()` with fewer arguments, the missing ones are filled in with these values. Default values are
+/// also registered with Godot, so they show up in the generated documentation and in `ClassDB` method info.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct MyStruct {
+///     base: Base<RefCounted>,
+/// }
+///
+/// #[godot_api]
+/// impl MyStruct {
+///     #[func]
+///     fn greet(
+///         &self,
+///         name: GString,
+///         #[opt(default = 1)] times: i32,
+///         #[opt(default = GString::from("!"))] suffix: GString,
+///     ) -> GString {
+///         // GDScript: greet("Rust") == greet("Rust", 1, "!")
+///         GString::from(format!("Hello {name}{suffix}").repeat(times as usize))
+///     }
+/// }
+/// ```
+///
+/// The expression must have the parameter's type, and cannot refer to `self` or other parameters. It is evaluated at registration time
+/// and whenever a call omits arguments. Parameters without default may not follow ones that have one.
+///
+/// Calls from Rust are unaffected: Rust has no default arguments, so all parameters must still be passed.
+///
+/// ## Variadic functions
+///
+/// If the last parameter of a `#[func]` has type `&[Variant]`, the function accepts any number of additional arguments, like the engine's
+/// `call()` or `emit_signal()`. Regular parameters must still be provided; all arguments after them are passed in the slice.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct MyStruct {
+///     base: Base<RefCounted>,
+/// }
+///
+/// #[godot_api]
+/// impl MyStruct {
+///     #[func]
+///     fn log(&self, prefix: GString, args: &[Variant]) {
+///         // GDScript: log("values:", 1, Vector2.ZERO, "text")
+///         godot_print!("{prefix} {args:?}");
+///     }
+/// }
+/// ```
+///
+/// Such functions are registered with the `VARARG` method flag and can only be invoked through varcalls, i.e. dynamic calls such as
+/// `Object::call()` or GDScript calls. `#[func(virtual)]` cannot be combined with variadic parameters.
+///
+/// ## Async functions
+///
+/// `#[func]` can be declared as `async fn` (Godot 4.2+). When invoked from Godot, the returned future is spawned on the
+/// [`godot::task`](../task/index.html) runtime, and the caller immediately receives a [`Signal`]. The signal is emitted once with the
+/// function's return value (converted via `ToGodot`), so GDScript can simply `await` the call.
+///
+/// The future must be `'static`, thus async functions cannot take `&self` or `&mut self`. Use either associated functions, or
+/// `#[func(gd_self)]` with an explicit `Gd<Self>` parameter. In the latter case, the task is canceled when the object is freed, and the
+/// signal is never emitted. For reference-counted classes, the future's own `Gd<Self>` does not keep the object alive: once all other
+/// references are gone, the task is canceled in a deferred call, which releases the object. If several such tasks are pending, this only
+/// happens once all but one of them have completed.
+///
+/// ```no_run
+/// # #[cfg(since_api = "4.2")]
+/// # mod conditional {
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Level {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Level {
+///     #[signal]
+///     fn loaded(path: GString);
+///
+///     #[func(gd_self)]
+///     async fn load_level(this: Gd<Self>, path: GString) -> bool {
+///         let signal = Signal::from_object_signal(&this, "loaded");
+///         let (loaded_path,): (GString,) = signal.to_future().await;
+///
+///         loaded_path == path
+///     }
+/// }
+/// # }
+/// ```
+///
+/// ```gdscript
+/// var success = await level.load_level("res://level.tscn")
+/// ```
+///
+/// [`Signal`]: ../builtin/struct.Signal.html
+///
 /// ## Virtual methods
 ///
 /// Functions with the `#[func(virtual)]` attribute are virtual functions, meaning attached scripts can override them.
@@ -750,10 +1032,39 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ### Sending RPCs
+///
+/// Classes with at least one `#[rpc]` method provide a typed RPC collection, accessible via `self.rpcs()` or `Gd::rpcs()`. It has one
+/// method per RPC, with the same name and parameters, which returns an [`RpcCall`]. The call is then sent with `to_all()` or `to_peer(id)`.
+/// Compared to `Node::rpc()`, argument types are checked at compile time and method names cannot be misspelled.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init, base=Node)]
+/// # struct Player {
+/// #     base: Base<Node>,
+/// # }
+/// #[godot_api]
+/// impl Player {
+///     #[rpc(any_peer, reliable)]
+///     fn take_damage(&mut self, amount: i32) {}
+///
+///     #[func]
+///     fn attack(&mut self, target_peer: i64) {
+///         # #[cfg(feature = "codegen-full")] {
+///         // Instead of: self.base_mut().rpc_id(target_peer, "take_damage", &[5.to_variant()]);
+///         self.rpcs().take_damage(5).to_peer(target_peer);
+///         # }
+///     }
+/// }
+/// ```
+///
 // Note: for some reason, the intra-doc links don't work here, despite dev-dependency on godot.
 /// [`RpcMode`]: ../classes/multiplayer_api/struct.RpcMode.html
 /// [`TransferMode`]: ../classes/multiplayer_peer/struct.TransferMode.html
 /// [`RpcConfig`]: ../register/struct.RpcConfig.html
+/// [`RpcCall`]: ../register/struct.RpcCall.html
 ///
 ///
 /// # Signals
@@ -794,6 +1105,8 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 ///
 /// Just like with regular structs, you can have multiple inherent `impl` blocks. This can be useful for code organization or when you want to generate code from a proc-macro.
 /// For implementation reasons, all but one `impl` blocks must have the key `secondary`. There is no difference between implementing all functions in one block or splitting them up between multiple blocks.
+/// The only exceptions are `#[signal]` declarations, which must be placed in the primary block, and `#[rpc]` methods, which are only
+/// configured as RPCs in the primary block (in secondary blocks, they are deprecated and registered as plain `#[func]`s).
 /// ```no_run
 /// # use godot::prelude::*;
 /// # #[derive(GodotClass)]
@@ -813,6 +1126,12 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 ///     pub fn two(&self) { }
 /// }
 /// ```
+///
+/// # Script instances
+///
+/// With the key `script_instance`, the `impl` block declares the `#[func]` methods of a type using
+/// [`#[derive(ScriptInstance)]`](derive.ScriptInstance.html) instead of a class. Signals, constants, RPCs as well as virtual, variadic and
+/// async functions are not available there.
 #[doc(
     alias = "func",
     alias = "rpc",
@@ -820,7 +1139,8 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
     alias = "signal",
     alias = "constant",
     alias = "rename",
-    alias = "secondary"
+    alias = "secondary",
+    alias = "script_instance"
 )]
 #[proc_macro_attribute]
 pub fn godot_api(meta: TokenStream, input: TokenStream) -> TokenStream {
@@ -876,6 +1196,175 @@ pub fn godot_dyn(_meta: TokenStream, input: TokenStream) -> TokenStream {
     translate(input, class::attribute_godot_dyn)
 }
 
+/// Generates a typed proxy for calling dynamically implemented methods, e.g. those of GDScript objects.
+///
+/// This attribute macro is applied to a trait declaration. It keeps the trait unchanged and additionally generates a struct
+/// `{Trait}Proxy`, which wraps a `Gd<Object>` and implements the trait by calling each method by name. Arguments are converted with
+/// [`ToGodot`](../meta/trait.ToGodot.html), return values with [`FromGodot`](../meta/trait.FromGodot.html).
+///
+/// Proxies are constructed through the [`GodotProxy`](../obj/trait.GodotProxy.html) trait. `try_from_object()` checks that the object
+/// has every method of the trait, with a compatible number of parameters, and otherwise returns a [`ProxyError`](../meta/error/struct.ProxyError.html)
+/// listing all missing or mismatched methods.
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[godot_proxy]
+/// trait EnemyApi {
+///     fn take_damage(&mut self, amount: i32) -> bool;
+///     fn get_health(&self) -> i32;
+/// }
+///
+/// fn hit(enemy: Gd<Node>) {
+///     let mut enemy = EnemyApiProxy::try_from_object(enemy).expect("enemy script");
+///     if enemy.take_damage(10) {
+///         godot_print!("enemy died");
+///     }
+/// }
+/// ```
+///
+/// # Error handling
+/// By default, a failing call or return value conversion panics, just like [`Object::call()`](../classes/struct.Object.html#method.call).
+/// Methods declared to return `Result<T, CallError>` use [`Object::try_call()`](../classes/struct.Object.html#method.try_call) instead and
+/// return such errors.
+///
+/// # Limitations
+/// - All methods must take `&self` or `&mut self`; calls always go through a shared `Gd` handle.
+/// - Methods cannot be generic or have default implementations.
+/// - The trait cannot have generic parameters, supertraits, associated types or constants.
+/// - Method names are used as-is for the dynamic call.
+#[proc_macro_attribute]
+pub fn godot_proxy(_meta: TokenStream, input: TokenStream) -> TokenStream {
+    translate(input, class::attribute_godot_proxy)
+}
+
+/// Derive macro for [`PropertyGroup`](../register/property/trait.PropertyGroup.html) on structs.
+///
+/// Allows the struct to be used in `#[var(flatten)]` and `#[export(flatten)]` fields of a [`GodotClass`](derive.GodotClass.html); see
+/// [Flattened properties](derive.GodotClass.html#flattened-properties).
+///
+/// Every field of the struct becomes a property. Fields may carry `#[var(...)]` and `#[export(...)]` attributes with the same keys as in
+/// classes, e.g. for hints or custom getters/setters. Fields without `#[export]` or explicit usage flags are visible in the editor if the
+/// enclosing field is `#[export(flatten)]`.
+#[proc_macro_derive(PropertyGroup, attributes(var, export))]
+pub fn derive_property_group(input: TokenStream) -> TokenStream {
+    translate(input, class::derive_property_group)
+}
+
+/// Derive macro for [`SceneNodes`](../obj/trait.SceneNodes.html) on structs.
+///
+/// Binds nodes of a scene to typed fields, resolving all paths at once. Each field annotated with `#[node("path")]` must have type
+/// `Gd<T>` (required node) or `Option<Gd<T>>` (optional node), where `T` inherits `Node`. Paths are relative to the node passed to
+/// [`SceneNodes::from_node()`](../obj/trait.SceneNodes.html#method.from_node). Fields without `#[node]` are initialized with
+/// `Default::default()`.
+///
+/// If any node is missing or has an incompatible class, a single error lists all such paths.
+///
+/// In a class, an `OnReady<T>` field annotated with `#[init(scene_nodes)]` is bound relative to the base node, before `ready()`.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::{Control, Label, ProgressBar};
+///
+/// #[derive(SceneNodes)]
+/// struct HudNodes {
+///     #[node("Top/Health")]
+///     health: Gd<ProgressBar>,
+///
+///     #[node("Top/Score")]
+///     score: Gd<Label>,
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Control)]
+/// struct Hud {
+///     #[init(scene_nodes)]
+///     nodes: OnReady<HudNodes>,
+///     base: Base<Control>,
+/// }
+///
+/// #[godot_api]
+/// impl IControl for Hud {
+///     fn ready(&mut self) {
+///         self.nodes.score.set_text("0");
+///     }
+/// }
+/// ```
+///
+/// # Checking against a scene file
+/// With `#[scene_nodes(tscn = "path/to/hud.tscn")]` on the struct, paths are additionally verified at compile time against the given
+/// scene, relative to the crate's `Cargo.toml` directory. Paths of `Gd<T>` fields that do not exist in the scene cause a compile error.
+///
+/// With `#[scene_nodes(tscn = "...", check_types)]`, the classes of these nodes are verified as well: if the scene specifies a node's class,
+/// it must inherit the declared class. Classes are looked up in the scope of the struct, so all classes used in the scene for such nodes
+/// must be imported, including engine classes.
+///
+/// Paths starting with `%` (unique names) or `/`, paths containing `..` and paths into instanced sub-scenes are only checked at runtime.
+#[proc_macro_derive(SceneNodes, attributes(node, scene_nodes))]
+pub fn derive_scene_nodes(input: TokenStream) -> TokenStream {
+    translate(input, class::derive_scene_nodes)
+}
+
+/// Derive macro for [`ScriptInstance`](../obj/script/trait.ScriptInstance.html) on structs.
+///
+/// Implementing `ScriptInstance` by hand means string-matching property and method names in a dozen functions. This derive generates
+/// them from the same attributes that are used in classes:
+/// - Fields annotated with `#[var]` or `#[export]` become script properties, with the same keys (`get`, `set`, `hint`, `usage_flags`, ...).
+/// - Methods annotated with `#[func]` inside a `#[godot_api(script_instance)]` impl block become script methods. Parameters can have
+///   default values with `#[opt(default = ...)]`. Without such a block, the script instance has no methods.
+///
+/// The script and language of the instance are provided by implementing
+/// [`ScriptInstanceSource`](../obj/script/trait.ScriptInstanceSource.html) by hand.
+///
+/// # Struct attributes
+/// The optional `#[script_instance]` attribute accepts the following keys:
+/// - `base = Class`: the engine class which the script can be attached to, as `ScriptInstance::Base`. Default is `Object`.
+/// - `class_name = "Name"`: the name returned by `ScriptInstance::class_name()`. Default is the name of the struct.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::{Script, ScriptLanguage};
+/// use godot::obj::script::ScriptInstanceSource;
+/// use godot::register::ScriptInstance;
+///
+/// #[derive(ScriptInstance)]
+/// #[script_instance(base = Node)]
+/// struct Enemy {
+///     #[var]
+///     health: i64,
+///
+///     #[export(range = (0.0, 10.0))]
+///     speed: f64,
+///
+///     script: Gd<Script>,
+///     language: Gd<ScriptLanguage>,
+/// }
+///
+/// #[godot_api(script_instance)]
+/// impl Enemy {
+///     #[func]
+///     fn damage(&mut self, amount: i64, #[opt(default = 1)] multiplier: i64) -> bool {
+///         self.health -= amount * multiplier;
+///         self.health <= 0
+///     }
+/// }
+///
+/// impl ScriptInstanceSource for Enemy {
+///     fn get_script(&self) -> &Gd<Script> {
+///         &self.script
+///     }
+///
+///     fn get_language(&self) -> Gd<ScriptLanguage> {
+///         self.language.clone()
+///     }
+/// }
+/// ```
+#[proc_macro_derive(ScriptInstance, attributes(script_instance, var, export))]
+pub fn derive_script_instance(input: TokenStream) -> TokenStream {
+    translate(input, class::derive_script_instance)
+}
+
 /// Derive macro for [`GodotConvert`](../meta/trait.GodotConvert.html) on structs.
 ///
 /// This derive macro also derives [`ToGodot`](../meta/trait.ToGodot.html) and [`FromGodot`](../meta/trait.FromGodot.html).
@@ -1024,6 +1513,86 @@ pub fn derive_export(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_export)
 }
 
+/// Derive macro for [`InputActions`](../tools/trait.InputActions.html) on enums.
+///
+/// Each variant corresponds to an input action. By default, the action name is the variant name in `snake_case`, e.g. `MoveLeft`
+/// becomes `"move_left"`. Variants must not have fields.
+///
+/// The optional `#[action(...)]` attribute on variants accepts the following keys:
+/// - `name = "..."`: custom action name, e.g. for built-in actions like `"ui_accept"`.
+/// - `keys = [SPACE, W]`: default key bindings, as constants of [`Key`](../global/struct.Key.html) (physical keycodes).
+/// - `mouse_buttons = [LEFT]`: default mouse bindings, as constants of [`MouseButton`](../global/struct.MouseButton.html).
+/// - `joy_buttons = [A]`: default gamepad bindings, as constants of [`JoyButton`](../global/struct.JoyButton.html).
+///
+/// Default bindings are only used by [`InputActions::register_default_bindings()`](../tools/trait.InputActions.html#method.register_default_bindings).
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::InputActions;
+///
+/// #[derive(InputActions, Copy, Clone, Eq, PartialEq, Debug)]
+/// enum Action {
+///     #[action(keys = [SPACE], joy_buttons = [A])]
+///     Jump,
+///     #[action(keys = [A, LEFT])]
+///     MoveLeft,
+///     #[action(name = "ui_cancel")]
+///     Pause,
+/// }
+///
+/// fn on_startup() {
+///     Action::register_default_bindings();
+///     if let Err(err) = Action::verify_input_map() {
+///         godot_error!("{err}");
+///     }
+/// }
+/// ```
+#[proc_macro_derive(InputActions, attributes(action))]
+pub fn derive_input_actions(input: TokenStream) -> TokenStream {
+    translate(input, derive::derive_input_actions)
+}
+
+/// Derive macro for [`TypedProjectSettings`](../tools/trait.TypedProjectSettings.html) on structs.
+///
+/// Every field of the struct is registered as a project setting named `prefix/section/field`, when the `Scene` init level is loaded.
+/// Fields must implement [`Var`](../register/property/trait.Var.html) and be convertible from/to `Variant`.
+///
+/// The `#[settings(...)]` attribute on the struct is required and accepts the following keys:
+/// - `prefix = "..."`: first part of all setting names, usually the plugin name.
+/// - `section = "..."`: default section for all fields. Without a section, settings are named `prefix/field`.
+///
+/// The optional `#[setting(...)]` attribute on fields accepts the following keys:
+/// - `section = "..."`: section of this field, overriding the one on the struct.
+/// - `default = expr`: default value. Without it, the field type's `Default` implementation is used.
+/// - `advanced`: only show the setting if _Advanced Settings_ is enabled in the editor.
+/// - `restart`: ask the user to restart the editor when the setting is changed.
+///
+/// Fields may additionally carry an `#[export(...)]` attribute with the same keys as in classes, e.g. `#[export(range = (0.0, 10.0))]`
+/// or `#[export(enum = (Low, High))]`, to customize the editor UI of the setting.
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(ProjectSettings)]
+/// #[settings(prefix = "my_plugin", section = "general")]
+/// struct MyPluginSettings {
+///     #[setting(default = 4.5)]
+///     #[export(range = (0.0, 10.0, or_greater))]
+///     max_speed: f32,
+///
+///     #[setting(section = "debug", advanced)]
+///     draw_paths: bool,
+/// }
+///
+/// fn ready() {
+///     let settings = MyPluginSettings::load();
+/// }
+/// ```
+#[proc_macro_derive(ProjectSettings, attributes(settings, setting, export))]
+pub fn derive_project_settings(input: TokenStream) -> TokenStream {
+    translate(input, derive::derive_project_settings)
+}
+
 /// Similar to `#[test]`, but runs an integration test with Godot.
 ///
 /// Transforms the `fn` into one returning `bool` (success of the test), which must be called explicitly.
@@ -198,6 +198,11 @@ impl KvParser {
             .ok_or_else(|| error!(self.span, "missing required argument `{key} = expression`",))
     }
 
+    /// Whether all keys have been handled.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
     /// Explicit "pre-destructor" that must be called, and checks that all map entries have been
     /// consumed.
     // We used to check in a `Drop` impl that `finish` has actually been called, but that turns out
@@ -383,6 +388,17 @@ impl<'a> ParserState<'a> {
                 self.next();
                 Some(self.parse_value()?)
             }
+            // Parenthesized list following key, e.g. `key(a, b)`
+            Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+                self.next();
+                if let Some(tt) = self.cur {
+                    if !is_punct(tt, ',') {
+                        return bail!(tt, "expected `,` after `{key}(...)`");
+                    }
+                    self.next();
+                }
+                Some(KvValue::new(vec![TokenTree::Group(group.clone())]))
+            }
             Some(tt) => {
                 let parens_hint = if prev_expr_complex {
                     let attr = &self.attr_name;
@@ -588,4 +604,18 @@ mod tests {
             ),
         );
     }
+
+    #[test]
+    fn test_parse_kv_nested_list() {
+        expect_parsed(
+            quote! {
+                #[attr(first, list(Pool<i64> = "IntPool", Pool<f64> = "FloatPool"), last = qux)]
+            },
+            kv_map!(
+                first => None,
+                list => Some(kv_value!((Pool<i64> = "IntPool", Pool<f64> = "FloatPool"))),
+                last => Some(kv_value!(qux)),
+            ),
+        );
+    }
 }
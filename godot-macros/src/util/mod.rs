@@ -12,6 +12,7 @@ use crate::ParseResult;
 use proc_macro2::{Delimiter, Group, Ident, Literal, Punct, Spacing, TokenStream, TokenTree};
 use quote::spanned::Spanned;
 use quote::{format_ident, quote, ToTokens, TokenStreamExt};
+use std::collections::HashMap;
 
 mod kv_parser;
 mod list_parser;
@@ -300,6 +301,183 @@ pub fn safe_ident(s: &str) -> Ident {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Generic classes
+
+/// Concrete instantiation of a generic class, declared as `Type<Args> = "Name"` in `#[class(instances(...))]`.
+pub(crate) struct ClassInstance {
+    /// Godot class name, also used as name of the Rust type alias.
+    pub name: Ident,
+
+    /// Concrete type, e.g. `Pool<i64>`.
+    pub ty: venial::TypeExpr,
+}
+
+impl ClassInstance {
+    /// Maps the generic parameters `params` (in declaration order) to the generic arguments of this instance.
+    pub fn map_generic_args(&self, params: &[Ident]) -> ParseResult<HashMap<Ident, TokenStream>> {
+        let args = match extract_typename(&self.ty).and_then(|seg| seg.generic_args) {
+            Some(list) => list
+                .args
+                .iter()
+                .map(|(arg, _punct)| arg.to_token_stream())
+                .collect(),
+            None => vec![],
+        };
+
+        if args.len() != params.len() {
+            return bail!(
+                &self.ty,
+                "instance `{}` must specify {} generic argument(s), found {}",
+                self.name,
+                params.len(),
+                args.len()
+            );
+        }
+
+        Ok(params.iter().cloned().zip(args).collect())
+    }
+}
+
+/// Parses the contents of `instances(...)`, i.e. a comma-separated list of `Type<Args> = "Name"` entries.
+pub(crate) fn parse_class_instances(list: TokenTree) -> ParseResult<Vec<ClassInstance>> {
+    let TokenTree::Group(group) = list else {
+        return bail!(
+            list,
+            "expected list of instances, e.g. `instances(Pool<i64> = \"IntPool\")`"
+        );
+    };
+
+    let mut instances = vec![];
+    for entry in split_top_level(group.stream(), ',') {
+        if entry.is_empty() {
+            continue; // Trailing comma.
+        }
+
+        let mut parts = split_top_level(entry.iter().cloned().collect(), '=');
+        let (ty, name) = match (parts.pop(), parts.pop(), parts.is_empty()) {
+            (Some(name), Some(ty), true) if !ty.is_empty() => (ty, name),
+            _ => {
+                let entry = entry.into_iter().collect::<TokenStream>();
+                return bail!(
+                    entry,
+                    "expected instance of the form `Type<Args> = \"Name\"`"
+                );
+            }
+        };
+
+        let name = match name.as_slice() {
+            [TokenTree::Literal(lit)] => parse_instance_name(lit)?,
+            _ => {
+                let name = name.into_iter().collect::<TokenStream>();
+                return bail!(name, "instance name must be a string literal");
+            }
+        };
+
+        instances.push(ClassInstance {
+            name,
+            ty: venial::TypeExpr { tokens: ty },
+        });
+    }
+
+    if instances.is_empty() {
+        return bail!(group, "#[class(instances)] requires at least one instance");
+    }
+
+    Ok(instances)
+}
+
+/// Each instance is also declared as type alias, so its name must be a valid identifier.
+fn parse_instance_name(lit: &Literal) -> ParseResult<Ident> {
+    let text = lit.to_string();
+    let name = text
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .unwrap_or_default();
+
+    let mut chars = name.chars();
+    let is_ident = chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_');
+
+    if !is_ident {
+        return bail!(
+            lit,
+            "instance name must be a string literal containing a valid identifier"
+        );
+    }
+
+    Ok(Ident::new(name, lit.span()))
+}
+
+/// Splits `tokens` at each occurrence of `separator` which is not nested inside `<...>`.
+fn split_top_level(tokens: TokenStream, separator: char) -> Vec<Vec<TokenTree>> {
+    let mut result = vec![vec![]];
+    let mut depth = 0usize;
+    let mut prev_is_minus = false;
+
+    for tt in tokens {
+        if let TokenTree::Punct(punct) = &tt {
+            match punct.as_char() {
+                '<' => depth += 1,
+                '>' if !prev_is_minus => depth = depth.saturating_sub(1), // Skip `->`.
+                c if c == separator && depth == 0 => {
+                    result.push(vec![]);
+                    prev_is_minus = false;
+                    continue;
+                }
+                _ => {}
+            }
+        }
+
+        prev_is_minus = matches!(&tt, TokenTree::Punct(p) if p.as_char() == '-' && p.spacing() == Spacing::Joint);
+        result.last_mut().unwrap().push(tt);
+    }
+
+    result
+}
+
+/// Replaces identifiers by tokens, e.g. generic parameters by concrete types.
+///
+/// Identifiers following `::` or `.` are not replaced, as they refer to path items or fields rather than generic parameters.
+pub(crate) fn substitute_idents(
+    tokens: TokenStream,
+    replacements: &HashMap<Ident, TokenStream>,
+) -> TokenStream {
+    let mut result = TokenStream::new();
+    let mut prev_is_accessor = false;
+    let mut prev_is_joint_colon = false;
+
+    for tt in tokens {
+        let (is_accessor, is_joint_colon) = match &tt {
+            TokenTree::Punct(p) if p.as_char() == '.' => (true, false),
+            TokenTree::Punct(p) if p.as_char() == ':' => (
+                prev_is_joint_colon, // Second colon of `::`.
+                p.spacing() == Spacing::Joint,
+            ),
+            _ => (false, false),
+        };
+
+        match tt {
+            TokenTree::Ident(ident) if !prev_is_accessor => match replacements.get(&ident) {
+                Some(replacement) => result.extend(replacement.clone()),
+                None => result.append(ident),
+            },
+            TokenTree::Group(group) => {
+                let stream = substitute_idents(group.stream(), replacements);
+                let mut new_group = Group::new(group.delimiter(), stream);
+                new_group.set_span(group.span());
+                result.append(new_group);
+            }
+            tt => result.append(tt),
+        }
+
+        prev_is_accessor = is_accessor;
+        prev_is_joint_colon = is_joint_colon;
+    }
+
+    result
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 /// Parses a `meta` TokenStream, that is, the tokens in parameter position of a proc-macro (between the braces).
@@ -412,3 +590,8 @@ pub fn format_funcs_collection_struct(class_name: &Ident) -> Ident {
 pub fn format_class_visibility_macro(class_name: &Ident) -> Ident {
     format_ident!("__godot_{class_name}_vis_macro")
 }
+
+/// Returns the name of the macro used to forward the instances of a generic class to `#[godot_api]` impl blocks.
+pub fn format_class_instances_macro(class_name: &Ident) -> Ident {
+    format_ident!("__godot_{class_name}_instances")
+}
@@ -0,0 +1,777 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Extraction of [`tr!`] and [`tr_n!`] strings of [**godot-rust**](https://godot-rust.github.io) projects into gettext POT templates.
+//!
+//! This crate has no dependencies and does not link to Godot, so it can be used from build scripts without compiling the `godot` crate
+//! a second time. See [`TranslationTemplate`] for usage.
+//!
+//! [`tr!`]: https://docs.rs/godot/latest/godot/tools/macro.tr.html
+//! [`tr_n!`]: https://docs.rs/godot/latest/godot/tools/macro.tr_n.html
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::io;
+use std::path::Path;
+
+/// Collects the strings of all [`tr!`] and [`tr_n!`] invocations in Rust sources, and writes them as gettext template (`.pot` file).
+///
+/// Godot's own POT generation only sees scenes and scripts, not Rust code. This type fills the gap: it scans source files without
+/// compiling them, so it does not need a running engine and can be used from a build script, a test or a small binary. Each entry contains
+/// the message, its plural form (for `tr_n!`), its context (if given as string literal) and the source locations where it is used.
+/// Identical messages with the same context are merged into one entry.
+///
+/// The resulting template is then translated into `.po` files with the usual gettext tools (Poedit, `msginit`/`msgmerge`, ...), which
+/// can be added to Godot's localization settings. If the project also uses Godot's POT generation, both templates can be combined with
+/// `msgcat`.
+///
+/// # Example
+/// In `build.rs`, with `godot-translation-template` listed under `[build-dependencies]`:
+/// ```no_run
+/// use godot_translation_template::TranslationTemplate;
+///
+/// fn main() {
+///     println!("cargo:rerun-if-changed=src");
+///
+///     let mut template = TranslationTemplate::new();
+///     template.add_dir("src").expect("read sources");
+///
+///     for warning in template.warnings() {
+///         println!("cargo:warning={warning}");
+///     }
+///
+///     template.write_pot("../godot/translations/rust.pot").expect("write template");
+/// }
+/// ```
+///
+/// # Placeholders
+/// The macros currently substitute format placeholders _before_ looking up the translation. The looked-up text thus depends on the
+/// arguments, and can never match a template entry. Such messages are therefore not extracted, but reported as [warnings](Self::warnings).
+/// Escaped braces (`{{` and `}}`) are no placeholders; they are extracted as `{` and `}`.
+///
+/// # Limitations
+/// Sources are scanned token by token, without macro expansion. Invocations produced by other macros are therefore not found, and
+/// contexts must be string literals to be extracted. Invocations that cannot be extracted are reported as [warnings](Self::warnings).
+///
+/// [`tr!`]: https://docs.rs/godot/latest/godot/tools/macro.tr.html
+/// [`tr_n!`]: https://docs.rs/godot/latest/godot/tools/macro.tr_n.html
+#[derive(Debug, Default)]
+pub struct TranslationTemplate {
+    entries: Vec<TemplateEntry>,
+    index: HashMap<(Option<String>, String), usize>,
+    warnings: Vec<String>,
+}
+
+impl TranslationTemplate {
+    /// Creates an empty template.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Scans all `.rs` files in `dir` and its subdirectories.
+    ///
+    /// Files are visited in alphabetical order, so the output is deterministic. Hidden directories and `target` directories are skipped.
+    /// Locations are recorded relative to the path as passed, e.g. `src/player.rs:12` for `add_dir("src")`.
+    pub fn add_dir(&mut self, dir: impl AsRef<Path>) -> io::Result<()> {
+        let mut paths = std::fs::read_dir(dir.as_ref())?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<io::Result<Vec<_>>>()?;
+        paths.sort();
+
+        for path in paths {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+
+            if path.is_dir() {
+                if !name.starts_with('.') && name != "target" {
+                    self.add_dir(&path)?;
+                }
+            } else if path.extension().is_some_and(|ext| ext == "rs") {
+                self.add_file(&path)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Scans a single Rust source file.
+    pub fn add_file(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path)?;
+
+        // Forward slashes, so templates do not differ between platforms.
+        let file_name = path.to_string_lossy().replace('\\', "/");
+        self.add_source(&file_name, &source);
+
+        Ok(())
+    }
+
+    /// Scans Rust source code from memory. `file_name` is only used for source locations and warnings.
+    pub fn add_source(&mut self, file_name: &str, source: &str) {
+        let tokens = tokenize(source);
+
+        for (i, window) in tokens.windows(3).enumerate() {
+            let [name, bang, open] = window else {
+                continue;
+            };
+
+            let is_plural = match &name.kind {
+                TokenKind::Ident(ident) if ident == "tr" => false,
+                TokenKind::Ident(ident) if ident == "tr_n" => true,
+                _ => continue,
+            };
+
+            if bang.kind != TokenKind::Punct('!')
+                || !matches!(open.kind, TokenKind::Punct('(' | '[' | '{'))
+            {
+                continue;
+            }
+
+            let location = format!("{file_name}:{}", name.line);
+            let Some(args) = invocation_args(&tokens[i + 3..]) else {
+                self.warn(&location, "unterminated macro invocation");
+                continue;
+            };
+
+            let parsed = if is_plural {
+                parse_tr_n(args)
+            } else {
+                parse_tr(args)
+            };
+
+            match parsed {
+                Ok(message) => self.add_message(message, location),
+                Err(reason) => self.warn(&location, reason),
+            }
+        }
+    }
+
+    /// Number of distinct messages.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no messages have been found.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Invocations that could not be extracted, in the form `file:line: reason`.
+    pub fn warnings(&self) -> impl Iterator<Item = &str> {
+        self.warnings.iter().map(String::as_str)
+    }
+
+    /// Renders the template in gettext POT format.
+    pub fn to_pot(&self) -> String {
+        let mut out = String::new();
+        out.push_str(
+            "# Translation template for Rust code, extracted from tr!() and tr_n!() invocations.\n",
+        );
+        out.push_str("#\n");
+        out.push_str("#, fuzzy\n");
+        out.push_str("msgid \"\"\n");
+        out.push_str("msgstr \"\"\n");
+        out.push_str("\"Project-Id-Version: \\n\"\n");
+        out.push_str("\"MIME-Version: 1.0\\n\"\n");
+        out.push_str("\"Content-Type: text/plain; charset=UTF-8\\n\"\n");
+        out.push_str("\"Content-Transfer-Encoding: 8bit\\n\"\n");
+        out.push_str("\"Plural-Forms: nplurals=INTEGER; plural=EXPRESSION;\\n\"\n");
+
+        for entry in &self.entries {
+            out.push('\n');
+            for location in &entry.locations {
+                let _ = writeln!(out, "#: {location}");
+            }
+
+            if let Some(context) = &entry.context {
+                write_po_string(&mut out, "msgctxt", context);
+            }
+            write_po_string(&mut out, "msgid", &entry.msgid);
+
+            match &entry.plural {
+                Some(plural) => {
+                    write_po_string(&mut out, "msgid_plural", plural);
+                    out.push_str("msgstr[0] \"\"\n");
+                    out.push_str("msgstr[1] \"\"\n");
+                }
+                None => out.push_str("msgstr \"\"\n"),
+            }
+        }
+
+        out
+    }
+
+    /// Writes the template to a `.pot` file. Parent directories are created if necessary.
+    pub fn write_pot(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        std::fs::write(path, self.to_pot())
+    }
+
+    fn add_message(&mut self, message: Message, location: String) {
+        let key = (message.context.clone(), message.msgid.clone());
+
+        if let Some(&index) = self.index.get(&key) {
+            let entry = &mut self.entries[index];
+            if entry.plural != message.plural {
+                let reason = format!(
+                    "message {:?} is used with different plural forms; keeping the first one",
+                    message.msgid
+                );
+                self.warn(&location, reason);
+                return;
+            }
+
+            entry.locations.push(location);
+            return;
+        }
+
+        self.index.insert(key, self.entries.len());
+        self.entries.push(TemplateEntry {
+            context: message.context,
+            msgid: message.msgid,
+            plural: message.plural,
+            locations: vec![location],
+        });
+    }
+
+    fn warn(&mut self, location: &str, reason: impl std::fmt::Display) {
+        self.warnings.push(format!("{location}: {reason}"));
+    }
+}
+
+#[derive(Debug)]
+struct TemplateEntry {
+    context: Option<String>,
+    msgid: String,
+    plural: Option<String>,
+    locations: Vec<String>,
+}
+
+/// Message extracted from a single invocation.
+struct Message {
+    context: Option<String>,
+    msgid: String,
+    plural: Option<String>,
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Invocation parsing
+
+const PLACEHOLDER_WARNING: &str =
+    "message has format placeholders, which are substituted before the translation lookup; it cannot be translated and is skipped";
+
+/// Parses the arguments of `tr!(context; "fmt", args)` or `tr!("fmt", args)`.
+fn parse_tr(args: &[Token]) -> Result<Message, &'static str> {
+    let (context, body) = match split_top_level(args, ';').as_slice() {
+        [body] => (None, *body),
+        [context, body] => (Some(literal_context(context)?), *body),
+        _ => return Err("expected `tr!(\"format\", ...)` or `tr!(context; \"format\", ...)`"),
+    };
+
+    let parts = split_top_level(body, ',');
+    let Some(fmt) = parts.first().and_then(|part| string_literal(part)) else {
+        return Err("format string must be a string literal");
+    };
+
+    if has_placeholders(fmt) {
+        return Err(PLACEHOLDER_WARNING);
+    }
+
+    Ok(Message {
+        context,
+        msgid: format_message(fmt),
+        plural: None,
+    })
+}
+
+/// Parses the arguments of `tr_n!(n, context; "singular", "plural", args)` or `tr_n!(n; "singular", "plural", args)`.
+fn parse_tr_n(args: &[Token]) -> Result<Message, &'static str> {
+    let [head, body] = split_top_level(args, ';')[..] else {
+        return Err(
+            "expected `tr_n!(n; \"singular\", \"plural\", ...)` or `tr_n!(n, context; ...)`",
+        );
+    };
+
+    let context = match split_top_level(head, ',').as_slice() {
+        [_n] => None,
+        [_n, context] => Some(literal_context(context)?),
+        _ => return Err("expected `n` or `n, context` before `;`"),
+    };
+
+    let parts = split_top_level(body, ',');
+    let (Some(singular), Some(plural)) = (
+        parts.first().and_then(|part| string_literal(part)),
+        parts.get(1).and_then(|part| string_literal(part)),
+    ) else {
+        return Err("singular and plural format strings must be string literals");
+    };
+
+    if has_placeholders(singular) || has_placeholders(plural) {
+        return Err(PLACEHOLDER_WARNING);
+    }
+
+    Ok(Message {
+        context,
+        msgid: format_message(singular),
+        plural: Some(format_message(plural)),
+    })
+}
+
+fn literal_context(tokens: &[Token]) -> Result<String, &'static str> {
+    // The macros format the context with `{}`, so its text is used verbatim.
+    string_literal(tokens)
+        .map(str::to_string)
+        .ok_or("context is not a string literal and cannot be extracted")
+}
+
+fn string_literal(tokens: &[Token]) -> Option<&str> {
+    match tokens {
+        [Token {
+            kind: TokenKind::Str(value),
+            ..
+        }] => Some(value),
+        _ => None,
+    }
+}
+
+/// Returns the tokens between the opening delimiter (already consumed) and its matching closing delimiter.
+fn invocation_args(tokens: &[Token]) -> Option<&[Token]> {
+    let mut depth = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        match token.kind {
+            TokenKind::Punct('(' | '[' | '{') => depth += 1,
+            TokenKind::Punct(')' | ']' | '}') if depth == 0 => return Some(&tokens[..i]),
+            TokenKind::Punct(')' | ']' | '}') => depth -= 1,
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Splits at `separator`, ignoring separators nested inside delimiters. A trailing separator does not produce an empty part.
+fn split_top_level(tokens: &[Token], separator: char) -> Vec<&[Token]> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (i, token) in tokens.iter().enumerate() {
+        match token.kind {
+            TokenKind::Punct('(' | '[' | '{') => depth += 1,
+            TokenKind::Punct(')' | ']' | '}') => depth -= 1,
+            TokenKind::Punct(c) if c == separator && depth == 0 => {
+                parts.push(&tokens[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    if start < tokens.len() {
+        parts.push(&tokens[start..]);
+    }
+
+    parts
+}
+
+/// Whether a format string contains `{...}` placeholders, as opposed to only escaped `{{` and `}}`.
+fn has_placeholders(fmt: &str) -> bool {
+    let mut chars = fmt.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '{' {
+            if chars.peek() != Some(&'{') {
+                return true;
+            }
+            chars.next();
+        }
+    }
+
+    false
+}
+
+/// Message as looked up at runtime: without placeholders, `format!` only turns `{{` and `}}` into braces.
+fn format_message(fmt: &str) -> String {
+    fmt.replace("{{", "{").replace("}}", "}")
+}
+
+/// Writes `keyword "value"`, splitting multi-line values into one string per line, as gettext tools do.
+fn write_po_string(out: &mut String, keyword: &str, value: &str) {
+    let lines: Vec<&str> = value.split_inclusive('\n').collect();
+
+    if lines.len() <= 1 {
+        let _ = writeln!(out, "{keyword} \"{}\"", escape_po(value));
+    } else {
+        let _ = writeln!(out, "{keyword} \"\"");
+        for line in lines {
+            let _ = writeln!(out, "\"{}\"", escape_po(line));
+        }
+    }
+}
+
+fn escape_po(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Tokenizer
+
+#[derive(Debug, PartialEq)]
+enum TokenKind {
+    Ident(String),
+    Punct(char),
+    /// String literal, with escape sequences resolved.
+    Str(String),
+    /// Any other literal (numbers, characters, byte strings).
+    Other,
+}
+
+#[derive(Debug)]
+struct Token {
+    kind: TokenKind,
+    line: usize,
+}
+
+/// Minimal Rust lexer: just enough to find macro invocations and their string literals, skipping comments.
+fn tokenize(source: &str) -> Vec<Token> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = vec![];
+    let mut line = 1;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let token_line = line;
+
+        let kind = match c {
+            '\n' => {
+                line += 1;
+                i += 1;
+                continue;
+            }
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '/' if chars.get(i + 1) == Some(&'/') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+                continue;
+            }
+            '/' if chars.get(i + 1) == Some(&'*') => {
+                i = skip_block_comment(&chars, i, &mut line);
+                continue;
+            }
+            '"' => {
+                let (raw, end) = lex_string(&chars, i + 1, &mut line);
+                i = end;
+                TokenKind::Str(unescape(&raw))
+            }
+            '\'' => {
+                i = skip_char_or_lifetime(&chars, i);
+                TokenKind::Other
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                let ident: String = chars[start..i].iter().collect();
+                let next = chars.get(i).copied();
+
+                match (ident.as_str(), next) {
+                    ("r", Some('"' | '#')) => {
+                        let (raw, end) = lex_raw_string(&chars, i, &mut line);
+                        i = end;
+                        TokenKind::Str(raw)
+                    }
+                    ("br" | "cr", Some('"' | '#')) => {
+                        i = lex_raw_string(&chars, i, &mut line).1;
+                        TokenKind::Other
+                    }
+                    ("b" | "c", Some('"')) => {
+                        i = lex_string(&chars, i + 1, &mut line).1;
+                        TokenKind::Other
+                    }
+                    ("b", Some('\'')) => {
+                        i = skip_char_or_lifetime(&chars, i);
+                        TokenKind::Other
+                    }
+                    _ => TokenKind::Ident(ident),
+                }
+            }
+            c if c.is_ascii_digit() => {
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                TokenKind::Other
+            }
+            c => {
+                i += 1;
+                TokenKind::Punct(c)
+            }
+        };
+
+        tokens.push(Token {
+            kind,
+            line: token_line,
+        });
+    }
+
+    tokens
+}
+
+/// Skips a (possibly nested) block comment starting at `start`; returns the index after it.
+fn skip_block_comment(chars: &[char], start: usize, line: &mut usize) -> usize {
+    let mut depth = 0;
+    let mut i = start;
+
+    while i < chars.len() {
+        match (chars[i], chars.get(i + 1)) {
+            ('/', Some('*')) => {
+                depth += 1;
+                i += 2;
+            }
+            ('*', Some('/')) => {
+                depth -= 1;
+                i += 2;
+                if depth == 0 {
+                    return i;
+                }
+            }
+            (c, _) => {
+                if c == '\n' {
+                    *line += 1;
+                }
+                i += 1;
+            }
+        }
+    }
+
+    i
+}
+
+/// Reads a string literal body starting after the opening quote. Returns the content with escapes intact, and the index after the
+/// closing quote.
+fn lex_string(chars: &[char], start: usize, line: &mut usize) -> (String, usize) {
+    let mut raw = String::new();
+    let mut i = start;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '"' => return (raw, i + 1),
+            '\\' => {
+                raw.push(c);
+                if let Some(&next) = chars.get(i + 1) {
+                    raw.push(next);
+                    if next == '\n' {
+                        *line += 1;
+                    }
+                }
+                i += 2;
+                continue;
+            }
+            '\n' => *line += 1,
+            _ => {}
+        }
+
+        raw.push(c);
+        i += 1;
+    }
+
+    (raw, i)
+}
+
+/// Reads a raw string literal starting at the first `#` or `"` after the `r` prefix. Returns its content and the index after it.
+fn lex_raw_string(chars: &[char], start: usize, line: &mut usize) -> (String, usize) {
+    let mut i = start;
+    let mut hashes = 0;
+    while chars.get(i) == Some(&'#') {
+        hashes += 1;
+        i += 1;
+    }
+
+    if chars.get(i) != Some(&'"') {
+        // Not a raw string, e.g. a raw identifier `r#name`.
+        return (String::new(), i);
+    }
+    i += 1;
+
+    let mut raw = String::new();
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '"' && (1..=hashes).all(|k| chars.get(i + k) == Some(&'#')) {
+            return (raw, i + 1 + hashes);
+        }
+        if c == '\n' {
+            *line += 1;
+        }
+
+        raw.push(c);
+        i += 1;
+    }
+
+    (raw, i)
+}
+
+/// Skips a character literal (`'a'`, `'\n'`) or a lifetime (`'a`) starting at the quote; returns the index after it.
+fn skip_char_or_lifetime(chars: &[char], start: usize) -> usize {
+    let mut i = start + 1;
+
+    if chars.get(i) == Some(&'\\') {
+        // Escaped character literal.
+        i += 2;
+        while i < chars.len() && chars[i] != '\'' {
+            i += 1;
+        }
+        return i + 1;
+    }
+
+    if chars.get(i + 1) == Some(&'\'') {
+        return i + 2;
+    }
+
+    // Lifetime or label.
+    while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+        i += 1;
+    }
+    i
+}
+
+/// Resolves escape sequences of a (non-raw) string literal.
+fn unescape(raw: &str) -> String {
+    let mut out = String::with_capacity(raw.len());
+    let mut chars = raw.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('t') => out.push('\t'),
+            Some('0') => out.push('\0'),
+            Some('x') => {
+                let hex: String = chars.by_ref().take(2).collect();
+                if let Some(c) = u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                    out.push(c);
+                }
+            }
+            Some('u') => {
+                let hex: String = chars
+                    .by_ref()
+                    .skip_while(|&c| c == '{')
+                    .take_while(|&c| c != '}')
+                    .filter(|&c| c != '_')
+                    .collect();
+                if let Some(c) = u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                    out.push(c);
+                }
+            }
+            // Line continuation: skip the newline and leading whitespace of the next line.
+            Some('\n' | '\r') => {
+                while chars.peek().is_some_and(|c| c.is_whitespace()) {
+                    chars.next();
+                }
+            }
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+
+    out
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::TranslationTemplate;
+
+    const SOURCE: &str = r##"
+        use godot::tools::{tr, tr_n};
+
+        // tr!("commented out");
+        /* tr!("also /* nested */ commented out"); */
+        fn menu(count: i32, player: &str) {
+            let _ = tr!("Start game");
+            let _ = tr!("menu"; "Quit");
+            let _ = tr!("Hello, {player}!");
+            let _ = tr_n!(count; "{count} apple", "{count} apples");
+            let _ = tr_n!(count, "fruit"; "One pear", "Many pears");
+            let _ = tr!(r#"Say "hi""#);
+            let _ = tr!("Line one\nline two");
+            let _ = tr!("Literal {{braces}}");
+            let _ = tr!("Start game");
+            let _ = tr!(context(); "Dynamic context");
+            let _ = 'label: loop { break 'label; };
+        }
+    "##;
+
+    #[test]
+    fn extract_messages() {
+        let mut template = TranslationTemplate::new();
+        template.add_source("src/menu.rs", SOURCE);
+
+        assert_eq!(template.len(), 6);
+
+        let warnings: Vec<&str> = template.warnings().collect();
+        assert_eq!(
+            warnings,
+            [
+                "src/menu.rs:9: message has format placeholders, which are substituted before the translation lookup; it cannot be translated and is skipped",
+                "src/menu.rs:10: message has format placeholders, which are substituted before the translation lookup; it cannot be translated and is skipped",
+                "src/menu.rs:16: context is not a string literal and cannot be extracted",
+            ]
+        );
+
+        let pot = template.to_pot();
+        assert!(pot
+            .contains("#: src/menu.rs:7\n#: src/menu.rs:15\nmsgid \"Start game\"\nmsgstr \"\"\n"));
+        assert!(pot.contains("msgctxt \"menu\"\nmsgid \"Quit\"\n"));
+        assert!(!pot.contains("{player}"));
+        assert!(!pot.contains("{count}"));
+        assert!(
+            pot.contains("msgctxt \"fruit\"\nmsgid \"One pear\"\nmsgid_plural \"Many pears\"\n")
+        );
+        assert!(pot.contains("msgid \"Say \\\"hi\\\"\"\n"));
+        assert!(pot.contains("msgid \"\"\n\"Line one\\n\"\n\"line two\"\n"));
+        assert!(pot.contains("msgid \"Literal {braces}\"\n"));
+        assert!(!pot.contains("commented out"));
+    }
+
+    #[test]
+    fn conflicting_plurals() {
+        let mut template = TranslationTemplate::new();
+        template.add_source(
+            "lib.rs",
+            r#"tr_n!(n; "One item", "Many items"); tr_n!(n; "One item", "Many things");"#,
+        );
+
+        assert_eq!(template.len(), 1);
+        assert_eq!(template.warnings().count(), 1);
+    }
+}
@@ -130,12 +130,34 @@
 //!   This feature requires at least Godot 4.3.
 //!   See also: [`#[derive(GodotClass)]`](register/derive.GodotClass.html#documentation)
 //!
+//! * **`embedded-resources`**
+//!
+//!   Provides [`tools::include_resource!`], which embeds files such as shaders, fonts or textures in the library, and
+//!   [`tools::EmbeddedResourceLoader`], which serves them under `rust://` paths.
+//!   This registers the class `EmbeddedResourceLoader` with Godot.<br><br>
+//!
+//! * **`loopback-peer`**
+//!
+//!   Provides [`tools::LoopbackNetwork`], an in-process `MultiplayerPeer` implementation to test RPCs without sockets.
+//!   This registers the class `LoopbackMultiplayerPeer` with Godot, so it should typically only be enabled for test builds.<br><br>
+//!
+//! * **`script-toolkit`**
+//!
+//!   Provides [`tools::scripting`], a framework to implement custom scripting languages on top of `ScriptLanguageExtension`.
+//!   This registers the classes `ScriptLanguageHost` and `ScriptHost` with Godot.<br><br>
+//!
 //! _Integrations:_
 //!
 //! * **`serde`**
 //!
 //!   Implement the [serde](https://serde.rs/) traits `Serialize` and `Deserialize` traits for certain built-in types.
 //!   The serialized representation underlies **no stability guarantees** and may change at any time, even without a SemVer-breaking change.
+//!   Also adds serde adapters for `ConfigFile` and `ProjectSettings` to the `tools` module, e.g. `tools::from_config_file()`.
+//!
+//! * **`serde_json`**
+//!
+//!   Adds conversions between `Variant` and `serde_json::Value` to the `tools` module, e.g. `tools::variant_to_json()`.
+//!   Implies `serde`.
 //!
 
 #![doc(
@@ -185,10 +207,13 @@ pub mod init {
 pub mod register {
     pub use godot_core::registry::property;
     pub use godot_core::registry::signal::re_export::*;
-    pub use godot_macros::{godot_api, godot_dyn, Export, GodotClass, GodotConvert, Var};
+    pub use godot_macros::{
+        godot_api, godot_dyn, godot_proxy, Export, GodotClass, GodotConvert, InputActions,
+        ProjectSettings, PropertyGroup, SceneNodes, ScriptInstance, Var,
+    };
 
     #[cfg(feature = "__codegen-full")]
-    pub use godot_core::registry::RpcConfig;
+    pub use godot_core::registry::{RpcCall, RpcConfig};
 
     /// Re-exports used by proc-macro API.
     #[doc(hidden)]
@@ -8,7 +8,10 @@
 pub use super::register::property::{Export, Var};
 
 // Re-export macros.
-pub use super::register::{godot_api, godot_dyn, Export, GodotClass, GodotConvert, Var};
+pub use super::register::{
+    godot_api, godot_dyn, godot_proxy, Export, GodotClass, GodotConvert, InputActions,
+    ProjectSettings, PropertyGroup, SceneNodes, Var,
+};
 
 pub use super::builtin::__prelude_reexport::*;
 pub use super::builtin::math::FloatExt as _;
@@ -33,8 +36,15 @@ pub use super::obj::{
 // Make trait methods available.
 pub use super::obj::EngineBitfield as _;
 pub use super::obj::EngineEnum as _;
+pub use super::obj::GodotProxy as _; // try_from_object(), from_object()
 pub use super::obj::NewAlloc as _;
 pub use super::obj::NewGd as _;
+pub use super::obj::SceneNodes as _; // try_from_node(), from_node()
 pub use super::obj::WithBaseField as _; // base(), base_mut(), to_gd()
 pub use super::obj::WithSignals as _; // Gd::signals()
+#[cfg(feature = "__codegen-full")]
+pub use super::obj::WithUserRpcs as _; // self.rpcs()
 pub use super::obj::WithUserSignals as _; // self.signals()
+
+pub use super::tools::InputActions as _; // is_pressed(), verify_input_map()
+pub use super::tools::TypedProjectSettings as _; // load()
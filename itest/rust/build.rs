@@ -278,6 +278,15 @@ fn main() {
     if godot_codegen::IS_CODEGEN_FULL {
         println!("cargo::rustc-cfg=feature=\"codegen-full\"");
     }
+
+    // Optional tools are enabled via `godot` features, e.g. `--features godot/loopback-peer`. Their tests are gated with
+    // `#[cfg(godot_feature = "...")]`, as itest does not forward such features itself.
+    println!(
+        r#"cargo::rustc-check-cfg=cfg(godot_feature, values("embedded-resources", "loopback-peer", "script-toolkit"))"#
+    );
+    for feature in godot_codegen::ENABLED_TOOLS {
+        println!(r#"cargo::rustc-cfg=godot_feature="{feature}""#);
+    }
 }
 
 // TODO remove, or remove code duplication with codegen
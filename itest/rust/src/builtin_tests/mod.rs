@@ -43,6 +43,8 @@ mod string {
 
 mod script {
     mod script_instance_tests;
+    #[cfg(godot_feature = "script-toolkit")]
+    mod script_toolkit_test;
 }
 
 mod color_test;
@@ -14,9 +14,9 @@ use godot::classes::{
 };
 use godot::global::{Error, MethodFlags};
 use godot::meta::{ClassName, FromGodot, MethodInfo, PropertyInfo, ToGodot};
-use godot::obj::script::{create_script_instance, ScriptInstance, SiMut};
+use godot::obj::script::{create_script_instance, ScriptInstance, ScriptInstanceSource, SiMut};
 use godot::obj::{Base, Gd, NewAlloc, WithBaseField};
-use godot::register::{godot_api, GodotClass};
+use godot::register::{godot_api, GodotClass, ScriptInstance};
 use godot::sys;
 
 use crate::framework::itest;
@@ -245,6 +245,111 @@ impl ScriptInstance for TestScriptInstance {
     }
 }
 
+/// Script whose instances are implemented via `#[derive(ScriptInstance)]`.
+#[derive(GodotClass)]
+#[class(base = ScriptExtension, no_init, tool)]
+struct DerivedTestScript {
+    language: Gd<TestScriptLanguage>,
+    base: Base<ScriptExtension>,
+}
+
+impl DerivedTestScript {
+    fn new(language: Gd<TestScriptLanguage>) -> Gd<Self> {
+        Gd::from_init_fn(|base| Self { language, base })
+    }
+}
+
+#[godot_api]
+impl IScriptExtension for DerivedTestScript {
+    fn can_instantiate(&self) -> bool {
+        true
+    }
+
+    unsafe fn instance_create(&self, for_object: Gd<Object>) -> *mut c_void {
+        create_script_instance(DerivedTestScriptInstance::new(self.to_gd()), for_object)
+    }
+
+    fn get_language(&self) -> Option<Gd<ScriptLanguage>> {
+        Some(self.language.clone().upcast())
+    }
+}
+
+#[derive(ScriptInstance)]
+#[script_instance(class_name = "DerivedTestScript")]
+struct DerivedTestScriptInstance {
+    #[var]
+    health: i64,
+
+    #[var(get = label_text)]
+    label: GString,
+
+    script: Gd<Script>,
+    script_language: Gd<ScriptLanguage>,
+}
+
+impl DerivedTestScriptInstance {
+    fn new(script: Gd<DerivedTestScript>) -> Self {
+        Self {
+            health: 10,
+            label: GString::from("derived"),
+            script_language: {
+                let s = script.bind();
+
+                s.get_language().unwrap()
+            },
+            script: script.upcast(),
+        }
+    }
+
+    fn label_text(&self) -> GString {
+        format!("{}:{}", self.label, self.health).into()
+    }
+}
+
+#[godot_api(script_instance)]
+impl DerivedTestScriptInstance {
+    #[func]
+    fn damage(&mut self, amount: i64, #[opt(default = 1)] multiplier: i64) -> bool {
+        self.health -= amount * multiplier;
+        self.health > 0
+    }
+
+    #[func(rename = describe)]
+    fn describe_instance(&self, prefix: GString) -> GString {
+        format!("{prefix}{}", self.label_text()).into()
+    }
+}
+
+impl ScriptInstanceSource for DerivedTestScriptInstance {
+    fn get_script(&self) -> &Gd<Script> {
+        &self.script
+    }
+
+    fn get_language(&self) -> Gd<ScriptLanguage> {
+        self.script_language.clone()
+    }
+}
+
+// No #[godot_api(script_instance)] block: the instance has properties, but no methods.
+#[derive(ScriptInstance)]
+struct PropertyOnlyScriptInstance {
+    #[var]
+    health: i64,
+
+    script: Gd<Script>,
+    script_language: Gd<ScriptLanguage>,
+}
+
+impl ScriptInstanceSource for PropertyOnlyScriptInstance {
+    fn get_script(&self) -> &Gd<Script> {
+        &self.script
+    }
+
+    fn get_language(&self) -> Gd<ScriptLanguage> {
+        self.script_language.clone()
+    }
+}
+
 #[derive(GodotClass)]
 #[class(base = ScriptLanguageExtension, tool, init)]
 struct TestScriptLanguage {
@@ -355,3 +460,69 @@ fn script_instance_exists() {
     object.free();
     language.free();
 }
+
+#[itest]
+fn script_instance_derived() {
+    let language = TestScriptLanguage::new_alloc();
+    let script = DerivedTestScript::new(language.clone());
+    let mut object = Object::new_alloc();
+
+    object.set_script(&script.to_variant());
+
+    // #[var] with generated getter and setter.
+    assert_eq!(object.get("health"), 10.to_variant());
+    object.set("health", &25.to_variant());
+    assert_eq!(object.get("health"), 25.to_variant());
+
+    // #[var] with custom getter and no setter.
+    assert_eq!(object.get("label"), "derived:25".to_variant());
+    object.set("label", &"changed".to_variant());
+    assert_eq!(object.get("label"), "derived:25".to_variant());
+
+    // #[func] with default parameter.
+    assert_eq!(object.call("damage", &[5.to_variant()]), true.to_variant());
+    assert_eq!(object.get("health"), 20.to_variant());
+    assert_eq!(
+        object.call("damage", &[5.to_variant(), 4.to_variant()]),
+        false.to_variant()
+    );
+    assert_eq!(object.get("health"), 0.to_variant());
+
+    // #[func] with rename.
+    assert!(object.has_method("describe"));
+    assert!(!object.has_method("describe_instance"));
+    assert_eq!(
+        object.call("describe", &["hp=".to_variant()]),
+        "hp=derived:0".to_variant()
+    );
+
+    let properties: Vec<String> = object
+        .get_property_list()
+        .iter_shared()
+        .map(|dict| dict.get("name").unwrap().to_string())
+        .collect();
+    assert!(properties.contains(&"health".to_string()));
+    assert!(properties.contains(&"label".to_string()));
+
+    object.free();
+    language.free();
+}
+
+#[itest]
+fn script_instance_derived_without_methods() {
+    let language = TestScriptLanguage::new_alloc();
+    let instance = PropertyOnlyScriptInstance {
+        health: 10,
+        script: DerivedTestScript::new(language.clone()).upcast(),
+        script_language: language.clone().upcast(),
+    };
+
+    assert!(instance.get_method_list().is_empty());
+    assert!(!instance.has_method(StringName::from("health")));
+    assert_eq!(
+        instance.get_property(StringName::from("health")),
+        Some(10.to_variant())
+    );
+
+    language.free();
+}
@@ -0,0 +1,228 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+
+use godot::builtin::{GString, StringName, Variant};
+use godot::classes::{Engine, Object};
+use godot::global::{Error, MethodFlags};
+use godot::meta::{ClassName, FromGodot, MethodInfo, PropertyInfo, ToGodot};
+use godot::obj::{Gd, NewAlloc};
+use godot::sys;
+use godot::tools::scripting::{Script, ScriptError, ScriptLanguage, ScriptLanguageHost};
+
+use crate::framework::itest;
+
+/// Minimal language: `var <name> = <int>` declares a property, `func <name>` declares a method that returns the sum of all properties.
+struct MiniLanguage;
+
+impl ScriptLanguage for MiniLanguage {
+    type Script = MiniScript;
+
+    fn name(&self) -> GString {
+        "MiniLanguage".into()
+    }
+
+    fn extension(&self) -> GString {
+        "mini".into()
+    }
+
+    fn compile(&self, source: &GString) -> Result<MiniScript, ScriptError> {
+        let mut script = MiniScript {
+            vars: Vec::new(),
+            funcs: Vec::new(),
+        };
+
+        for (index, line) in source.to_string().lines().enumerate() {
+            let line_no = index as i32 + 1;
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+
+            match tokens.as_slice() {
+                [] => {}
+                ["var", name, "=", value] => {
+                    let value = value
+                        .parse()
+                        .map_err(|_| ScriptError::new(line_no, 1, "expected integer"))?;
+                    script.vars.push((name.to_string(), value));
+                }
+                ["func", name] => script.funcs.push(name.to_string()),
+                _ => return Err(ScriptError::new(line_no, 1, "unexpected statement")),
+            }
+        }
+
+        Ok(script)
+    }
+}
+
+struct MiniScript {
+    vars: Vec<(String, i64)>,
+    funcs: Vec<String>,
+}
+
+impl Script for MiniScript {
+    type State = HashMap<String, i64>;
+
+    fn instantiate(&self, _owner: &Gd<Object>) -> Self::State {
+        self.vars.iter().cloned().collect()
+    }
+
+    fn call(
+        &self,
+        state: &mut Self::State,
+        method: &StringName,
+        _args: &[&Variant],
+    ) -> Result<Variant, sys::GDExtensionCallErrorType> {
+        if !self.funcs.iter().any(|f| *method == StringName::from(f)) {
+            return Err(sys::GDEXTENSION_CALL_ERROR_INVALID_METHOD);
+        }
+
+        let total: i64 = state.values().sum();
+        Ok(total.to_variant())
+    }
+
+    fn get(&self, state: &Self::State, name: &StringName) -> Option<Variant> {
+        state.get(&name.to_string()).map(|value| value.to_variant())
+    }
+
+    fn set(&self, state: &mut Self::State, name: &StringName, value: &Variant) -> bool {
+        match state.get_mut(&name.to_string()) {
+            Some(stored) => {
+                *stored = i64::from_variant(value);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn properties(&self) -> Vec<PropertyInfo> {
+        self.vars
+            .iter()
+            .map(|(name, _)| PropertyInfo::new_var::<i64>(name))
+            .collect()
+    }
+
+    fn property_default(&self, name: &StringName) -> Option<Variant> {
+        self.vars
+            .iter()
+            .find(|(var, _)| *name == StringName::from(var))
+            .map(|(_, value)| value.to_variant())
+    }
+
+    fn methods(&self) -> Vec<MethodInfo> {
+        self.funcs
+            .iter()
+            .enumerate()
+            .map(|(id, name)| MethodInfo {
+                id: id as i32,
+                method_name: StringName::from(name),
+                class_name: ClassName::none(),
+                return_type: PropertyInfo::new_var::<i64>(""),
+                arguments: vec![],
+                default_arguments: vec![],
+                flags: MethodFlags::NORMAL,
+            })
+            .collect()
+    }
+}
+
+const SOURCE: &str = "var health = 100\nvar mana = 5\nfunc total";
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Tests
+
+#[itest]
+fn script_toolkit_register_language() {
+    let count = Engine::singleton().get_script_language_count();
+
+    let language = ScriptLanguageHost::register(MiniLanguage);
+    assert_eq!(Engine::singleton().get_script_language_count(), count + 1);
+
+    let registered = Engine::singleton()
+        .get_script_language(count)
+        .expect("registered language");
+    assert_eq!(registered.instance_id(), language.instance_id());
+
+    ScriptLanguageHost::unregister(language);
+    assert_eq!(Engine::singleton().get_script_language_count(), count);
+}
+
+#[itest]
+fn script_toolkit_instance() {
+    let language = ScriptLanguageHost::new(MiniLanguage);
+    let script = language.bind().new_script(SOURCE);
+
+    let mut object = Object::new_alloc();
+    object.set_script(&script.to_variant());
+
+    assert_eq!(object.get("health"), 100.to_variant());
+    assert_eq!(object.get("mana"), 5.to_variant());
+
+    object.set("health", &7.to_variant());
+    assert_eq!(object.get("health"), 7.to_variant());
+
+    assert!(object.has_method("total"));
+    assert_eq!(object.call("total", &[]), 12.to_variant());
+
+    // Native methods are still reachable.
+    assert_eq!(object.call("get_class", &[]), "Object".to_variant());
+
+    let property_names: Vec<String> = object
+        .get_property_list()
+        .iter_shared()
+        .map(|dict| dict.get("name").unwrap().to_string())
+        .collect();
+    assert!(property_names.contains(&"health".to_string()));
+    assert!(property_names.contains(&"mana".to_string()));
+
+    object.free();
+    drop(script);
+    language.free();
+}
+
+#[itest]
+fn script_toolkit_reflection() {
+    let language = ScriptLanguageHost::new(MiniLanguage);
+    let script = language.bind().new_script(SOURCE);
+
+    assert!(script.can_instantiate());
+    assert_eq!(script.get_instance_base_type(), StringName::from("Object"));
+    assert_eq!(script.get_script_property_list().len(), 2);
+    assert_eq!(script.get_script_method_list().len(), 1);
+    assert!(script.has_script_method("total"));
+    assert_eq!(script.get_property_default_value("mana"), 5.to_variant());
+
+    drop(script);
+    language.free();
+}
+
+#[itest]
+fn script_toolkit_compile_error_and_reload() {
+    let language = ScriptLanguageHost::new(MiniLanguage);
+    let mut script = language.bind().new_script("var health = 100\nbogus");
+
+    {
+        let script = script.bind();
+        let err = script.compile_error().expect("compile error");
+        assert_eq!(err.line, 2);
+        assert!(script.compiled::<MiniScript>().is_none());
+    }
+    assert!(!script.can_instantiate());
+
+    script.set_source_code("var health = 100");
+    assert_eq!(script.reload(), Error::OK);
+
+    {
+        let script = script.bind();
+        assert!(script.compile_error().is_none());
+        let compiled = script.compiled::<MiniScript>().expect("compiled script");
+        assert_eq!(compiled.vars, vec![("health".to_string(), 100)]);
+    }
+    assert!(script.can_instantiate());
+
+    drop(script);
+    language.free();
+}
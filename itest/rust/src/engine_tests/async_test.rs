@@ -8,7 +8,7 @@
 use std::ops::Deref;
 
 use godot::builtin::{array, Array, Callable, Signal, Variant};
-use godot::classes::{Object, RefCounted};
+use godot::classes::{Engine, Node, Object, RefCounted};
 use godot::meta::ToGodot;
 use godot::obj::{Base, Gd, NewAlloc, NewGd};
 use godot::prelude::{godot_api, GodotClass};
@@ -28,6 +28,39 @@ impl AsyncRefCounted {
     fn custom_signal(value: u32);
     #[signal]
     fn custom_signal_array(value: Array<i64>);
+
+    #[func]
+    async fn async_double(value: i64) -> i64 {
+        value * 2
+    }
+
+    #[func(gd_self)]
+    async fn async_wait_for_signal(this: Gd<Self>) -> u32 {
+        let signal = Signal::from_object_signal(&this, "custom_signal");
+        let (value,): (u32,) = signal.to_future().await;
+
+        value
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct AsyncNode {
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl AsyncNode {
+    #[signal]
+    fn never_emitted();
+
+    #[func(gd_self)]
+    async fn wait_forever(this: Gd<Self>) {
+        let signal = Signal::from_object_signal(&this, "never_emitted");
+        let _: () = signal.to_future().await;
+
+        unreachable!();
+    }
 }
 
 #[itest(async)]
@@ -234,3 +267,65 @@ fn async_typed_signal_with_array() -> TaskHandle {
 
     task_handle
 }
+
+#[itest(async)]
+fn async_func_static() -> TaskHandle {
+    let mut object = AsyncRefCounted::new_gd();
+    let signal = object
+        .call("async_double", &[21.to_variant()])
+        .to::<Signal>();
+
+    task::spawn(async move {
+        let (result,): (i64,) = signal.to_future().await;
+
+        assert_eq!(result, 42);
+    })
+}
+
+#[itest(async)]
+fn async_func_gd_self() -> TaskHandle {
+    let mut object = AsyncRefCounted::new_gd();
+    let signal = object.call("async_wait_for_signal", &[]).to::<Signal>();
+
+    let task_handle = task::spawn(async move {
+        let (result,): (u32,) = signal.to_future().await;
+
+        assert_eq!(result, 77);
+    });
+
+    object.signals().custom_signal().emit(77);
+
+    task_handle
+}
+
+#[itest]
+fn async_func_canceled_on_free() {
+    let mut node = AsyncNode::new_alloc();
+    let signal = node.call("wait_forever", &[]).to::<Signal>();
+    let completion_id = signal.object_id().expect("signal has an object");
+
+    node.free();
+
+    // Canceling the task drops the future, which owns the completion object.
+    assert!(Gd::<Object>::try_from_instance_id(completion_id).is_err());
+}
+
+#[itest(async)]
+fn async_func_refcounted_released() -> TaskHandle {
+    let mut object = AsyncRefCounted::new_gd();
+    let object_id = object.instance_id();
+    let signal = object.call("async_wait_for_signal", &[]).to::<Signal>();
+    let completion_id = signal.object_id().expect("signal has an object");
+    drop(object);
+
+    // Only the pending task references the object now. It is canceled in a deferred call, which releases the object.
+    let main_loop = Engine::singleton().get_main_loop().expect("main loop");
+    let frame = Signal::from_object_signal(&main_loop, "process_frame");
+
+    task::spawn(async move {
+        let _: () = frame.to_future().await;
+
+        assert!(Gd::<Object>::try_from_instance_id(object_id).is_err());
+        assert!(Gd::<Object>::try_from_instance_id(completion_id).is_err());
+    })
+}
@@ -0,0 +1,99 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Color, Dictionary};
+use godot::classes::{IResourceFormatLoader, ImageTexture, Json, ResourceLoader, Shader};
+use godot::tools::{include_resource, load, try_load, EmbeddedResource, EmbeddedResourceLoader};
+
+use crate::framework::itest;
+
+include_resource!("assets/embedded.gdshader");
+include_resource!("assets/embedded.json");
+include_resource!("assets/embedded_icon.png");
+
+#[itest]
+fn embedded_resource_bytes() {
+    let shader = EmbeddedResource::find("rust://itest/assets/embedded.gdshader")
+        .expect("shader is embedded");
+
+    assert_eq!(
+        shader.bytes(),
+        include_bytes!("../../assets/embedded.gdshader")
+    );
+    assert_eq!(
+        shader.resource_path(),
+        "rust://itest/assets/embedded.gdshader"
+    );
+
+    assert!(EmbeddedResource::find("rust://itest/assets/missing.png").is_none());
+}
+
+#[itest]
+fn embedded_resource_loader() {
+    let loader = EmbeddedResourceLoader::register();
+
+    let mut paths = loader.bind().paths().map(String::from).collect::<Vec<_>>();
+    paths.sort();
+    assert_eq!(
+        paths,
+        [
+            "rust://itest/assets/embedded.gdshader",
+            "rust://itest/assets/embedded.json",
+            "rust://itest/assets/embedded_icon.png",
+        ]
+    );
+
+    assert!(ResourceLoader::singleton().exists("rust://itest/assets/embedded.json"));
+    assert!(!ResourceLoader::singleton().exists("rust://itest/assets/missing.json"));
+
+    let shader = load::<Shader>("rust://itest/assets/embedded.gdshader");
+    assert!(shader
+        .get_code()
+        .to_string()
+        .contains("shader_type canvas_item;"));
+
+    let json = load::<Json>("rust://itest/assets/embedded.json");
+    let data = json.get_data().to::<Dictionary>();
+    assert_eq!(data.at("name"), "embedded".into());
+
+    let texture = load::<ImageTexture>("rust://itest/assets/embedded_icon.png");
+    assert_eq!(texture.get_width(), 2);
+    let image = texture.get_image().expect("texture has image");
+    assert_eq!(image.get_pixel(1, 1), Color::from_rgba(1.0, 0.0, 0.0, 1.0));
+
+    assert!(try_load::<Shader>("rust://itest/assets/missing.gdshader").is_err());
+
+    EmbeddedResourceLoader::unregister(loader);
+}
+
+#[itest]
+fn embedded_resource_loader_types() {
+    let loader = EmbeddedResourceLoader::register();
+
+    {
+        let loader = loader.bind();
+        assert!(loader.handles_type("Shader".into()));
+        assert!(loader.handles_type("ImageTexture".into()));
+        assert!(loader.handles_type("JSON".into()));
+
+        // No embedded fonts, and no types that the loader cannot produce.
+        assert!(!loader.handles_type("FontFile".into()));
+        assert!(!loader.handles_type("Gradient".into()));
+        assert!(!loader.handles_type("Resource".into()));
+
+        assert_eq!(
+            loader.get_resource_type("rust://itest/assets/embedded_icon.png".into()),
+            "ImageTexture".into()
+        );
+        assert_eq!(
+            loader.get_resource_type("rust://itest/assets/missing.png".into()),
+            "".into()
+        );
+    }
+
+    EmbeddedResourceLoader::unregister(loader);
+}
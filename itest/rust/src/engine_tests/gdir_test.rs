@@ -0,0 +1,154 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::io::{ErrorKind, Write};
+
+use crate::framework::itest;
+use godot::classes::file_access::ModeFlags;
+use godot::tools::{GDir, GFile};
+
+const TEST_DIR: &str = "user://gdir_tests";
+
+fn write_file(path: &str, content: &str) {
+    let mut file = GFile::open(path, ModeFlags::WRITE).unwrap();
+    file.write_all(content.as_bytes()).unwrap();
+}
+
+/// Creates `TEST_DIR` with the layout `a.txt`, `sub/b.txt`, `sub/nested/c.txt`.
+fn create_test_tree() {
+    if GDir::exists(TEST_DIR) {
+        GDir::remove_dir_all(TEST_DIR).unwrap();
+    }
+
+    GDir::create_dir_all(&format!("{TEST_DIR}/sub/nested")).unwrap();
+    write_file(&format!("{TEST_DIR}/a.txt"), "a");
+    write_file(&format!("{TEST_DIR}/sub/b.txt"), "b");
+    write_file(&format!("{TEST_DIR}/sub/nested/c.txt"), "c");
+}
+
+fn sorted_paths(paths: impl Iterator<Item = String>) -> Vec<String> {
+    let mut paths: Vec<String> = paths
+        .map(|path| path.trim_start_matches(TEST_DIR).to_string())
+        .collect();
+    paths.sort();
+    paths
+}
+
+#[itest]
+fn gdir_read_dir() {
+    create_test_tree();
+
+    let mut entries: Vec<_> = GDir::read_dir(TEST_DIR).unwrap().collect();
+    entries.sort_by_key(|entry| entry.name().to_string());
+
+    let summary: Vec<_> = entries
+        .iter()
+        .map(|entry| (entry.name().to_string(), entry.is_dir()))
+        .collect();
+    assert_eq!(
+        summary,
+        [("a.txt".to_string(), false), ("sub".to_string(), true)]
+    );
+    assert_eq!(entries[1].path().to_string(), format!("{TEST_DIR}/sub"));
+
+    GDir::remove_dir_all(TEST_DIR).unwrap();
+}
+
+#[itest]
+fn gdir_walk() {
+    create_test_tree();
+
+    let paths = GDir::walk(TEST_DIR)
+        .unwrap()
+        .map(|entry| entry.unwrap().path().to_string());
+
+    assert_eq!(
+        sorted_paths(paths),
+        [
+            "/a.txt",
+            "/sub",
+            "/sub/b.txt",
+            "/sub/nested",
+            "/sub/nested/c.txt"
+        ]
+    );
+
+    GDir::remove_dir_all(TEST_DIR).unwrap();
+}
+
+#[itest]
+fn gdir_copy_rename_remove() {
+    create_test_tree();
+
+    let a = format!("{TEST_DIR}/a.txt");
+    let copied = format!("{TEST_DIR}/copied.txt");
+    let renamed = format!("{TEST_DIR}/renamed.txt");
+
+    GDir::copy(&a, &copied).unwrap();
+    GDir::rename(&copied, &renamed).unwrap();
+
+    let mut file = GFile::open(&renamed, ModeFlags::READ).unwrap();
+    assert_eq!(file.read_as_gstring_entire(false).unwrap(), "a".into());
+    drop(file);
+
+    GDir::remove(&renamed).unwrap();
+    let names = sorted_paths(
+        GDir::read_dir(TEST_DIR)
+            .unwrap()
+            .map(|e| e.name().to_string()),
+    );
+    assert_eq!(names, ["a.txt", "sub"]);
+
+    GDir::remove_dir_all(TEST_DIR).unwrap();
+    assert!(!GDir::exists(TEST_DIR));
+}
+
+#[itest]
+fn gdir_errors() {
+    let err = GDir::read_dir("user://gdir_tests_nonexistent")
+        .err()
+        .expect("opening missing directory must fail");
+    assert!(err.to_string().contains("gdir_tests_nonexistent"));
+
+    GDir::create_dir_all(TEST_DIR).unwrap();
+    let err = GDir::create_dir(TEST_DIR).expect_err("directory exists");
+    assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+
+    GDir::remove_dir_all(TEST_DIR).unwrap();
+}
+
+// Creating symbolic links requires extra privileges on Windows.
+#[cfg(unix)]
+#[itest]
+fn gdir_remove_dir_all_symlink() {
+    use godot::classes::{DirAccess, ProjectSettings};
+    use godot::global::Error;
+
+    create_test_tree();
+
+    let target = "user://gdir_tests_link_target";
+    GDir::create_dir_all(target).unwrap();
+    write_file(&format!("{target}/kept.txt"), "kept");
+
+    let target_abs = ProjectSettings::singleton().globalize_path(target);
+    let mut dir = DirAccess::open(TEST_DIR).unwrap();
+    assert_eq!(dir.create_link(&target_abs, "link"), Error::OK);
+
+    let link = GDir::read_dir(TEST_DIR)
+        .unwrap()
+        .find(|entry| entry.name().to_string() == "link")
+        .expect("link is listed");
+    assert!(link.is_symlink());
+    assert!(link.is_dir());
+
+    // Only the link is removed, not the contents of its target.
+    GDir::remove_dir_all(TEST_DIR).unwrap();
+    assert!(!GDir::exists(TEST_DIR));
+    assert!(GFile::open(&format!("{target}/kept.txt"), ModeFlags::READ).is_ok());
+
+    GDir::remove_dir_all(target).unwrap();
+}
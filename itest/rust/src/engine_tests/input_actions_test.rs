@@ -0,0 +1,129 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::StringName;
+use godot::classes::{Input, InputEventAction, InputEventKey, InputMap};
+use godot::global::Key;
+use godot::obj::NewGd;
+use godot::register::InputActions;
+use godot::tools::InputActions;
+
+use crate::framework::itest;
+
+#[derive(InputActions, Copy, Clone, Eq, PartialEq, Debug)]
+enum TestAction {
+    #[action(keys = [SPACE, W], joy_buttons = [A])]
+    ItestJump,
+
+    #[action(keys = [LEFT], mouse_buttons = [LEFT])]
+    ItestMoveLeft,
+
+    #[action(name = "ui_accept")]
+    Confirm,
+}
+
+#[derive(InputActions, Copy, Clone, Eq, PartialEq, Debug)]
+enum UnregisteredAction {
+    ItestNeverRegistered,
+    ItestAlsoMissing,
+}
+
+fn erase_test_actions() {
+    let mut input_map = InputMap::singleton();
+    for action in [TestAction::ItestJump, TestAction::ItestMoveLeft] {
+        if input_map.has_action(&action.string_name()) {
+            input_map.erase_action(&action.string_name());
+        }
+    }
+}
+
+#[itest]
+fn input_actions_names() {
+    assert_eq!(
+        TestAction::ALL,
+        [
+            TestAction::ItestJump,
+            TestAction::ItestMoveLeft,
+            TestAction::Confirm
+        ]
+    );
+
+    assert_eq!(TestAction::ItestJump.action_name(), "itest_jump");
+    assert_eq!(TestAction::ItestMoveLeft.action_name(), "itest_move_left");
+    assert_eq!(TestAction::Confirm.action_name(), "ui_accept");
+    assert_eq!(
+        TestAction::ItestMoveLeft.string_name(),
+        StringName::from("itest_move_left")
+    );
+}
+
+#[itest]
+fn input_actions_verify_input_map() {
+    // Built-in action exists in every project.
+    assert!(InputMap::singleton().has_action("ui_accept"));
+
+    let err = UnregisteredAction::verify_input_map().expect_err("actions are missing");
+    assert_eq!(err.enum_name(), "UnregisteredAction");
+    assert_eq!(
+        err.missing_actions().collect::<Vec<_>>(),
+        ["itest_never_registered", "itest_also_missing"]
+    );
+}
+
+#[itest]
+fn input_actions_default_bindings() {
+    erase_test_actions();
+    assert!(TestAction::verify_input_map().is_err());
+
+    TestAction::register_default_bindings();
+    TestAction::verify_input_map().expect("all actions registered");
+
+    let input_map = InputMap::singleton();
+    let jump_events = input_map.action_get_events(&TestAction::ItestJump.string_name());
+    assert_eq!(jump_events.len(), 3);
+
+    // Existing actions are left unchanged.
+    let accept_events = input_map.action_get_events("ui_accept");
+    TestAction::register_default_bindings();
+    assert_eq!(
+        input_map.action_get_events("ui_accept").len(),
+        accept_events.len()
+    );
+
+    let key_event = jump_events.at(0).try_cast::<InputEventKey>();
+    assert_eq!(key_event.unwrap().get_physical_keycode(), Key::SPACE);
+
+    erase_test_actions();
+}
+
+#[itest]
+fn input_actions_state_and_events() {
+    erase_test_actions();
+    TestAction::register_default_bindings();
+
+    let mut input = Input::singleton();
+    assert!(!TestAction::ItestJump.is_pressed());
+
+    input.action_press(&TestAction::ItestJump.string_name());
+    assert!(TestAction::ItestJump.is_pressed());
+    assert_eq!(TestAction::ItestJump.strength(), 1.0);
+    assert!(!TestAction::ItestMoveLeft.is_pressed());
+
+    input.action_release(&TestAction::ItestJump.string_name());
+    assert!(!TestAction::ItestJump.is_pressed());
+
+    let mut event = InputEventAction::new_gd();
+    event.set_action("itest_move_left");
+    let event = event.upcast();
+    assert!(TestAction::ItestMoveLeft.matches_event(&event));
+    assert_eq!(
+        TestAction::from_event(&event),
+        Some(TestAction::ItestMoveLeft)
+    );
+
+    erase_test_actions();
+}
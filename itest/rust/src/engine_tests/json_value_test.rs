@@ -0,0 +1,160 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::itest;
+use godot::builtin::{
+    dict, varray, Array, Callable, Dictionary, StringName, Variant, VariantType, Vector2,
+};
+use godot::meta::ToGodot;
+use godot::tools::{json_to_variant, variant_to_json, JsonConversion, JsonKeys, JsonNumbers};
+use serde_json::json;
+
+#[itest]
+fn json_value_roundtrip() {
+    let json = json!({
+        "name": "Ferris",
+        "level": 3,
+        "speed": 1.5,
+        "alive": true,
+        "items": ["sword", null, 2.0],
+    });
+
+    let variant = json_to_variant(&json).unwrap();
+    let dict = variant.to::<Dictionary>();
+    assert_eq!(dict.get("name"), Some("Ferris".to_variant()));
+    assert_eq!(dict.get("level").unwrap().get_type(), VariantType::INT);
+    assert_eq!(dict.get("speed"), Some(1.5.to_variant()));
+    assert_eq!(
+        dict.get("items"),
+        Some(varray!["sword", Variant::nil(), 2.0].to_variant())
+    );
+
+    let back = variant_to_json(&variant).unwrap();
+    assert_eq!(back, json);
+}
+
+#[itest]
+fn json_value_from_variant() {
+    let typed: Array<i64> = Array::from(&[1, 2, 3]);
+    let variant = dict! {
+        "name": StringName::from("node"),
+        "path": "Parent/Child",
+        "scores": typed,
+    }
+    .to_variant();
+
+    let json = variant_to_json(&variant).unwrap();
+    assert_eq!(
+        json,
+        json!({ "name": "node", "path": "Parent/Child", "scores": [1, 2, 3] })
+    );
+}
+
+#[itest]
+fn json_value_numbers() {
+    let json = json!([1, 1.0, -7]);
+
+    let preserved = json_to_variant(&json).unwrap();
+    assert_eq!(preserved, varray![1, 1.0, -7].to_variant());
+
+    let floats = JsonConversion::new()
+        .numbers(JsonNumbers::Float)
+        .to_variant(&json)
+        .unwrap();
+    assert_eq!(floats, varray![1.0, 1.0, -7.0].to_variant());
+
+    let err = json_to_variant(&json!({ "big": u64::MAX })).unwrap_err();
+    assert_eq!(err.pointer(), "/big");
+
+    let err = variant_to_json(&f64::NAN.to_variant()).unwrap_err();
+    assert_eq!(err.pointer(), "");
+}
+
+#[itest]
+fn json_value_keys() {
+    let variant = dict! { 1: "one", "two": 2 }.to_variant();
+
+    let err = variant_to_json(&variant).unwrap_err();
+    assert!(err.message().contains("not a string"), "{err}");
+
+    let json = JsonConversion::new()
+        .keys(JsonKeys::Stringify)
+        .to_json(&variant)
+        .unwrap();
+    assert_eq!(json, json!({ "1": "one", "two": 2 }));
+
+    let duplicate = dict! { 1: "int", "1": "string" }.to_variant();
+    let result = JsonConversion::new()
+        .keys(JsonKeys::Stringify)
+        .to_json(&duplicate);
+    assert!(result.is_err());
+}
+
+#[itest]
+fn json_value_error_pointer() {
+    let variant = dict! {
+        "a/b": varray![0, Vector2::new(1.0, 2.0)],
+    }
+    .to_variant();
+
+    let err = variant_to_json(&variant).unwrap_err();
+    assert_eq!(err.pointer(), "/a~1b/1");
+    assert!(err.to_string().starts_with("at `/a~1b/1`"), "{err}");
+}
+
+#[itest]
+fn json_value_rejects_objects() {
+    let callable = Callable::invalid().to_variant();
+    let err = variant_to_json(&callable).unwrap_err();
+    assert!(err.message().contains("CALLABLE"), "{err}");
+}
+
+#[cfg(since_api = "4.4")]
+#[itest]
+fn json_value_native_types() {
+    use godot::builtin::{GString, PackedByteArray};
+    use godot::tools::JsonNativeTypes;
+
+    let conversion = JsonConversion::new().native_types(JsonNativeTypes::Encode);
+    let variant = varray![
+        Vector2::new(1.5, -2.0),
+        PackedByteArray::from(&[1, 2, 3]),
+        GString::from("plain"),
+    ]
+    .to_variant();
+
+    let json = conversion.to_json(&variant).unwrap();
+    assert_eq!(json[0]["type"], "Vector2");
+    assert_eq!(json[2], "plain");
+
+    let back = conversion.to_variant(&json).unwrap();
+    assert_eq!(back, variant);
+
+    // Objects that merely look like encodings stay dictionaries.
+    let unrelated = json!({ "type": "Player", "args": [1] });
+    let dict = conversion.to_variant(&unrelated).unwrap();
+    assert_eq!(dict.get_type(), VariantType::DICTIONARY);
+
+    let extra_keys = json!({ "type": "Color", "name": "red" });
+    let dict = conversion.to_variant(&extra_keys).unwrap();
+    assert_eq!(dict, dict! { "type": "Color", "name": "red" }.to_variant());
+
+    let extra_keys = json!({ "type": "Vector2", "args": [1.0, 2.0], "label": "pos" });
+    let dict = conversion.to_variant(&extra_keys).unwrap();
+    assert_eq!(dict.get_type(), VariantType::DICTIONARY);
+
+    // StringName and NodePath become plain strings, also when encoding native types.
+    let json = conversion
+        .to_json(&StringName::from("name").to_variant())
+        .unwrap();
+    assert_eq!(json, json!("name"));
+    let back = conversion.to_variant(&json).unwrap();
+    assert_eq!(back.get_type(), VariantType::STRING);
+
+    // Native types are rejected by default.
+    assert!(variant_to_json(&variant).is_err());
+}
@@ -9,12 +9,22 @@
 mod async_test;
 mod codegen_enums_test;
 mod codegen_test;
+// Embedded resources are loaded as textures, shaders etc.; the feature enables the full codegen for them.
+#[cfg(godot_feature = "embedded-resources")]
+mod embedded_resources_test;
 mod engine_enum_test;
+mod gdir_test;
 mod gfile_test;
+mod input_actions_test;
+#[cfg(feature = "serde_json")]
+mod json_value_test;
 mod native_st_niche_audio_test;
 mod native_st_niche_pointer_test;
 mod native_structures_test;
 mod node_test;
+mod project_settings_test;
 mod save_load_test;
+#[cfg(feature = "serde")]
+mod settings_serde_test;
 mod translate_test;
 mod utilities_test;
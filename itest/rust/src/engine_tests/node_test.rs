@@ -10,7 +10,7 @@ use std::str::FromStr;
 use godot::builtin::{NodePath, Variant};
 use godot::classes::{Node, Node3D, PackedScene, SceneTree};
 use godot::global;
-use godot::obj::{NewAlloc, NewGd};
+use godot::obj::{Gd, Inherits, NewAlloc, NewGd};
 
 use crate::framework::{itest, TestContext};
 
@@ -92,3 +92,120 @@ fn node_call_group(ctx: &TestContext) {
     node.add_to_group("group");
     tree.call_group("group", "set_name", &[Variant::from("name")]);
 }
+
+/// Builds the tree `root(Node) -> [a(Node3D) -> [a1(Node3D), a2(Node)], b(Node3D) -> [b1(Node3D)]]`.
+fn make_typed_tree() -> Gd<Node> {
+    fn add(parent: &mut Gd<Node>, mut child: Gd<Node>, name: &str) -> Gd<Node> {
+        child.set_name(name);
+        parent.add_child(&child);
+        child
+    }
+
+    let mut root = Node::new_alloc();
+    root.set_name("root");
+
+    let mut a = add(&mut root, Node3D::new_alloc().upcast(), "a");
+    add(&mut a, Node3D::new_alloc().upcast(), "a1");
+    add(&mut a, Node::new_alloc(), "a2");
+
+    let mut b = add(&mut root, Node3D::new_alloc().upcast(), "b");
+    add(&mut b, Node3D::new_alloc().upcast(), "b1");
+
+    root
+}
+
+fn names<T: Inherits<Node>>(nodes: impl Iterator<Item = Gd<T>>) -> Vec<String> {
+    nodes
+        .map(|node| node.upcast::<Node>().get_name().to_string())
+        .collect()
+}
+
+#[itest]
+fn node_children_of() {
+    let root = make_typed_tree();
+
+    assert_eq!(names(root.children_of::<Node3D>()), ["a", "b"]);
+    assert_eq!(names(root.children_of::<Node>()), ["a", "b"]);
+
+    let a = root.get_node_as::<Node>("a");
+    assert_eq!(names(a.children_of::<Node3D>()), ["a1"]);
+
+    // Removing children during iteration ends it early, without accessing indices out of range.
+    let mut removed = vec![];
+    for child in root.children_of::<Node>() {
+        root.clone().remove_child(&child);
+        removed.push(child);
+    }
+    assert_eq!(names(removed.iter().cloned()), ["a"]);
+
+    for node in removed {
+        node.free();
+    }
+    root.free();
+}
+
+#[itest]
+fn node_descendants_of() {
+    let root = make_typed_tree();
+
+    assert_eq!(
+        names(root.descendants_of::<Node3D>()),
+        ["a", "a1", "b", "b1"]
+    );
+    assert_eq!(
+        names(root.descendants_of::<Node>()),
+        ["a", "a1", "a2", "b", "b1"]
+    );
+    assert_eq!(
+        names(root.descendants_breadth_first_of::<Node3D>()),
+        ["a", "b", "a1", "b1"]
+    );
+
+    let leaf = root.get_node_as::<Node>("a/a2");
+    assert_eq!(leaf.descendants_of::<Node>().count(), 0);
+
+    root.free();
+}
+
+#[itest]
+fn node_find_child_and_ancestor_of() {
+    let root = make_typed_tree();
+
+    let found = root.find_child_of::<Node3D>("b*").expect("b found");
+    assert_eq!(found.get_name(), "b".into());
+
+    let found = root.find_child_of::<Node3D>("?1").expect("a1 found");
+    assert_eq!(found.get_name(), "a1".into());
+
+    // Name matches, but type does not.
+    assert!(root.find_child_of::<Node3D>("a2").is_none());
+
+    let a1 = root.get_node_as::<Node3D>("a/a1");
+    let ancestor = a1.ancestor_of::<Node3D>().expect("a is Node3D");
+    assert_eq!(ancestor.get_name(), "a".into());
+
+    let a = root.get_node_as::<Node3D>("a");
+    assert!(a.ancestor_of::<Node3D>().is_none());
+    assert_eq!(a.ancestor_of::<Node>(), Some(root.clone()));
+
+    root.free();
+}
+
+#[itest]
+fn node_nodes_in_group_of(ctx: &TestContext) {
+    let root = make_typed_tree();
+    ctx.scene_tree.clone().add_child(&root);
+
+    for mut node in root.descendants_of::<Node>() {
+        node.add_to_group("typed_group");
+    }
+
+    let tree = root.get_tree().unwrap();
+    assert_eq!(
+        names(tree.nodes_in_group_of::<Node3D>("typed_group")),
+        ["a", "a1", "b", "b1"]
+    );
+    assert_eq!(tree.nodes_in_group_of::<Node>("typed_group").count(), 5);
+
+    root.free();
+}
@@ -0,0 +1,96 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::itest;
+use godot::builtin::{Dictionary, GString, Variant};
+use godot::classes::ProjectSettings;
+use godot::global::PropertyHint;
+use godot::meta::ToGodot;
+use godot::register::ProjectSettings;
+use godot::tools::TypedProjectSettings;
+
+#[derive(ProjectSettings, PartialEq, Debug)]
+#[settings(prefix = "itest_plugin", section = "general")]
+struct PluginSettings {
+    #[setting(default = 4.5)]
+    #[export(range = (0.0, 10.0))]
+    max_speed: f64,
+
+    #[setting(default = "info".into(), advanced)]
+    #[export(enum = (error, info, verbose))]
+    log_level: GString,
+
+    #[setting(section = "debug")]
+    draw_paths: bool,
+}
+
+fn find_property_info(name: &str) -> Option<Dictionary> {
+    ProjectSettings::singleton()
+        .get_property_list()
+        .iter_shared()
+        .find(|info| info.get("name") == Some(name.to_variant()))
+}
+
+#[itest]
+fn project_settings_registered() {
+    assert_eq!(
+        PluginSettings::SETTING_NAMES,
+        &[
+            "itest_plugin/general/max_speed",
+            "itest_plugin/general/log_level",
+            "itest_plugin/debug/draw_paths",
+        ]
+    );
+
+    let project_settings = ProjectSettings::singleton();
+    for name in PluginSettings::SETTING_NAMES {
+        assert!(project_settings.has_setting(*name), "missing {name}");
+    }
+
+    let info = find_property_info("itest_plugin/general/max_speed").expect("property info");
+    assert_eq!(
+        info.get("hint"),
+        Some(PropertyHint::RANGE.ord().to_variant())
+    );
+    assert_eq!(info.get("hint_string"), Some("0,10".to_variant()));
+
+    let info = find_property_info("itest_plugin/general/log_level").expect("property info");
+    assert_eq!(
+        info.get("hint"),
+        Some(PropertyHint::ENUM.ord().to_variant())
+    );
+}
+
+#[itest]
+fn project_settings_load() {
+    let defaults = PluginSettings {
+        max_speed: 4.5,
+        log_level: "info".into(),
+        draw_paths: false,
+    };
+    assert_eq!(PluginSettings::load(), defaults);
+
+    let mut project_settings = ProjectSettings::singleton();
+    project_settings.set_setting("itest_plugin/general/max_speed", &7.0.to_variant());
+    project_settings.set_setting("itest_plugin/debug/draw_paths", &true.to_variant());
+
+    let settings = PluginSettings::load();
+    assert_eq!(settings.max_speed, 7.0);
+    assert_eq!(settings.log_level, GString::from("info"));
+    assert!(settings.draw_paths);
+
+    // Registering again keeps current values.
+    PluginSettings::register();
+    assert_eq!(PluginSettings::load().max_speed, 7.0);
+
+    // Setting a value to nil removes it; loading falls back to the default.
+    project_settings.set_setting("itest_plugin/general/max_speed", &Variant::nil());
+    project_settings.set_setting("itest_plugin/debug/draw_paths", &false.to_variant());
+    assert_eq!(PluginSettings::load().max_speed, 4.5);
+
+    PluginSettings::register();
+}
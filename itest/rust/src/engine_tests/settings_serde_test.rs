@@ -0,0 +1,170 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+
+use crate::framework::itest;
+use godot::builtin::{varray, Color, Variant, Vector2i};
+use godot::classes::{ConfigFile, ProjectSettings};
+use godot::global::Error;
+use godot::meta::ToGodot;
+use godot::obj::NewGd;
+use godot::tools::{from_config_file, from_project_settings, to_config_file, to_project_settings};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Settings {
+    audio: Audio,
+    video: Video,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Audio {
+    volume: f32,
+    muted: bool,
+    #[serde(default)]
+    device: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Video {
+    resolution: Vector2i,
+    quality: Quality,
+    tint: Color,
+    scales: Vec<i32>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+enum Quality {
+    Low,
+    High,
+}
+
+fn sample_settings() -> Settings {
+    Settings {
+        audio: Audio {
+            volume: 0.5,
+            muted: false,
+            device: None,
+        },
+        video: Video {
+            resolution: Vector2i::new(1920, 1080),
+            quality: Quality::High,
+            tint: Color::from_rgba(1.0, 0.5, 0.25, 1.0),
+            scales: vec![1, 2, 4],
+        },
+    }
+}
+
+#[itest]
+fn settings_config_file_parse() {
+    let mut config = ConfigFile::new_gd();
+    let err = config.parse(
+        r#"
+[audio]
+volume=0.5
+muted=false
+
+[video]
+resolution=Vector2i(1920, 1080)
+quality="High"
+tint=Color(1, 0.5, 0.25, 1)
+scales=[1, 2, 4]
+"#,
+    );
+    assert_eq!(err, Error::OK);
+
+    let settings: Settings = from_config_file(&config).unwrap();
+    assert_eq!(settings, sample_settings());
+}
+
+#[itest]
+fn settings_config_file_roundtrip() {
+    let settings = sample_settings();
+
+    let mut config = ConfigFile::new_gd();
+    to_config_file(&settings, &mut config).unwrap();
+
+    assert_eq!(config.get_value("audio", "volume"), 0.5.to_variant());
+    assert_eq!(
+        config.get_value("video", "resolution"),
+        Vector2i::new(1920, 1080).to_variant()
+    );
+    assert_eq!(config.get_value("video", "quality"), "High".to_variant());
+    assert!(!config.has_section_key("audio", "device"));
+
+    let back: Settings = from_config_file(&config).unwrap();
+    assert_eq!(back, settings);
+}
+
+#[itest]
+fn settings_config_file_map_section() {
+    let mut config = ConfigFile::new_gd();
+    config.set_value("bindings", "jump", &"space".to_variant());
+    config.set_value("bindings", "fire", &"ctrl".to_variant());
+
+    #[derive(Deserialize)]
+    struct Bindings {
+        bindings: HashMap<String, String>,
+    }
+
+    let bindings: Bindings = from_config_file(&config).unwrap();
+    assert_eq!(bindings.bindings.len(), 2);
+    assert_eq!(bindings.bindings["jump"], "space");
+}
+
+#[itest]
+fn settings_config_file_errors() {
+    let mut config = ConfigFile::new_gd();
+    to_config_file(&sample_settings(), &mut config).unwrap();
+
+    // Wrong type.
+    config.set_value("audio", "volume", &"loud".to_variant());
+    let err = from_config_file::<Settings>(&config).unwrap_err();
+    assert_eq!(err.key(), Some("audio/volume"));
+
+    // Missing key.
+    config.set_value("audio", "volume", &0.5.to_variant());
+    config.erase_section_key("audio", "muted");
+    let err = from_config_file::<Settings>(&config).unwrap_err();
+    assert_eq!(err.key(), Some("audio/muted"));
+    assert!(err.to_string().contains("audio/muted"), "{err}");
+
+    // Wrong element in an array.
+    config.set_value("audio", "muted", &false.to_variant());
+    let scales = varray![1, "two", 4];
+    config.set_value("video", "scales", &scales.to_variant());
+    let err = from_config_file::<Settings>(&config).unwrap_err();
+    assert_eq!(err.key(), Some("video/scales[1]"));
+}
+
+#[itest]
+fn settings_project_settings_roundtrip() {
+    let settings = sample_settings();
+    to_project_settings(&settings, "itest_settings").unwrap();
+
+    let mut project_settings = ProjectSettings::singleton();
+    assert_eq!(
+        project_settings.get_setting("itest_settings/audio/volume"),
+        0.5.to_variant()
+    );
+
+    let back: Settings = from_project_settings("itest_settings").unwrap();
+    assert_eq!(back, settings);
+
+    for name in [
+        "audio/volume",
+        "audio/muted",
+        "video/resolution",
+        "video/quality",
+        "video/tint",
+        "video/scales",
+    ] {
+        // Setting a value to nil removes it.
+        project_settings.set_setting(&format!("itest_settings/{name}"), &Variant::nil());
+    }
+}
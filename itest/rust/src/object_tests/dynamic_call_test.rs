@@ -5,9 +5,9 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use godot::builtin::{Variant, Vector3};
+use godot::builtin::{GString, Variant, Vector3};
 use godot::classes::{Node, Node3D, Object};
-use godot::meta::error::CallError;
+use godot::meta::error::{CallError, CallErrorKind};
 use godot::meta::{FromGodot, ToGodot};
 use godot::obj::{InstanceId, NewAlloc};
 use std::error::Error;
@@ -312,3 +312,57 @@ fn dynamic_call_return_mismatch() {
     // The parser will fail since it knows the signature of take_1_int(). And if we enforce `: Variant` type hints, it will just
     // cause a runtime error, but that's entirely handled in GDScript.
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Typed dynamic calls
+
+#[itest]
+fn dynamic_call_typed() {
+    let mut node = Node3D::new_alloc();
+
+    let expected_pos = Vector3::new(2.5, 6.42, -1.11);
+    node.call_typed::<_, ()>("set_position", (expected_pos,))
+        .expect("set_position");
+
+    let actual_pos: Vector3 = node.call_typed("get_position", ()).expect("get_position");
+    assert_eq!(actual_pos, expected_pos);
+
+    let obj = ObjPayload::new_alloc();
+    let result = obj
+        .clone()
+        .upcast::<Object>()
+        .call_typed::<(i64,), i64>("take_1_int", (21,));
+    assert_eq!(result.expect("take_1_int"), 21);
+
+    obj.free();
+    node.free();
+}
+
+#[itest]
+fn dynamic_call_typed_errors() {
+    let mut obj = ObjPayload::new_alloc();
+
+    let err = obj
+        .call_typed::<(), ()>("no_such_method", ())
+        .expect_err("unknown method");
+    assert_eq!(err.kind(), CallErrorKind::MethodNotFound);
+    assert_eq!(err.class_name(), Some("ObjPayload"));
+    assert_eq!(err.method_name(), "no_such_method");
+
+    let err = obj
+        .call_typed::<(i64, i64), i64>("take_1_int", (1, 2))
+        .expect_err("too many arguments");
+    assert_eq!(err.kind(), CallErrorKind::ArgumentCount);
+
+    let err = obj
+        .call_typed::<(GString,), i64>("take_1_int", ("abc".into(),))
+        .expect_err("wrong argument type");
+    assert_eq!(err.kind(), CallErrorKind::ArgumentConversion);
+
+    let err = obj
+        .call_typed::<(i64,), GString>("take_1_int", (5,))
+        .expect_err("wrong return type");
+    assert_eq!(err.kind(), CallErrorKind::ReturnConversion);
+
+    obj.free();
+}
@@ -0,0 +1,155 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Array, Dictionary, GString, StringName, Variant, Vector2};
+use godot::classes::IObject;
+use godot::global::{PropertyHint, PropertyUsageFlags};
+use godot::meta::{PropertyInfo, ToGodot};
+use godot::obj::{Base, Gd, NewAlloc};
+use godot::register::property::DynamicProperties;
+use godot::register::{godot_api, GodotClass};
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(base = Object)]
+struct DynamicPropertiesTest {
+    props: DynamicProperties,
+    base: Base<Object>,
+}
+
+#[godot_api]
+impl IObject for DynamicPropertiesTest {
+    fn init(base: Base<Object>) -> Self {
+        let mut props = DynamicProperties::new();
+        props.add_group("Stats", "stats/");
+        props
+            .add("stats/health", 100i64)
+            .range(0.0, 200.0)
+            .validate_value(|health| *health >= 0);
+        props.add("stats/name", GString::from("Orc"));
+        props.add("locked", false);
+        props.add("secret", 7).validate_info(|props, info| {
+            if props.get::<bool>("locked") == Some(true) {
+                info.usage |= PropertyUsageFlags::READ_ONLY;
+            }
+        });
+
+        Self { props, base }
+    }
+
+    fn get_property_list(&mut self) -> Vec<PropertyInfo> {
+        self.props.property_list()
+    }
+
+    fn get_property(&self, property: StringName) -> Option<Variant> {
+        self.props.get_property(&property)
+    }
+
+    fn set_property(&mut self, property: StringName, value: Variant) -> bool {
+        self.props.set_property(&property, &value)
+    }
+
+    fn property_get_revert(&self, property: StringName) -> Option<Variant> {
+        self.props.property_get_revert(&property)
+    }
+
+    fn validate_property(&self, property: &mut PropertyInfo) {
+        self.props.validate_property(property);
+    }
+}
+
+fn find_property(obj: &Gd<DynamicPropertiesTest>, name: &str) -> Option<Dictionary> {
+    let properties: Array<Dictionary> = obj.get_property_list();
+    properties
+        .iter_shared()
+        .find(|dict| dict.get("name").is_some_and(|v| v.to_string() == name))
+}
+
+#[itest]
+fn dynamic_properties_get_set() {
+    let mut obj = DynamicPropertiesTest::new_alloc();
+
+    assert_eq!(obj.get("stats/health"), 100.to_variant());
+    assert_eq!(obj.get("stats/name"), "Orc".to_variant());
+
+    obj.set("stats/health", &50.to_variant());
+    assert_eq!(obj.bind().props.get::<i64>("stats/health"), Some(50));
+
+    // Rejected by validator.
+    obj.set("stats/health", &(-1).to_variant());
+    assert_eq!(obj.get("stats/health"), 50.to_variant());
+
+    // Rejected because of incompatible type.
+    obj.set("stats/name", &Vector2::new(1.0, 2.0).to_variant());
+    assert_eq!(obj.get("stats/name"), "Orc".to_variant());
+
+    // Typed access from Rust goes through the same checks.
+    assert!(obj
+        .bind_mut()
+        .props
+        .set("stats/name", GString::from("Goblin")));
+    assert!(!obj.bind_mut().props.set("stats/health", -5));
+    assert!(!obj.bind_mut().props.set("unknown", 1));
+    assert_eq!(obj.get("stats/name"), "Goblin".to_variant());
+
+    obj.free();
+}
+
+#[itest]
+fn dynamic_properties_revert() {
+    let mut obj = DynamicPropertiesTest::new_alloc();
+    obj.set("stats/health", &150.to_variant());
+
+    assert!(obj.property_can_revert("stats/health"));
+    assert_eq!(obj.property_get_revert("stats/health"), 100.to_variant());
+    assert!(!obj.property_can_revert("unknown"));
+
+    obj.free();
+}
+
+#[itest]
+fn dynamic_properties_list() {
+    let mut obj = DynamicPropertiesTest::new_alloc();
+
+    let group = find_property(&obj, "Stats").expect("group is listed");
+    assert_eq!(
+        group.get("usage").unwrap().to::<PropertyUsageFlags>(),
+        PropertyUsageFlags::GROUP
+    );
+
+    let health = find_property(&obj, "stats/health").expect("property is listed");
+    assert_eq!(
+        health.get("hint").unwrap().to::<PropertyHint>(),
+        PropertyHint::RANGE
+    );
+    assert_eq!(
+        health.get("hint_string").unwrap().to::<GString>(),
+        "0,200".into()
+    );
+
+    // validate_property() reacts to the current value of another property.
+    let usage = |obj: &Gd<DynamicPropertiesTest>| {
+        find_property(obj, "secret")
+            .unwrap()
+            .get("usage")
+            .unwrap()
+            .to::<PropertyUsageFlags>()
+    };
+    assert_eq!(usage(&obj), PropertyUsageFlags::DEFAULT);
+
+    obj.set("locked", &true.to_variant());
+    assert_eq!(
+        usage(&obj),
+        PropertyUsageFlags::DEFAULT | PropertyUsageFlags::READ_ONLY
+    );
+
+    obj.bind_mut().props.remove("secret");
+    assert!(find_property(&obj, "secret").is_none());
+
+    obj.free();
+}
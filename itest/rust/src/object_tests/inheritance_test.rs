@@ -0,0 +1,260 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Tests for user classes inheriting other user classes.
+
+use crate::framework::itest;
+use godot::classes::notify::NodeNotification;
+use godot::classes::{ClassDb, INode, Node};
+use godot::obj::{Gd, NewAlloc, OnReady, WithBaseField};
+use godot::prelude::*;
+
+#[itest]
+fn inherit_rust_class_hierarchy() {
+    let obj = InheritDerived::new_alloc();
+
+    assert!(obj.is_class("InheritDerived"));
+    assert!(obj.is_class("InheritBase"));
+    assert!(obj.is_class("Node"));
+    assert_eq!(obj.get_class(), GString::from("InheritDerived"));
+
+    let db = ClassDb::singleton();
+    assert_eq!(
+        db.get_parent_class("InheritDerived"),
+        StringName::from("InheritBase")
+    );
+    assert_eq!(db.get_parent_class("InheritBase"), StringName::from("Node"));
+
+    assert!(InheritDerived::inherits::<InheritBase>());
+    assert!(InheritDerived::inherits::<Node>());
+
+    obj.free();
+}
+
+#[itest]
+fn inherit_rust_class_bind_levels() {
+    let mut obj = InheritDerived::new_alloc();
+
+    // Both levels are initialized through their own init.
+    assert_eq!(obj.bind().phase, 1);
+    assert_eq!(obj.clone().upcast::<InheritBase>().bind().health, 100);
+
+    obj.bind_mut().phase = 2;
+
+    let mut as_base: Gd<InheritBase> = obj.clone().upcast();
+    as_base.bind_mut().health = 50;
+
+    // Binding both levels at the same time is allowed, since they are separate Rust instances.
+    {
+        let derived = obj.bind();
+        let base = as_base.bind();
+        assert_eq!(derived.phase, 2);
+        assert_eq!(base.health, 50);
+    }
+
+    let downcast = as_base.clone().cast::<InheritDerived>();
+    assert_eq!(downcast.bind().phase, 2);
+
+    obj.free();
+}
+
+#[itest]
+fn inherit_rust_class_base_access() {
+    let mut obj = InheritDerived::new_alloc();
+
+    obj.bind_mut().damage_through_base(30);
+    assert_eq!(obj.clone().upcast::<InheritBase>().bind().health, 70);
+
+    obj.free();
+}
+
+#[itest]
+fn inherit_rust_class_funcs_and_vars() {
+    let mut obj = InheritDerived::new_alloc();
+
+    // Inherited #[func].
+    let health = obj.call("get_health_percent", &[]);
+    assert_eq!(health, 100.to_variant());
+
+    // Overridden #[func].
+    assert_eq!(obj.call("describe", &[]), "derived".to_variant());
+
+    let mut base = InheritBase::new_alloc();
+    assert_eq!(base.call("describe", &[]), "base".to_variant());
+    base.free();
+
+    // Inherited #[var].
+    obj.set("health", &42.to_variant());
+    assert_eq!(obj.get("health"), 42.to_variant());
+    assert_eq!(obj.clone().upcast::<InheritBase>().bind().health, 42);
+
+    obj.free();
+}
+
+#[itest]
+fn inherit_rust_class_virtuals() {
+    let mut obj = InheritDerived::new_alloc();
+
+    // to_string() is only implemented by the base.
+    assert_eq!(obj.to_string(), "InheritBase(health=100)");
+
+    // ready() is only implemented by the base, OnReady fields are initialized on both levels.
+    obj.notify(NodeNotification::READY);
+    assert!(obj.clone().upcast::<InheritBase>().bind().is_ready);
+    assert_eq!(*obj.bind().ready_value, 7);
+
+    obj.free();
+}
+
+#[itest]
+fn inherit_rust_class_overridden_virtual() {
+    let mut obj = InheritOverride::new_alloc();
+
+    obj.notify(NodeNotification::READY);
+    assert!(obj.bind().is_ready);
+    assert!(!obj.clone().upcast::<InheritBase>().bind().is_ready);
+
+    obj.free();
+}
+
+#[itest]
+fn inherit_rust_class_from_init_fn() {
+    let obj = Gd::from_init_fn(|base| InheritDerived {
+        phase: 9,
+        ready_value: OnReady::new(|| 7),
+        base,
+    });
+
+    assert_eq!(obj.bind().phase, 9);
+    assert_eq!(obj.clone().upcast::<InheritBase>().bind().health, 100);
+
+    obj.free();
+}
+
+#[itest]
+fn inherit_class_named_like_engine_class() {
+    let obj = shadowing::Timer::new_alloc();
+
+    assert_eq!(obj.bind().ticks, 7);
+    assert!(obj.is_class("InheritShadowTimer"));
+    assert!(!obj.is_class("Timer"));
+
+    obj.free();
+}
+
+#[itest]
+fn inherit_base_named_like_engine_class() {
+    // Documented limitation: `base=Timer` refers to the engine class, even if a Rust class `Timer` is in scope.
+    let obj = shadowing::InheritShadowedBase::new_alloc();
+
+    assert!(obj.is_class("Timer"));
+    assert!(!obj.is_class("InheritShadowTimer"));
+    assert_eq!(
+        ClassDb::singleton().get_parent_class("InheritShadowedBase"),
+        StringName::from("Timer")
+    );
+
+    obj.free();
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+mod shadowing {
+    use godot::prelude::*;
+
+    // Rust name equal to an engine class; must not be shadowed by engine classes that are in scope for the base.
+    #[derive(GodotClass)]
+    #[class(rename = InheritShadowTimer, init, base = Node)]
+    pub struct Timer {
+        #[init(val = 7)]
+        pub ticks: i32,
+    }
+
+    // As a base, engine classes take precedence over Rust classes of the same name.
+    #[derive(GodotClass)]
+    #[class(init, base = Timer)]
+    pub struct InheritShadowedBase {
+        base: Base<godot::classes::Timer>,
+    }
+}
+
+#[derive(GodotClass)]
+#[class(base=Node)]
+struct InheritBase {
+    #[var]
+    health: i64,
+    is_ready: bool,
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl INode for InheritBase {
+    fn init(base: Base<Node>) -> Self {
+        Self {
+            health: 100,
+            is_ready: false,
+            base,
+        }
+    }
+
+    fn ready(&mut self) {
+        self.is_ready = true;
+    }
+
+    fn to_string(&self) -> GString {
+        format!("InheritBase(health={})", self.health).into()
+    }
+}
+
+#[godot_api]
+impl InheritBase {
+    #[func]
+    fn get_health_percent(&self) -> i64 {
+        self.health
+    }
+
+    #[func]
+    fn describe(&self) -> GString {
+        "base".into()
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=InheritBase)]
+struct InheritDerived {
+    #[init(val = 1)]
+    phase: u8,
+    #[init(val = OnReady::new(|| 7))]
+    ready_value: OnReady<i32>,
+    base: Base<InheritBase>,
+}
+
+#[godot_api]
+impl InheritDerived {
+    #[func]
+    fn describe(&self) -> GString {
+        "derived".into()
+    }
+
+    fn damage_through_base(&mut self, amount: i64) {
+        self.base_mut().bind_mut().health -= amount;
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=InheritBase)]
+struct InheritOverride {
+    is_ready: bool,
+    base: Base<InheritBase>,
+}
+
+#[godot_api]
+impl INode for InheritOverride {
+    fn ready(&mut self) {
+        self.is_ready = true;
+    }
+}
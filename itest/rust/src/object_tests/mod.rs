@@ -10,19 +10,31 @@ mod class_name_test;
 mod class_rename_test;
 mod dyn_gd_test;
 mod dynamic_call_test;
+// Relies on `get_property_list`, which is only supported in Godot 4.3+.
+#[cfg(since_api = "4.3")]
+mod dynamic_properties_test;
 mod enum_test;
 // `get_property_list` is only supported in Godot 4.3+
 #[cfg(since_api = "4.3")]
 mod get_property_list_test;
+mod inheritance_test;
 mod init_level_test;
 mod object_arg_test;
 mod object_swap_test;
 mod object_test;
 mod oneditor_test;
 mod onready_test;
+// Flattened properties are registered as indexed properties, only supported in Godot 4.2+.
+#[cfg(since_api = "4.2")]
+mod property_flatten_test;
+// Change notification emits typed signals, which are only supported in Godot 4.2+.
+#[cfg(since_api = "4.2")]
+mod property_notify_test;
 mod property_template_test;
 mod property_test;
+mod proxy_test;
 mod reentrant_test;
+mod scene_nodes_test;
 mod singleton_test;
 // `validate_property` is only supported in Godot 4.2+.
 #[cfg(since_api = "4.2")]
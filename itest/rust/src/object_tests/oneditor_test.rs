@@ -6,11 +6,14 @@
  */
 
 use crate::framework::{expect_panic, itest};
+use godot::builtin::{GString, PackedStringArray};
 use godot::classes::notify::NodeNotification;
 use godot::classes::{INode, Node, RefCounted};
+use godot::meta::ToGodot;
 use godot::register::{godot_api, GodotClass};
 
-use godot::obj::{Gd, NewAlloc, OnEditor};
+use godot::obj::cap::ImplementsGodotVirtual;
+use godot::obj::{Base, Gd, NewAlloc, OnEditor, UserClass};
 
 #[itest]
 fn oneditor_deref() {
@@ -64,6 +67,97 @@ fn oneditor_no_panic_on_ready() {
     obj.free();
 }
 
+#[itest]
+fn oneditor_configuration_warnings() {
+    let mut obj = OnEditorTool::new_alloc();
+
+    let warnings = obj.bind().__configuration_warnings();
+    assert_eq!(
+        warnings.as_slice(),
+        &[
+            GString::from("Required property `some_primitive` has not been set."),
+            GString::from("Required property `node_field` has not been set."),
+        ]
+    );
+
+    obj.set("some_primitive", &7.to_variant());
+    assert_eq!(obj.bind().__configuration_warnings().len(), 1);
+
+    obj.free();
+}
+
+#[itest]
+fn oneditor_configuration_warnings_merged() {
+    let obj = OnEditorToolWithWarnings::new_alloc();
+
+    let warnings = obj.bind().get_configuration_warnings();
+    assert_eq!(
+        warnings.as_slice(),
+        &[
+            GString::from("Custom warning."),
+            GString::from("Required property `node_field` has not been set."),
+        ]
+    );
+
+    obj.free();
+}
+
+#[itest]
+fn oneditor_configuration_warnings_virtual() {
+    // Without I* impl: looked up via default_get_virtual().
+    assert!(OnEditorTool::__default_virtual_call(
+        "_get_configuration_warnings",
+        #[cfg(since_api = "4.4")]
+        godot::sys::known_virtual_hashes::Node::get_configuration_warnings,
+    )
+    .is_some());
+
+    // With I* impl, but without get_configuration_warnings().
+    assert!(has_virtual::<OnEditorToolWithProcess>(
+        "_get_configuration_warnings"
+    ));
+    assert!(has_virtual::<OnEditorToolWithProcess>("_process"));
+
+    // User-defined get_configuration_warnings().
+    assert!(has_virtual::<OnEditorToolWithWarnings>(
+        "_get_configuration_warnings"
+    ));
+
+    // Not a tool class, thus no generated warnings.
+    assert!(!has_virtual::<OnEditorNoDefault>(
+        "_get_configuration_warnings"
+    ));
+}
+
+#[itest]
+fn oneditor_unrelated_virtuals_not_resolved() {
+    assert!(!has_virtual::<OnEditorToolWithProcess>("_physics_process"));
+    assert!(!has_virtual::<OnEditorToolWithProcess>("_input"));
+    assert!(!has_virtual::<OnEditorToolWithWarnings>("_process"));
+}
+
+/// Whether Godot finds an implementation of the given `Node` virtual method.
+fn has_virtual<T: ImplementsGodotVirtual>(method_name: &str) -> bool {
+    #[cfg(since_api = "4.4")]
+    use godot::sys::known_virtual_hashes::Node as hashes;
+
+    #[cfg(since_api = "4.4")]
+    let hash = match method_name {
+        "_get_configuration_warnings" => hashes::get_configuration_warnings,
+        "_process" => hashes::process,
+        "_physics_process" => hashes::physics_process,
+        "_input" => hashes::input,
+        _ => unreachable!("unknown method {method_name}"),
+    };
+
+    T::__virtual_call(
+        method_name,
+        #[cfg(since_api = "4.4")]
+        hash,
+    )
+    .is_some()
+}
+
 #[derive(GodotClass)]
 #[class(init, base=Node)]
 struct OnEditorNoDefault {
@@ -83,3 +177,43 @@ impl INode for OnEditorNoDefault {
         self.was_ready_run = true;
     }
 }
+
+#[derive(GodotClass)]
+#[class(init, tool, base=Node)]
+struct OnEditorTool {
+    #[export]
+    #[init(sentinel = 0)]
+    some_primitive: OnEditor<i64>,
+    #[export]
+    node_field: OnEditor<Gd<Node>>,
+
+    base: Base<Node>,
+}
+
+#[derive(GodotClass)]
+#[class(init, tool, base=Node)]
+struct OnEditorToolWithWarnings {
+    #[export]
+    node_field: OnEditor<Gd<Node>>,
+}
+
+#[godot_api]
+impl INode for OnEditorToolWithWarnings {
+    fn get_configuration_warnings(&self) -> PackedStringArray {
+        let mut warnings = PackedStringArray::new();
+        warnings.push("Custom warning.");
+        warnings
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, tool, base=Node)]
+struct OnEditorToolWithProcess {
+    #[export]
+    node_field: OnEditor<Gd<Node>>,
+}
+
+#[godot_api]
+impl INode for OnEditorToolWithProcess {
+    fn process(&mut self, _delta: f64) {}
+}
@@ -0,0 +1,119 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Dictionary, GString, VariantType};
+use godot::classes::{Node, Object};
+use godot::global::{PropertyHint, PropertyUsageFlags};
+use godot::meta::ToGodot;
+use godot::obj::{EngineBitfield, EngineEnum, Gd, NewAlloc};
+use godot::register::{GodotClass, PropertyGroup};
+use godot::test::itest;
+
+#[derive(PropertyGroup, Default)]
+struct Movement {
+    #[export(range = (0.0, 500.0))]
+    speed: f32,
+    accel: f32,
+    #[var(get = get_label, set = set_label)]
+    label: GString,
+}
+
+impl Movement {
+    fn get_label(&self) -> GString {
+        format!("[{}]", self.label).into()
+    }
+
+    fn set_label(&mut self, label: GString) {
+        self.label = label;
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct FlattenedProperties {
+    #[export(flatten)]
+    movement: Movement,
+
+    #[var(flatten)]
+    hidden_movement: Movement,
+
+    #[export]
+    after: i32,
+}
+
+fn find_property(object: &Gd<impl godot::obj::Inherits<Object>>, name: &str) -> Option<Dictionary> {
+    object
+        .upcast_ref::<Object>()
+        .get_property_list()
+        .iter_shared()
+        .find(|dict| dict.get_or_nil("name") == name.to_variant())
+}
+
+#[itest]
+fn property_flatten_get_set() {
+    let mut obj = FlattenedProperties::new_alloc();
+
+    obj.set("movement/speed", &120.5.to_variant());
+    obj.set("movement/accel", &3.0.to_variant());
+    obj.set("hidden_movement/speed", &7.0.to_variant());
+    obj.set("movement/label", &"fast".to_variant());
+
+    {
+        let flattened = obj.bind();
+        assert_eq!(flattened.movement.speed, 120.5);
+        assert_eq!(flattened.movement.accel, 3.0);
+        assert_eq!(flattened.hidden_movement.speed, 7.0);
+        assert_eq!(flattened.movement.label, GString::from("fast"));
+    }
+
+    assert_eq!(obj.get("movement/speed"), 120.5.to_variant());
+    assert_eq!(obj.get("movement/label"), "[fast]".to_variant());
+
+    obj.free();
+}
+
+#[itest]
+fn property_flatten_registration() {
+    let obj = FlattenedProperties::new_alloc();
+
+    let group = find_property(&obj, "movement").expect("inspector group");
+    assert_eq!(
+        group.get_or_nil("usage"),
+        PropertyUsageFlags::GROUP.ord().to_variant()
+    );
+    assert_eq!(group.get_or_nil("hint_string"), "movement/".to_variant());
+
+    let speed = find_property(&obj, "movement/speed").expect("movement/speed");
+    assert_eq!(
+        speed.get_or_nil("type"),
+        VariantType::FLOAT.ord().to_variant()
+    );
+    assert_eq!(
+        speed.get_or_nil("hint"),
+        PropertyHint::RANGE.ord().to_variant()
+    );
+    assert_eq!(
+        speed.get_or_nil("usage"),
+        PropertyUsageFlags::DEFAULT.ord().to_variant()
+    );
+
+    // Inner fields without #[export] follow the outer attribute.
+    let accel = find_property(&obj, "movement/accel").expect("movement/accel");
+    assert_eq!(
+        accel.get_or_nil("usage"),
+        PropertyUsageFlags::DEFAULT.ord().to_variant()
+    );
+    let hidden_accel = find_property(&obj, "hidden_movement/accel").expect("hidden_movement/accel");
+    assert_eq!(
+        hidden_accel.get_or_nil("usage"),
+        PropertyUsageFlags::NONE.ord().to_variant()
+    );
+
+    assert!(find_property(&obj, "after").is_some());
+
+    obj.free();
+}
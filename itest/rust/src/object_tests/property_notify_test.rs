@@ -0,0 +1,97 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use godot::builtin::{GString, StringName};
+use godot::classes::Node;
+use godot::meta::ToGodot;
+use godot::obj::{Base, NewAlloc};
+use godot::register::{godot_api, GodotClass};
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct NotifyingPlayer {
+    #[export(notify)]
+    speed: f32,
+
+    #[var(notify = on_health_changed, notify_signal = stats_changed)]
+    health: i32,
+
+    #[var(notify_signal = stats_changed)]
+    title: GString,
+
+    health_hook_calls: u32,
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl NotifyingPlayer {
+    #[signal]
+    fn speed_changed(value: f32);
+
+    #[signal]
+    fn stats_changed(property: StringName);
+
+    fn on_health_changed(&mut self) {
+        self.health_hook_calls += 1;
+    }
+}
+
+#[itest]
+fn property_notify_signals() {
+    let mut player = NotifyingPlayer::new_alloc();
+
+    let speeds = Rc::new(RefCell::new(vec![]));
+    let stats = Rc::new(RefCell::new(vec![]));
+    {
+        let speeds = speeds.clone();
+        player
+            .signals()
+            .speed_changed()
+            .connect(move |value| speeds.borrow_mut().push(value));
+
+        let stats = stats.clone();
+        player
+            .signals()
+            .stats_changed()
+            .connect(move |property| stats.borrow_mut().push(property.to_string()));
+    }
+
+    player.set("speed", &2.5.to_variant());
+    player.bind_mut().set_speed(2.5); // Unchanged: no signal.
+    player.bind_mut().set_speed(4.0);
+    assert_eq!(*speeds.borrow(), [2.5, 4.0]);
+
+    player.set("title", &"Hero".to_variant());
+    player.bind_mut().set_health(10);
+    player.bind_mut().set_health(10);
+    assert_eq!(*stats.borrow(), ["title", "health"]);
+
+    // Values are stored even with notification.
+    assert_eq!(player.bind().get_speed(), 4.0);
+    assert_eq!(player.bind().get_title(), GString::from("Hero"));
+
+    player.free();
+}
+
+#[itest]
+fn property_notify_hook() {
+    let mut player = NotifyingPlayer::new_alloc();
+
+    player.set("health", &100.to_variant());
+    player.set("health", &100.to_variant());
+    player.set("health", &50.to_variant());
+
+    assert_eq!(player.bind().health, 50);
+    assert_eq!(player.bind().health_hook_calls, 2);
+
+    player.free();
+}
@@ -0,0 +1,133 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::GString;
+use godot::classes::RefCounted;
+use godot::meta::error::CallError;
+use godot::obj::{GodotProxy, NewGd};
+use godot::register::{godot_api, godot_proxy, GodotClass};
+
+use crate::framework::{expect_panic, itest};
+
+#[godot_proxy]
+trait EnemyApi {
+    fn take_damage(&mut self, amount: i32) -> bool;
+    fn get_health(&self) -> i32;
+    fn rename(&mut self, name: GString);
+    fn get_name(&self) -> Result<GString, CallError>;
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct ProxyEnemy {
+    #[init(val = 100)]
+    health: i32,
+    name: GString,
+}
+
+#[godot_api]
+impl ProxyEnemy {
+    #[func]
+    fn take_damage(&mut self, amount: i32) -> bool {
+        self.health -= amount;
+        self.health <= 0
+    }
+
+    #[func]
+    fn get_health(&self) -> i32 {
+        self.health
+    }
+
+    #[func]
+    fn rename(&mut self, name: GString, #[opt(default = false)] _loud: bool) {
+        self.name = name;
+    }
+
+    // Returns a Variant that cannot be converted to GString, to test error propagation.
+    #[func]
+    fn get_name(&self) -> i64 {
+        42
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct ProxyMismatchedEnemy {}
+
+#[godot_api]
+impl ProxyMismatchedEnemy {
+    #[func]
+    fn take_damage(&mut self) -> bool {
+        false
+    }
+
+    #[func]
+    fn get_health(&self, _scale: f32) -> i32 {
+        0
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Tests
+
+#[itest]
+fn proxy_calls() {
+    let enemy = ProxyEnemy::new_gd();
+    let mut proxy = EnemyApiProxy::from_object(enemy.clone());
+
+    assert_eq!(proxy.get_health(), 100);
+    assert!(!proxy.take_damage(30));
+    assert_eq!(proxy.get_health(), 70);
+    assert!(proxy.take_damage(70));
+
+    // Method with optional parameter, called with fewer arguments.
+    proxy.rename("Goblin".into());
+    assert_eq!(enemy.bind().name, GString::from("Goblin"));
+
+    assert_eq!(proxy.object().instance_id(), enemy.instance_id());
+    assert_eq!(proxy.into_object().cast::<ProxyEnemy>(), enemy);
+}
+
+#[itest]
+fn proxy_try_call_conversion_error() {
+    let proxy = EnemyApiProxy::from_object(ProxyEnemy::new_gd());
+
+    let err = proxy.get_name().expect_err("i64 -> GString must fail");
+    assert_eq!(err.class_name(), Some("EnemyApi"));
+    assert_eq!(err.method_name(), "get_name");
+}
+
+#[itest]
+fn proxy_missing_methods() {
+    let object = RefCounted::new_gd();
+
+    let err = EnemyApiProxy::try_from_object(object.clone()).expect_err("no methods");
+    assert_eq!(err.proxy_name(), "EnemyApi");
+    assert_eq!(err.class_name(), "RefCounted");
+    assert_eq!(
+        err.missing_methods().collect::<Vec<_>>(),
+        ["take_damage", "get_health", "rename", "get_name"]
+    );
+
+    expect_panic("from_object() with missing methods", || {
+        EnemyApiProxy::from_object(object);
+    });
+}
+
+#[itest]
+fn proxy_mismatched_methods() {
+    let err = EnemyApiProxy::try_from_object(ProxyMismatchedEnemy::new_gd())
+        .expect_err("wrong parameter counts");
+    assert_eq!(
+        err.mismatched_methods().collect::<Vec<_>>(),
+        ["take_damage", "get_health"]
+    );
+    assert_eq!(
+        err.missing_methods().collect::<Vec<_>>(),
+        ["rename", "get_name"]
+    );
+}
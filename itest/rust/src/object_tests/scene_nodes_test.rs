@@ -0,0 +1,144 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::notify::NodeNotification;
+use godot::classes::{Control, HBoxContainer, Label, Node, PackedScene, ProgressBar};
+use godot::obj::{Base, Gd, NewAlloc, OnReady, SceneNodes};
+use godot::register::{GodotClass, SceneNodes};
+use godot::tools::load;
+
+use crate::framework::{expect_panic, itest};
+
+#[derive(SceneNodes)]
+#[scene_nodes(tscn = "../godot/SceneNodesTest.tscn", check_types)]
+struct HudNodes {
+    #[node("Top/Health")]
+    health: Gd<ProgressBar>,
+
+    #[node("Top/Score")]
+    score: Gd<Label>,
+
+    #[node("Top")]
+    top: Gd<Control>, // Declared as base class of HBoxContainer.
+
+    #[node("Debug")]
+    debug: Option<Gd<Label>>,
+
+    refreshes: u32,
+}
+
+#[derive(SceneNodes)]
+struct BrokenNodes {
+    #[node("Top/Health")]
+    _health: Gd<Label>,
+
+    #[node("Top/Missing")]
+    _missing: Gd<Node>,
+
+    #[node("Top/Score")]
+    _score: Option<Gd<ProgressBar>>,
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Control)]
+struct Hud {
+    #[init(scene_nodes)]
+    nodes: OnReady<HudNodes>,
+    base: Base<Control>,
+}
+
+fn make_hud_tree() -> Gd<Control> {
+    let mut root = Control::new_alloc();
+    populate_hud(&mut root);
+    root
+}
+
+fn populate_hud(root: &mut Gd<Control>) {
+    let mut top = HBoxContainer::new_alloc();
+    top.set_name("Top");
+
+    let mut health = ProgressBar::new_alloc();
+    health.set_name("Health");
+    top.add_child(&health);
+
+    let mut score = Label::new_alloc();
+    score.set_name("Score");
+    top.add_child(&score);
+
+    root.add_child(&top);
+}
+
+#[itest]
+fn scene_nodes_bind() {
+    let root = make_hud_tree();
+
+    let nodes = HudNodes::from_node(&root);
+    assert_eq!(nodes.health.get_name(), "Health".into());
+    assert_eq!(nodes.score.get_name(), "Score".into());
+    assert_eq!(nodes.top.get_class(), "HBoxContainer".into());
+    assert!(nodes.debug.is_none());
+    assert_eq!(nodes.refreshes, 0);
+
+    let mut debug = Label::new_alloc();
+    debug.set_name("Debug");
+    root.clone().add_child(&debug);
+
+    let nodes = HudNodes::from_node(&root);
+    assert_eq!(nodes.debug, Some(debug));
+
+    root.free();
+}
+
+#[itest]
+fn scene_nodes_aggregated_error() {
+    let mut root = make_hud_tree();
+    root.set_name("HudRoot");
+
+    let err = BrokenNodes::try_from_node(&root)
+        .err()
+        .expect("binding must fail");
+
+    assert_eq!(err.struct_name(), "BrokenNodes");
+    assert_eq!(err.root_path(), "HudRoot");
+    assert_eq!(err.missing_paths().collect::<Vec<_>>(), ["Top/Missing"]);
+    assert_eq!(
+        err.mismatched_paths().collect::<Vec<_>>(),
+        ["Top/Health", "Top/Score"]
+    );
+
+    let message = err.to_string();
+    assert!(message.contains("missing node `Top/Missing` (expected Node)"));
+    assert!(message.contains("node `Top/Health` is ProgressBar, expected Label"));
+
+    expect_panic("from_node() with missing nodes", || {
+        BrokenNodes::from_node(&root);
+    });
+
+    root.free();
+}
+
+#[itest]
+fn scene_nodes_from_packed_scene() {
+    let scene = load::<PackedScene>("res://SceneNodesTest.tscn");
+    let root = scene.instantiate().expect("instantiate scene");
+
+    let nodes = HudNodes::from_node(&root);
+    assert_eq!(nodes.health.get_name(), "Health".into());
+
+    root.free();
+}
+
+#[itest]
+fn scene_nodes_onready() {
+    let mut hud = Hud::new_alloc();
+    populate_hud(&mut hud.clone().upcast());
+
+    hud.notify(NodeNotification::READY);
+    assert_eq!(hud.bind().nodes.score.get_name(), "Score".into());
+
+    hud.free();
+}
@@ -0,0 +1,74 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::{ClassDb, Node, RefCounted};
+use godot::obj::{Base, NewAlloc, NewGd};
+use godot::register::GodotClass;
+
+use crate::framework::{itest, suppress_godot_print};
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(GodotClass)]
+#[class(init, abstract, base=Node)]
+struct AbstractWeapon {
+    #[init(val = 10)]
+    damage: i32,
+    base: Base<Node>,
+}
+
+#[derive(GodotClass)]
+#[class(init, base=AbstractWeapon)]
+struct AbstractWeaponSword {
+    base: Base<AbstractWeapon>,
+}
+
+#[cfg(since_api = "4.3")]
+#[derive(GodotClass)]
+#[class(init, runtime, base=RefCounted)]
+struct RuntimeOnlyClass {
+    base: Base<RefCounted>,
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[itest]
+fn abstract_class_not_instantiable() {
+    let db = ClassDb::singleton();
+
+    assert!(db.class_exists("AbstractWeapon"));
+    assert!(!db.can_instantiate("AbstractWeapon"));
+
+    suppress_godot_print(|| {
+        assert!(db.instantiate("AbstractWeapon").is_nil());
+    });
+
+    // Derived classes are instantiable.
+    assert!(db.can_instantiate("AbstractWeaponSword"));
+}
+
+#[itest]
+fn abstract_class_as_rust_base() {
+    let sword = AbstractWeaponSword::new_alloc();
+    let weapon = sword.clone().upcast::<AbstractWeapon>();
+
+    assert_eq!(weapon.bind().damage, 10);
+    assert!(sword.is_class("AbstractWeapon"));
+
+    sword.free();
+}
+
+#[cfg(since_api = "4.3")]
+#[itest]
+fn runtime_class_outside_editor() {
+    // Outside the editor, runtime classes behave like regular ones.
+    let db = ClassDb::singleton();
+    assert!(db.can_instantiate("RuntimeOnlyClass"));
+
+    let obj = RuntimeOnlyClass::new_gd();
+    assert_eq!(obj.get_class(), "RuntimeOnlyClass".into());
+}
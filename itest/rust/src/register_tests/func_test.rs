@@ -10,6 +10,8 @@
 
 use crate::framework::itest;
 use godot::classes::ClassDb;
+use godot::global::MethodFlags;
+use godot::obj::EngineBitfield;
 use godot::prelude::*;
 
 #[derive(GodotClass)]
@@ -33,6 +35,28 @@ impl FuncObj {
         GString::from("static")
     }
 
+    #[func]
+    fn sum_with_defaults(
+        &self,
+        a: i32,
+        #[opt(default = 10)] b: i32,
+        #[opt(default = 100)] c: i32,
+    ) -> i32 {
+        a + b + c
+    }
+
+    #[func]
+    fn count_varargs(&self, first: i32, rest: &[Variant]) -> VariantArray {
+        let mut result = varray![first];
+        result.extend(rest.iter().cloned());
+        result
+    }
+
+    #[func]
+    fn static_varargs(args: &[Variant]) -> i64 {
+        args.len() as i64
+    }
+
     #[cfg(all())]
     fn returns_hello_world(&self) -> GString {
         GString::from("Hello world!")
@@ -308,6 +332,84 @@ fn cfg_removes_or_keeps_signals() {
     assert!(!class_has_signal::<GdSelfObj>("cfg_removes_signal"));
 }
 
+#[itest]
+fn func_default_params_filled_in_varcall() {
+    let mut obj = FuncObj::new_gd().upcast::<RefCounted>();
+
+    let result = obj.call("sum_with_defaults", &[1.to_variant()]);
+    assert_eq!(result, 111.to_variant());
+
+    let result = obj.call("sum_with_defaults", &[1.to_variant(), 2.to_variant()]);
+    assert_eq!(result, 103.to_variant());
+
+    let result = obj.call(
+        "sum_with_defaults",
+        &[1.to_variant(), 2.to_variant(), 3.to_variant()],
+    );
+    assert_eq!(result, 6.to_variant());
+}
+
+#[itest]
+fn func_default_params_registered() {
+    let methods = ClassDb::singleton()
+        .class_get_method_list_ex(&FuncObj::class_name().to_string_name())
+        .no_inheritance(true)
+        .done();
+
+    let method = methods
+        .iter_shared()
+        .find(|method| method.get("name") == Some("sum_with_defaults".to_variant()))
+        .expect("method `sum_with_defaults` is registered");
+
+    let default_args = method.get("default_args").expect("`default_args` key");
+    assert_eq!(default_args, varray![10, 100].to_variant());
+}
+
+#[itest]
+fn func_varargs_receive_extra_args() {
+    let mut obj = FuncObj::new_gd().upcast::<RefCounted>();
+
+    let result = obj.call("count_varargs", &[1.to_variant()]);
+    assert_eq!(result, varray![1].to_variant());
+
+    let result = obj.call(
+        "count_varargs",
+        &[1.to_variant(), "two".to_variant(), 3.5.to_variant()],
+    );
+    assert_eq!(result, varray![1, "two", 3.5].to_variant());
+}
+
+#[cfg(since_api = "4.4")]
+#[itest]
+fn func_varargs_static() {
+    let result = ClassDb::singleton().class_call_static(
+        &FuncObj::class_name().to_string_name(),
+        "static_varargs",
+        &[1.to_variant(), 2.to_variant()],
+    );
+    assert_eq!(result, 2.to_variant());
+}
+
+#[itest]
+fn func_varargs_registered_with_flag() {
+    let methods = ClassDb::singleton()
+        .class_get_method_list_ex(&FuncObj::class_name().to_string_name())
+        .no_inheritance(true)
+        .done();
+
+    let method = methods
+        .iter_shared()
+        .find(|method| method.get("name") == Some("count_varargs".to_variant()))
+        .expect("method `count_varargs` is registered");
+
+    let flags = method.get("flags").expect("`flags` key").to::<u64>();
+    assert_ne!(flags & MethodFlags::VARARG.ord(), 0);
+
+    // Only the fixed parameter is part of the signature.
+    let args = method.get("args").expect("`args` key").to::<VariantArray>();
+    assert_eq!(args.len(), 1);
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helpers
 
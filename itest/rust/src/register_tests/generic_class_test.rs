@@ -0,0 +1,113 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{GString, StringName};
+use godot::classes::{ClassDb, IRefCounted, RefCounted};
+use godot::meta::ToGodot;
+use godot::obj::{Base, GodotClass, NewGd};
+use godot::register::{godot_api, GodotClass};
+
+use crate::framework::itest;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted, instances(GenericPool<i64> = "GenericIntPool", GenericPool<GString> = "GenericStringPool"))]
+struct GenericPool<T: Clone + Default> {
+    items: Vec<T>,
+    #[var]
+    #[init(val = T::default())]
+    fallback: T,
+    base: Base<RefCounted>,
+}
+
+#[godot_api]
+impl<T: Clone + Default> GenericPool<T> {
+    #[func]
+    fn push(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    #[func]
+    fn pop_or_fallback(&mut self) -> T {
+        self.items.pop().unwrap_or_else(|| self.fallback.clone())
+    }
+}
+
+#[godot_api(secondary)]
+impl<U: Clone + Default> GenericPool<U> {
+    #[func]
+    fn size(&self) -> i64 {
+        self.items.len() as i64
+    }
+}
+
+#[godot_api]
+impl<T: Clone + Default> IRefCounted for GenericPool<T> {
+    fn to_string(&self) -> GString {
+        format!("{}(size={})", Self::class_name(), self.items.len()).into()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[itest]
+fn generic_class_instances_registered() {
+    assert_eq!(GenericIntPool::class_name().to_string(), "GenericIntPool");
+    assert_eq!(
+        GenericStringPool::class_name().to_string(),
+        "GenericStringPool"
+    );
+
+    let db = ClassDb::singleton();
+    for class in ["GenericIntPool", "GenericStringPool"] {
+        assert!(db.class_exists(class));
+        assert_eq!(db.get_parent_class(class), StringName::from("RefCounted"));
+    }
+}
+
+#[itest]
+fn generic_class_funcs() {
+    let mut ints = GenericIntPool::new_gd();
+    ints.call("push", &[7.to_variant()]);
+    ints.call("push", &[8.to_variant()]);
+
+    assert_eq!(ints.call("size", &[]), 2.to_variant());
+    assert_eq!(ints.call("pop_or_fallback", &[]), 8.to_variant());
+    assert_eq!(ints.bind().items, vec![7]);
+
+    let mut strings = GenericStringPool::new_gd();
+    assert_eq!(
+        strings.call("pop_or_fallback", &[]),
+        GString::new().to_variant()
+    );
+
+    strings.call("push", &["hello".to_variant()]);
+    assert_eq!(strings.bind().items.len(), 1);
+}
+
+#[itest]
+fn generic_class_vars() {
+    let mut strings = GenericStringPool::new_gd();
+    strings.set("fallback", &"empty".to_variant());
+
+    assert_eq!(strings.bind().fallback, GString::from("empty"));
+    assert_eq!(strings.get("fallback"), "empty".to_variant());
+    assert_eq!(strings.call("pop_or_fallback", &[]), "empty".to_variant());
+}
+
+#[itest]
+fn generic_class_virtuals() {
+    let mut ints = GenericIntPool::new_gd();
+    ints.bind_mut().push(1);
+
+    assert_eq!(ints.to_string(), "GenericIntPool(size=1)");
+    assert_eq!(
+        GenericStringPool::new_gd().to_string(),
+        "GenericStringPool(size=0)"
+    );
+}
@@ -5,11 +5,13 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+mod abstract_class_test;
 mod constant_test;
 mod conversion_test;
 mod derive_godotconvert_test;
 mod func_test;
 mod gdscript_ffi_test;
+mod generic_class_test;
 mod multiple_impl_blocks_test;
 mod naming_tests;
 mod option_ffi_test;
@@ -78,6 +78,13 @@ impl RpcTest {
 
     #[rpc(config = provide_cfg())]
     pub fn arg_config_fn(&mut self) {}
+
+    #[rpc(any_peer, reliable)]
+    pub fn take_damage(&mut self, _amount: i32, _source: GString) {}
+
+    #[rpc]
+    #[func(rename = heal_renamed)]
+    pub fn heal(&mut self, _amount: i32) {}
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -101,3 +108,353 @@ fn node_enters_tree() {
     root.remove_child(&node);
     node.free();
 }
+
+#[itest]
+fn typed_rpc_call() {
+    let node = RpcTest::new_alloc();
+
+    let call = node.rpcs().take_damage(5, GString::from("trap"));
+    assert_eq!(call.method_name(), &StringName::from("take_damage"));
+    assert_eq!(call.args(), &[5.to_variant(), "trap".to_variant()]);
+
+    // Registered name is used, not the Rust one.
+    let call = node.rpcs().heal(3);
+    assert_eq!(call.method_name(), &StringName::from("heal_renamed"));
+    assert_eq!(call.args(), &[3.to_variant()]);
+
+    node.free();
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Loopback multiplayer (needs `godot/loopback-peer`)
+
+#[cfg(godot_feature = "loopback-peer")]
+mod loopback {
+    use std::time::Duration;
+
+    use godot::classes::multiplayer_peer::{ConnectionStatus, TransferMode};
+    use godot::classes::{Engine, MultiplayerPeer, SceneMultiplayer};
+    use godot::global::Error;
+    use godot::prelude::*;
+    use godot::tools::{LoopbackConfig, LoopbackLink, LoopbackNetwork};
+
+    use crate::framework::{itest, suppress_godot_print};
+
+    #[derive(GodotClass)]
+    #[class(init, base = Node)]
+    pub struct RpcReceiver {
+        /// `(sender ID, value)` for each received call.
+        received: Vec<(i32, i64)>,
+        base: Base<Node>,
+    }
+
+    #[godot_api]
+    impl RpcReceiver {
+        #[rpc(any_peer, call_local)]
+        fn record(&mut self, value: i64) {
+            self.push(value);
+        }
+
+        #[rpc(authority, call_remote)]
+        fn record_from_authority(&mut self, value: i64) {
+            self.push(value);
+        }
+
+        #[rpc(any_peer, unreliable_ordered, channel = 3)]
+        fn record_on_channel(&mut self, value: i64) {
+            self.push(value);
+        }
+    }
+
+    impl RpcReceiver {
+        fn push(&mut self, value: i64) {
+            let sender = self
+                .base()
+                .get_multiplayer()
+                .expect("node has multiplayer API")
+                .get_remote_sender_id();
+
+            self.received.push((sender, value));
+        }
+    }
+
+    /// Server and client scene trees connected by a loopback network, living side by side under the root node.
+    struct LoopbackScene {
+        server: Gd<SceneMultiplayer>,
+        client: Gd<SceneMultiplayer>,
+        server_receiver: Gd<RpcReceiver>,
+        client_receiver: Gd<RpcReceiver>,
+        client_id: i32,
+        subtrees: Vec<Gd<Node>>,
+    }
+
+    impl LoopbackScene {
+        fn new() -> Self {
+            let network = LoopbackNetwork::new();
+
+            let server_peer = network.create_server().upcast::<MultiplayerPeer>();
+            let client_peer = network.create_client().upcast::<MultiplayerPeer>();
+            let client_id = client_peer.get_unique_id();
+
+            let (server, server_subtree, server_receiver) =
+                Self::add_subtree("LoopbackServer", server_peer);
+            let (client, client_subtree, client_receiver) =
+                Self::add_subtree("LoopbackClient", client_peer);
+
+            let scene = Self {
+                server,
+                client,
+                server_receiver,
+                client_receiver,
+                client_id,
+                subtrees: vec![server_subtree, client_subtree],
+            };
+
+            scene.pump();
+            scene
+        }
+
+        fn add_subtree(
+            name: &str,
+            peer: Gd<MultiplayerPeer>,
+        ) -> (Gd<SceneMultiplayer>, Gd<Node>, Gd<RpcReceiver>) {
+            let mut scene_tree = scene_tree();
+
+            let mut subtree = Node::new_alloc();
+            subtree.set_name(name);
+            scene_tree.get_root().unwrap().add_child(&subtree);
+
+            let mut multiplayer = SceneMultiplayer::new_gd();
+            multiplayer.set_multiplayer_peer(&peer);
+            scene_tree
+                .set_multiplayer_ex(&multiplayer)
+                .root_path(&subtree.get_path())
+                .done();
+
+            // Same relative path on both sides, so that RPCs find their counterpart.
+            let mut receiver = RpcReceiver::new_alloc();
+            receiver.set_name("Receiver");
+            subtree.add_child(&receiver);
+
+            (multiplayer, subtree, receiver)
+        }
+
+        /// Polls both sides a few times, so that packets and their responses (e.g. path confirmations) are exchanged.
+        fn pump(&self) {
+            for _ in 0..8 {
+                self.server.clone().poll();
+                self.client.clone().poll();
+            }
+        }
+
+        fn free(self) {
+            let mut scene_tree = scene_tree();
+
+            for subtree in self.subtrees {
+                scene_tree
+                    .set_multiplayer_ex(Gd::null_arg())
+                    .root_path(&subtree.get_path())
+                    .done();
+
+                scene_tree.get_root().unwrap().remove_child(&subtree);
+                subtree.free();
+            }
+        }
+    }
+
+    fn scene_tree() -> Gd<SceneTree> {
+        Engine::singleton()
+            .get_main_loop()
+            .unwrap()
+            .cast::<SceneTree>()
+    }
+
+    fn received(receiver: &Gd<RpcReceiver>) -> Vec<(i32, i64)> {
+        receiver.bind().received.clone()
+    }
+
+    #[itest]
+    fn loopback_connects_peers() {
+        let scene = LoopbackScene::new();
+
+        assert_eq!(scene.server.get_unique_id(), 1);
+        assert_eq!(scene.client.get_unique_id(), scene.client_id);
+        assert_eq!(scene.server.get_peers().as_slice(), &[scene.client_id]);
+        assert_eq!(scene.client.get_peers().as_slice(), &[1]);
+
+        scene.free();
+    }
+
+    #[itest]
+    fn loopback_rpc_call_local() {
+        let scene = LoopbackScene::new();
+
+        scene
+            .server_receiver
+            .clone()
+            .rpc("record", &[7.to_variant()]);
+        scene.pump();
+
+        // call_local: executed on the server as well, with the server as sender.
+        assert_eq!(received(&scene.server_receiver), vec![(1, 7)]);
+        assert_eq!(received(&scene.client_receiver), vec![(1, 7)]);
+
+        scene.free();
+    }
+
+    #[itest]
+    fn loopback_rpc_from_client() {
+        let scene = LoopbackScene::new();
+
+        scene
+            .client_receiver
+            .clone()
+            .rpc_id(1, "record_on_channel", &[42.to_variant()]);
+        scene.pump();
+
+        assert_eq!(
+            received(&scene.server_receiver),
+            vec![(scene.client_id, 42)]
+        );
+        assert!(received(&scene.client_receiver).is_empty());
+
+        scene.free();
+    }
+
+    #[itest]
+    fn loopback_rpc_authority() {
+        let scene = LoopbackScene::new();
+
+        // The server is the authority, so the client accepts its call.
+        scene
+            .server_receiver
+            .clone()
+            .rpc("record_from_authority", &[1.to_variant()]);
+        scene.pump();
+        assert_eq!(received(&scene.client_receiver), vec![(1, 1)]);
+
+        // The client is not the authority, so the server rejects its call (and logs an error).
+        suppress_godot_print(|| {
+            scene
+                .client_receiver
+                .clone()
+                .rpc_id(1, "record_from_authority", &[2.to_variant()]);
+            scene.pump();
+        });
+        assert!(received(&scene.server_receiver).is_empty());
+
+        scene.free();
+    }
+
+    // Transport only, without scene tree.
+
+    fn connected_peers(network: &LoopbackNetwork) -> (Gd<MultiplayerPeer>, Gd<MultiplayerPeer>) {
+        let mut server = network.create_server().upcast::<MultiplayerPeer>();
+        let mut client = network.create_client().upcast::<MultiplayerPeer>();
+
+        client.poll();
+        server.poll();
+        assert_eq!(client.get_connection_status(), ConnectionStatus::CONNECTED);
+
+        client.set_target_peer(1);
+        (server, client)
+    }
+
+    #[itest]
+    fn loopback_packet_channel_and_mode() {
+        let network = LoopbackNetwork::new();
+        let (mut server, mut client) = connected_peers(&network);
+
+        client.set_transfer_channel(3);
+        client.set_transfer_mode(TransferMode::UNRELIABLE_ORDERED);
+        assert_eq!(
+            client.put_packet(&PackedByteArray::from(&[1, 2, 3])),
+            Error::OK
+        );
+
+        server.poll();
+        assert_eq!(server.get_available_packet_count(), 1);
+        assert_eq!(server.get_packet_peer(), client.get_unique_id());
+        assert_eq!(server.get_packet_channel(), 3);
+        assert_eq!(server.get_packet_mode(), TransferMode::UNRELIABLE_ORDERED);
+        assert_eq!(server.get_packet(), PackedByteArray::from(&[1, 2, 3]));
+        assert_eq!(server.get_available_packet_count(), 0);
+    }
+
+    #[itest]
+    fn loopback_packet_latency() {
+        let latent = LoopbackLink {
+            latency: Duration::from_secs(60),
+            ..LoopbackConfig::default().reliable
+        };
+        let network = LoopbackNetwork::with_config(LoopbackConfig {
+            reliable: latent,
+            ..Default::default()
+        });
+        let (mut server, mut client) = connected_peers(&network);
+
+        client.put_packet(&PackedByteArray::from(&[1]));
+        server.poll();
+        assert_eq!(server.get_available_packet_count(), 0);
+        assert_eq!(network.in_flight_count(), 1);
+
+        network.advance_time(Duration::from_secs(60));
+        server.poll();
+        assert_eq!(server.get_available_packet_count(), 1);
+        assert_eq!(network.in_flight_count(), 0);
+    }
+
+    #[itest]
+    fn loopback_packet_loss() {
+        let defaults = LoopbackConfig::default();
+        let network = LoopbackNetwork::with_config(LoopbackConfig {
+            reliable: LoopbackLink {
+                packet_loss: 1.0,
+                ..defaults.reliable
+            },
+            unreliable: LoopbackLink {
+                packet_loss: 1.0,
+                ..defaults.unreliable
+            },
+            ..defaults
+        });
+        let (mut server, mut client) = connected_peers(&network);
+
+        client.set_transfer_mode(TransferMode::UNRELIABLE);
+        client.put_packet(&PackedByteArray::from(&[1]));
+        server.poll();
+        assert_eq!(server.get_available_packet_count(), 0);
+
+        // Reliable packets are never lost.
+        client.set_transfer_mode(TransferMode::RELIABLE);
+        client.put_packet(&PackedByteArray::from(&[2]));
+        server.poll();
+        assert_eq!(server.get_available_packet_count(), 1);
+    }
+
+    #[itest]
+    fn loopback_packet_ordering() {
+        let jittery = LoopbackLink {
+            jitter: Duration::from_secs(10),
+            ..LoopbackConfig::default().unreliable_ordered
+        };
+        let network = LoopbackNetwork::with_config(LoopbackConfig {
+            unreliable_ordered: jittery,
+            ..Default::default()
+        });
+        let (mut server, mut client) = connected_peers(&network);
+
+        client.set_transfer_mode(TransferMode::UNRELIABLE_ORDERED);
+        for i in 0..16 {
+            client.put_packet(&PackedByteArray::from(&[i]));
+        }
+
+        network.advance_time(Duration::from_secs(10));
+        server.poll();
+
+        let order: Vec<u8> = (0..server.get_available_packet_count())
+            .map(|_| server.get_packet()[0])
+            .collect();
+        assert_eq!(order, (0..16).collect::<Vec<u8>>());
+    }
+}